@@ -0,0 +1,104 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! This crate provides the `Recyclable` derive macro of the `maskerad_object_pool` crate.
+//!
+//! Use it through the `derive` feature of `maskerad_object_pool`, which re-exports it.
+//!
+//! The generated `reinitialize` function sets each field of the struct to its `Default` value.
+//! The `#[recycle(with = "expr")]` attribute sets a field to the value of the given expression instead.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{Data, DeriveInput, Expr, Field, Fields, Index, LitStr};
+use syn::spanned::Spanned;
+
+/// Derives the `Recyclable` trait, resetting every field of the struct to its `Default` value.
+///
+/// A field annotated with `#[recycle(with = "expr")]` is set to the value of `expr` instead.
+#[proc_macro_derive(Recyclable, attributes(recycle))]
+pub fn derive_recyclable(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "Recyclable can only be derived for structs",
+            ))
+        }
+    };
+
+    let resets = match *fields {
+        Fields::Named(ref fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let name = &field.ident;
+                reset(field, quote!(self.#name))
+            })
+            .collect::<syn::Result<Vec<_>>>()?,
+        Fields::Unnamed(ref fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let index = Index::from(index);
+                reset(field, quote!(self.#index))
+            })
+            .collect::<syn::Result<Vec<_>>>()?,
+        Fields::Unit => Vec::new(),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::maskerad_object_pool::Recyclable for #name #ty_generics #where_clause {
+            fn reinitialize(&mut self) {
+                #(#resets)*
+            }
+        }
+    })
+}
+
+// Generates the statement resetting the given field.
+fn reset(field: &Field, place: TokenStream2) -> syn::Result<TokenStream2> {
+    match custom_reset(field)? {
+        Some(expr) => Ok(quote!(#place = #expr;)),
+        None => Ok(quote!(#place = ::std::default::Default::default();)),
+    }
+}
+
+// Parses the `#[recycle(with = "expr")]` attribute of the field, if any.
+fn custom_reset(field: &Field) -> syn::Result<Option<Expr>> {
+    let mut with = None;
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("recycle")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("with") {
+                let expr: LitStr = meta.value()?.parse()?;
+                with = Some(expr.parse::<Expr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `with = \"expr\"`"))
+            }
+        })?;
+    }
+    Ok(with)
+}
@@ -0,0 +1,165 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use refcounted_pool_handler::RcHandle;
+use pool_object::Recyclable;
+
+/// The state of a slot of a `RcPool`, given to an `AcquisitionPolicy`.
+#[derive(Debug)]
+pub struct SlotInfo<'a, T: Recyclable + 'a> {
+    index: usize,
+    in_use: bool,
+    stamp: u64,
+    handle: &'a RcHandle<T>,
+}
+
+impl<'a, T: Recyclable> SlotInfo<'a, T> {
+    #[doc(hidden)]
+    pub fn new(index: usize, in_use: bool, stamp: u64, handle: &'a RcHandle<T>) -> Self {
+        SlotInfo { index, in_use, stamp, handle }
+    }
+
+    /// Returns the index of the slot in its pool.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns `true` if the object of the slot is used.
+    pub fn in_use(&self) -> bool {
+        self.in_use
+    }
+
+    /// Returns the acquisition order of the slot: the higher, the more recently the slot has been acquired.
+    pub fn stamp(&self) -> u64 {
+        self.stamp
+    }
+
+    /// Returns the `RcHandle<T>` of the slot.
+    pub fn handle(&self) -> &'a RcHandle<T> {
+        self.handle
+    }
+}
+
+/// A strategy choosing which slot `RcPool::create_with_policy` acquires.
+///
+/// `select` is given the state of every slot of the pool, and returns the index of the slot to acquire.
+/// If it returns `None`, the index of a used slot, or an index out of bounds, nothing is acquired.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::{AcquisitionPolicy, RcPool, SlotInfo};
+/// # use maskerad_object_pool::Recyclable;
+/// #
+/// # struct Monster {
+/// # hp :u32,
+/// # pub level: u32,
+/// # }
+/// #
+/// # impl Default for Monster {
+/// #    fn default() -> Self {
+/// #        Monster {
+/// #            hp: 10,
+/// #            level: 10,
+/// #        }
+/// #    }
+/// # }
+/// #
+/// # impl Recyclable for Monster {
+/// #   fn reinitialize(&mut self) {
+/// #       self.level = 1;
+/// #   }
+/// # }
+/// #
+/// # impl Monster {
+/// #    pub fn level_up(&mut self) {
+/// #        self.level += 1;
+/// #    }
+/// # }
+///
+/// // Acquires the last non-used slot.
+/// struct LastFree;
+///
+/// impl<T: Recyclable> AcquisitionPolicy<T> for LastFree {
+///     fn select(&self, slots: &[SlotInfo<T>]) -> Option<usize> {
+///         slots.iter().rev().find(|slot| !slot.in_use()).map(|slot| slot.index())
+///     }
+/// }
+///
+/// let pool = RcPool::with_capacity(3, || {
+///     Monster::default()
+/// });
+///
+/// let a_monster = pool.create_with_policy(&LastFree).unwrap();
+/// assert!(a_monster.ptr_eq(&pool.pool_slice()[2]));
+/// ```
+pub trait AcquisitionPolicy<T: Recyclable> {
+    /// Returns the index of the slot to acquire.
+    fn select(&self, slots: &[SlotInfo<T>]) -> Option<usize>;
+}
+
+/// Acquires the non-used slot with the lowest index, like `RcPool::create`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FirstFree;
+
+impl<T: Recyclable> AcquisitionPolicy<T> for FirstFree {
+    fn select(&self, slots: &[SlotInfo<T>]) -> Option<usize> {
+        trace!("Selecting the first non-used slot.");
+        slots
+            .iter()
+            .find(|slot| !slot.in_use())
+            .map(|slot| slot.index())
+    }
+}
+
+/// Acquires the least recently acquired non-used slot.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Lru;
+
+impl<T: Recyclable> AcquisitionPolicy<T> for Lru {
+    fn select(&self, slots: &[SlotInfo<T>]) -> Option<usize> {
+        trace!("Selecting the least recently acquired non-used slot.");
+        slots
+            .iter()
+            .filter(|slot| !slot.in_use())
+            .min_by_key(|slot| slot.stamp())
+            .map(|slot| slot.index())
+    }
+}
+
+/// Acquires the most recently acquired non-used slot.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Mru;
+
+impl<T: Recyclable> AcquisitionPolicy<T> for Mru {
+    fn select(&self, slots: &[SlotInfo<T>]) -> Option<usize> {
+        trace!("Selecting the most recently acquired non-used slot.");
+        slots
+            .iter()
+            .filter(|slot| !slot.in_use())
+            .max_by_key(|slot| slot.stamp())
+            .map(|slot| slot.index())
+    }
+}
+
+/// Acquires the non-used slot holding the smallest object, according to its `Ord` implementation.
+///
+/// Objects which are currently borrowed are skipped.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinOrd;
+
+impl<T: Recyclable + Ord> AcquisitionPolicy<T> for MinOrd {
+    fn select(&self, slots: &[SlotInfo<T>]) -> Option<usize> {
+        trace!("Selecting the non-used slot holding the smallest object.");
+        slots
+            .iter()
+            .filter(|slot| !slot.in_use())
+            .filter_map(|slot| slot.handle().try_borrow().ok().map(|object| (object, slot.index())))
+            .min_by(|(a, _), (b, _)| (**a).cmp(&**b))
+            .map(|(_, index)| index)
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+use pool_object::Recyclable;
+
+// The non-used objects of an AnyPool, by type. Shared with the TypedHandles, which give their object back.
+type FreeLists = Rc<RefCell<HashMap<TypeId, Vec<Box<dyn Any>>>>>;
+
+/// A pool of objects of different types, for the programs needing a single pool for several concrete types,
+/// like scripting runtimes.
+///
+/// The objects are stored as `Box<dyn Any>`, in a free list per type. `create::<T>` gives a non-used object of type `T`,
+/// in a `TypedHandle<T>`. When the `TypedHandle<T>` is dropped, the object is reinitialized and goes back to the free
+/// list of its type.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::{AnyPool, Recyclable};
+///
+/// #[derive(Default)]
+/// struct Monster {
+///     level: u32,
+/// }
+///
+/// impl Recyclable for Monster {
+///     fn reinitialize(&mut self) {
+///         self.level = 1;
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct Item {
+///     name: String,
+/// }
+///
+/// impl Recyclable for Item {
+///     fn reinitialize(&mut self) {
+///         self.name.clear();
+///     }
+/// }
+///
+/// let pool = AnyPool::new();
+/// pool.populate(2, Monster::default);
+/// pool.populate(1, Item::default);
+///
+/// let mut monster = pool.create::<Monster>().unwrap();
+/// let mut item = pool.create::<Item>().unwrap();
+/// monster.level = 10;
+/// item.name.push_str("sword");
+/// assert!(pool.create::<Item>().is_none());
+///
+/// drop(item);
+/// assert_eq!(pool.nb_unused::<Item>(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct AnyPool {
+    free: FreeLists,
+}
+
+impl AnyPool {
+    /// Creates an empty pool. Use `populate` to add objects to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::AnyPool;
+    ///
+    /// let pool = AnyPool::new();
+    /// assert_eq!(pool.nb_unused::<String>(), 0);
+    /// ```
+    pub fn new() -> Self {
+        debug!("Creating an empty AnyPool.");
+        AnyPool::default()
+    }
+
+    /// Adds `n` objects of type `T`, created with the given closure, to the pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{AnyPool, Recyclable};
+    /// #
+    /// # #[derive(Default)]
+    /// # struct Monster {
+    /// #     level: u32,
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #     fn reinitialize(&mut self) {
+    /// #         self.level = 1;
+    /// #     }
+    /// # }
+    ///
+    /// let pool = AnyPool::new();
+    /// pool.populate(3, Monster::default);
+    /// assert_eq!(pool.nb_unused::<Monster>(), 3);
+    /// ```
+    pub fn populate<T, F>(&self, n: usize, op: F)
+    where
+        T: Recyclable + 'static,
+        F: Fn() -> T,
+    {
+        debug!("Adding {} object(s) to the AnyPool.", n);
+        let objects: Vec<Box<dyn Any>> = (0..n).map(|_| Box::new(op()) as Box<dyn Any>).collect();
+        self.free
+            .borrow_mut()
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .extend(objects);
+    }
+
+    /// Asks the pool for a non-used object of type `T`, returning an `Option<TypedHandle<T>>`.
+    ///
+    /// `None` is returned if all the objects of type `T` are used, or if the pool has no object of type `T`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{AnyPool, Recyclable};
+    /// #
+    /// # #[derive(Default)]
+    /// # struct Monster {
+    /// #     level: u32,
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #     fn reinitialize(&mut self) {
+    /// #         self.level = 1;
+    /// #     }
+    /// # }
+    ///
+    /// let pool = AnyPool::new();
+    /// pool.populate(1, Monster::default);
+    ///
+    /// let monster = pool.create::<Monster>().unwrap();
+    /// assert!(pool.create::<Monster>().is_none());
+    /// assert!(pool.create::<Option<u32>>().is_none());
+    /// ```
+    pub fn create<T: Recyclable + 'static>(&self) -> Option<TypedHandle<T>> {
+        debug!("The AnyPool is being asked a TypedHandle.");
+        let object = self.free.borrow_mut().get_mut(&TypeId::of::<T>())?.pop()?;
+        trace!("A non-used object of the requested type has been found !");
+        let object = object
+            .downcast::<T>()
+            .expect("the free list of a type holds objects of this type");
+        Some(TypedHandle {
+            object: Some(object),
+            free: self.free.clone(),
+        })
+    }
+
+    /// Returns the number of non-used objects of type `T` in the pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{AnyPool, Recyclable};
+    /// #
+    /// # #[derive(Default)]
+    /// # struct Monster {
+    /// #     level: u32,
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #     fn reinitialize(&mut self) {
+    /// #         self.level = 1;
+    /// #     }
+    /// # }
+    ///
+    /// let pool = AnyPool::new();
+    /// pool.populate(2, Monster::default);
+    ///
+    /// let monster = pool.create::<Monster>().unwrap();
+    /// assert_eq!(pool.nb_unused::<Monster>(), 1);
+    /// ```
+    pub fn nb_unused<T: 'static>(&self) -> usize {
+        debug!("Getting the number of unused objects of a type in the AnyPool.");
+        self.free
+            .borrow()
+            .get(&TypeId::of::<T>())
+            .map_or(0, Vec::len)
+    }
+}
+
+/// An object of type `T` given by an `AnyPool`.
+///
+/// It dereferences to the object. When it is dropped, the object is reinitialized and goes back to the free list
+/// of its type in the pool.
+#[derive(Debug)]
+pub struct TypedHandle<T: Recyclable + 'static> {
+    // Always Some, until the handle is dropped.
+    object: Option<Box<T>>,
+    free: FreeLists,
+}
+
+impl<T: Recyclable + 'static> Deref for TypedHandle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.object.as_ref().expect("a TypedHandle holds its object until it is dropped")
+    }
+}
+
+impl<T: Recyclable + 'static> DerefMut for TypedHandle<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.object.as_mut().expect("a TypedHandle holds its object until it is dropped")
+    }
+}
+
+impl<T: Recyclable + 'static> Drop for TypedHandle<T> {
+    fn drop(&mut self) {
+        trace!("The TypedHandle is being dropped. Reinitializing the inner object.");
+        if let Some(mut object) = self.object.take() {
+            object.reinitialize();
+            self.free
+                .borrow_mut()
+                .entry(TypeId::of::<T>())
+                .or_default()
+                .push(object);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::sync::{Arc, RwLock, TryLockError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+use concurrent_pool_handler::DEFERRED;
+
+/// The channel on which the released objects are sent to the background recycler. `None` stops the recycler.
+pub type RecycleSender<T> = Sender<Option<RecycleJob<T>>>;
+
+/// An object released by its last `ArcHandle`, waiting to be reinitialized by the background recycler.
+///
+/// The object is reinitialized when the job is dropped, so a job left in the channel when the recycler stops,
+/// or sent after it stopped, still gives its object back to the pool.
+#[derive(Debug)]
+pub struct RecycleJob<T> {
+    object: Arc<RwLock<T>>,
+    recycle: fn(&mut T),
+    users: Arc<AtomicUsize>,
+}
+
+impl<T> RecycleJob<T> {
+    #[doc(hidden)]
+    pub fn new(object: Arc<RwLock<T>>, recycle: fn(&mut T), users: Arc<AtomicUsize>) -> Self {
+        RecycleJob { object, recycle, users }
+    }
+
+    // Reinitializes the object, and marks it as non-used.
+    fn run(self) {
+        drop(self);
+    }
+}
+
+impl<T> Drop for RecycleJob<T> {
+    fn drop(&mut self) {
+        // The job may be dropped by the thread releasing the object, which may hold its lock through `pool_slice`.
+        match self.object.try_write() {
+            Ok(mut object) => {
+                trace!("Reinitializing an object in the background.");
+                (self.recycle)(&mut object);
+            }
+            Err(TryLockError::WouldBlock) => {
+                trace!("The object to recycle is locked, its reinitialization is deferred.");
+                self.users.store(DEFERRED, Ordering::Release);
+                return;
+            }
+            Err(TryLockError::Poisoned(_)) => error!("The lock of an object to recycle in the background is poisoned !"),
+        }
+        self.users.store(1, Ordering::Release);
+    }
+}
+
+/// The thread reinitializing the objects released by the `ArcHandle`s of an `ArcPool`.
+///
+/// The thread is stopped and joined when the recycler is dropped, after the objects already released are reinitialized.
+#[derive(Debug)]
+pub struct BackgroundRecycler<T> {
+    sender: RecycleSender<T>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl<T: Send + Sync + 'static> BackgroundRecycler<T> {
+    #[doc(hidden)]
+    pub fn spawn() -> Self {
+        debug!("Spawning the background recycler.");
+        let (sender, receiver) = mpsc::channel::<Option<RecycleJob<T>>>();
+        let thread = thread::spawn(move || {
+            while let Ok(Some(job)) = receiver.recv() {
+                job.run();
+            }
+            trace!("The background recycler is stopping.");
+            // Handles dropped while the recycler was stopping may have queued jobs after the shutdown message.
+            for job in receiver.try_iter().flatten() {
+                job.run();
+            }
+        });
+
+        BackgroundRecycler {
+            sender,
+            thread: Some(thread),
+        }
+    }
+}
+
+impl<T> BackgroundRecycler<T> {
+    /// Returns a sender, given to the `ArcHandle`s of the pool.
+    #[doc(hidden)]
+    pub fn sender(&self) -> RecycleSender<T> {
+        self.sender.clone()
+    }
+}
+
+impl<T> Drop for BackgroundRecycler<T> {
+    fn drop(&mut self) {
+        debug!("Stopping the background recycler.");
+        // The thread may already have stopped if it panicked, the error is then ignored.
+        let _ = self.sender.send(None);
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                error!("The background recycler panicked !");
+            }
+        }
+    }
+}
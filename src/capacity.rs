@@ -0,0 +1,32 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+/// Returns the capacity of a pool expected to have the given number of objects used at the same time,
+/// multiplied by the given safety factor.
+///
+/// The result is rounded up, so a safety factor of `1.0` or more never gives less objects than expected.
+/// A negative safety factor gives a capacity of 0.
+///
+/// Use it, or `RcPool::with_concurrency`, to document the sizing intent of a pool at the call site.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::recommended_capacity;
+///
+/// assert_eq!(recommended_capacity(10, 1.5), 15);
+/// assert_eq!(recommended_capacity(3, 1.5), 5);
+/// assert_eq!(recommended_capacity(10, 1.0), 10);
+/// ```
+pub fn recommended_capacity(expected_concurrent: usize, safety_factor: f32) -> usize {
+    debug!(
+        "Computing the capacity of a pool for {} concurrent object(s), with a safety factor of {}.",
+        expected_concurrent, safety_factor
+    );
+    (expected_concurrent as f32 * safety_factor).ceil() as usize
+}
+
@@ -7,9 +7,27 @@
 
 use errors::{PoolError, PoolResult};
 use concurrent_pool_handler::ArcHandle;
+use concurrent_pool_guard::{ArcHandleRead, ArcHandleWrite};
+use refcounted_pool_allocator::RcPool;
+use pool_stats::PoolStats;
+use slot_health::SlotHealth;
+use background_recycler::BackgroundRecycler;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use pool_object::Recyclable;
+use pool_id::PoolId;
+#[cfg(feature = "zeroize")]
+use pool_object::zeroize_and_reinitialize;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "crossbeam")]
+use crossbeam;
 
-use std::sync::Arc;
+use std::fmt;
+use std::sync::{Arc, RwLockWriteGuard, TryLockError};
+use std::thread;
 
 /// A wrapper around a vector of `ArcHandle<T>`.
 ///
@@ -77,9 +95,73 @@ use std::sync::Arc;
 /// #   try_main().unwrap();
 /// # }
 /// ```
-#[derive(Debug, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct ArcPool<T: Recyclable>(Vec<ArcHandle<T>>);
+///
+/// `ArcPool<T>` is `Send` and `Sync` when `T` is `Send` and `Sync`: it can be shared between threads in an `Arc`.
+///
+/// ```rust
+/// use maskerad_object_pool::ArcPool;
+/// use std::sync::Arc;
+/// use std::thread;
+/// # use maskerad_object_pool::Recyclable;
+/// #
+/// # struct Monster {
+/// # hp :u32,
+/// # pub level: u32,
+/// # }
+/// #
+/// # impl Default for Monster {
+/// #    fn default() -> Self {
+/// #        Monster {
+/// #            hp: 10,
+/// #            level: 10,
+/// #        }
+/// #    }
+/// # }
+/// #
+/// # impl Recyclable for Monster {
+/// #   fn reinitialize(&mut self) {
+/// #       self.level = 1;
+/// #   }
+/// # }
+/// #
+/// # impl Monster {
+/// #    pub fn level_up(&mut self) {
+/// #        self.level += 1;
+/// #    }
+/// # }
+///
+/// let pool = Arc::new(ArcPool::with_capacity(2, || {
+///     Monster::default()
+/// }));
+///
+/// let workers: Vec<_> = (0..2).map(|_| {
+///     let pool = Arc::clone(&pool);
+///     thread::spawn(move || {
+///         let mut monster = pool.create_write().unwrap();
+///         let level = monster.level;
+///         monster.level_up();
+///         assert_eq!(monster.level, level + 1);
+///     })
+/// }).collect();
+///
+/// for worker in workers {
+///     worker.join().unwrap();
+/// }
+///
+/// assert_eq!(pool.nb_unused(), 2);
+/// ```
+///
+/// With the `serde` feature, the pool is serialized as the values of its objects.
+#[derive(Clone)]
+pub struct ArcPool<T: Recyclable> {
+    id: PoolId,
+    handles: Vec<ArcHandle<T>>,
+    recycle: fn(&mut T),
+    // Shared by the clones of the pool, the recycler thread is joined when the last of them is dropped.
+    recycler: Option<Arc<BackgroundRecycler<T>>>,
+    // The pool prefers the objects last released by the calling thread.
+    thread_affinity: bool,
+}
 
 impl<T: Recyclable> ArcPool<T> {
     /// Create an object pool with the given capacity, and instantiate the given number of object.
@@ -125,16 +207,326 @@ impl<T: Recyclable> ArcPool<T> {
         F: Fn() -> T,
     {
         debug!("Creating an ArcPool with a size of {} ArcHandles", size);
-        let mut objects = Vec::with_capacity(size);
+        ArcPool::with_recycler(size, |_| op(), T::reinitialize)
+    }
+
+    /// Create an object pool with the given capacity, and instantiate the given number of object.
+    ///
+    /// The closure is given the index of the slot of each object.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = ArcPool::with_capacity_indexed(20, |index| {
+    ///     Monster {
+    ///         hp: 10,
+    ///         level: index as u32,
+    ///     }
+    /// });
+    /// assert_eq!(pool.nb_unused(), 20);
+    /// ```
+    pub fn with_capacity_indexed<F>(size: usize, op: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        debug!("Creating an ArcPool with a size of {} ArcHandle(s), from their indices", size);
+        ArcPool::with_recycler(size, op, T::reinitialize)
+    }
+
+    /// Create an object pool with the given capacity, and instantiate the given number of object,
+    /// reinitializing each object with `Recyclable` right after its construction.
+    ///
+    /// Fresh objects start in the same state as recycled ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity_consistent(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// // Monster's default initialization set their level at 10, but they are reinitialized at level 1.
+    /// assert_eq!(pool.pool_slice()[0].read().unwrap().level, 1);
+    /// ```
+    pub fn with_capacity_consistent<F>(size: usize, op: F) -> Self
+    where
+        F: Fn() -> T,
+    {
+        debug!("Creating a consistent ArcPool with a size of {} ArcHandle(s)", size);
+        ArcPool::with_recycler(
+            size,
+            |_| {
+                let mut object = op();
+                object.reinitialize();
+                object
+            },
+            T::reinitialize,
+        )
+    }
+
+    /// Create an object pool with the given capacity, whose objects are reinitialized by a dedicated thread.
+    ///
+    /// When the last `ArcHandle<T>` of an object is dropped, the object is sent to the recycler thread instead of
+    /// being reinitialized in place, so an expensive `reinitialize` does not slow down the thread dropping the handle.
+    /// The object is not given by the pool until the recycler thread has reinitialized it.
+    ///
+    /// The recycler thread is stopped and joined when the pool, and all its clones, are dropped. The objects released
+    /// after that are reinitialized in place.
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// use std::thread;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_background_recycler(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create().unwrap();
+    /// monster.write().unwrap().level_up();
+    /// drop(monster);
+    ///
+    /// // The monster is available once the recycler thread reinitialized it.
+    /// let monster = loop {
+    ///     match pool.create() {
+    ///         Some(monster) => break monster,
+    ///         None => thread::yield_now(),
+    ///     }
+    /// };
+    /// assert_eq!(monster.read().unwrap().level, 1);
+    /// ```
+    pub fn with_background_recycler<F>(size: usize, op: F) -> Self
+    where
+        F: Fn() -> T,
+        T: Send + Sync + 'static,
+    {
+        debug!("Creating an ArcPool with a size of {} ArcHandle(s), and a background recycler", size);
+        let recycler = Arc::new(BackgroundRecycler::spawn());
+        let id = PoolId::next();
+        let handles = (0..size)
+            .map(|_| ArcHandle::pooled(op(), T::reinitialize, id, Some(recycler.sender()), false))
+            .collect();
+
+        ArcPool {
+            id,
+            handles,
+            recycle: T::reinitialize,
+            recycler: Some(recycler),
+            thread_affinity: false,
+        }
+    }
+
+    /// Create an object pool with the given capacity, which prefers to give the objects last released by the calling thread.
+    ///
+    /// On multi-socket machines, reusing an object last touched by the same thread improves the cache and NUMA locality.
+    /// The thread releasing an object is recorded, and the pool gives a non-used object released by the calling thread
+    /// if there is one. It is a best-effort hint: any other available object is given otherwise.
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// use std::thread;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_thread_affinity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// let another_monster = pool.create().unwrap();
+    /// drop(another_monster);
+    /// thread::spawn(move || drop(a_monster)).join().unwrap();
+    ///
+    /// // The first object is available, but the second one has been released by this thread.
+    /// let monster = pool.create().unwrap();
+    /// assert!(monster.ptr_eq(&pool.pool_slice()[1]));
+    /// ```
+    pub fn with_thread_affinity<F>(size: usize, op: F) -> Self
+    where
+        F: Fn() -> T,
+    {
+        debug!("Creating an ArcPool with a size of {} ArcHandle(s), preferring thread-local objects", size);
+        let id = PoolId::next();
+        let handles = (0..size)
+            .map(|_| ArcHandle::pooled(op(), T::reinitialize, id, None, true))
+            .collect();
 
-        for _ in 0..size {
-            objects.push(ArcHandle::new(op()));
+        ArcPool {
+            id,
+            handles,
+            recycle: T::reinitialize,
+            recycler: None,
+            thread_affinity: true,
         }
+    }
 
-        ArcPool(objects)
+    /// Create an object pool with the given capacity, whose objects are zeroed before being reinitialized,
+    /// when they go back to the pool.
+    ///
+    /// Use this pool for sensitive data, like keys or tokens: `Recyclable::reinitialize` may leave old bytes readable.
+    ///
+    /// This function is only available with the `zeroize` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate maskerad_object_pool;
+    /// # extern crate zeroize;
+    /// use maskerad_object_pool::ArcPool;
+    /// use maskerad_object_pool::Recyclable;
+    /// use zeroize::Zeroize;
+    ///
+    /// #[derive(Default)]
+    /// struct Secret {
+    ///     key: [u8; 4],
+    /// }
+    ///
+    /// impl Zeroize for Secret {
+    ///     fn zeroize(&mut self) {
+    ///         self.key.zeroize();
+    ///     }
+    /// }
+    ///
+    /// impl Recyclable for Secret {
+    ///     fn reinitialize(&mut self) {}
+    /// }
+    ///
+    /// # fn main() {
+    /// let pool = ArcPool::with_capacity_zeroizing(1, || {
+    ///     Secret::default()
+    /// });
+    ///
+    /// {
+    ///     let secret = pool.create().unwrap();
+    ///     secret.write().unwrap().key = [1, 2, 3, 4];
+    /// }
+    ///
+    /// assert_eq!(pool.pool_slice()[0].read().unwrap().key, [0; 4]);
+    /// # }
+    /// ```
+    #[cfg(feature = "zeroize")]
+    pub fn with_capacity_zeroizing<F>(size: usize, op: F) -> Self
+    where
+        F: Fn() -> T,
+        T: Zeroize,
+    {
+        debug!("Creating a zeroizing ArcPool with a size of {} ArcHandles", size);
+        ArcPool::with_recycler(size, |_| op(), zeroize_and_reinitialize::<T>)
     }
 
-    /// Returns an immutable slice of the vector of `ArcHandle<T>`
+    /// Creates an `ArcPool`, constructing its objects in parallel on the global `rayon` thread pool.
+    ///
+    /// It is useful for big pools of objects which are expensive to construct.
+    ///
+    /// This function is only available with the `rayon` feature.
     ///
     /// # Example
     ///
@@ -167,36 +559,1316 @@ impl<T: Recyclable> ArcPool<T> {
     /// #        self.level += 1;
     /// #    }
     /// # }
-    /// let pool = ArcPool::with_capacity(20, || {
+    ///
+    /// let pool = ArcPool::with_capacity_parallel(1000, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// assert_eq!(pool.len(), 1000);
+    /// assert_eq!(pool.nb_unused(), 1000);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn with_capacity_parallel<F>(size: usize, op: F) -> Self
+    where
+        F: Fn() -> T + Sync,
+        T: Send,
+    {
+        debug!("Creating an ArcPool with a size of {} ArcHandle(s), in parallel", size);
+        let objects: Vec<T> = (0..size).into_par_iter().map(|_| op()).collect();
+        ArcPool::from_objects(objects, T::reinitialize)
+    }
+
+    #[doc(hidden)]
+    pub fn from_objects(objects: Vec<T>, recycle: fn(&mut T)) -> Self {
+        trace!("Creating an ArcPool from {} objects.", objects.len());
+        let size = objects.len();
+        let mut objects = objects.into_iter();
+        Self::with_recycler(size, |_| objects.next().expect("as many objects as the size of the ArcPool"), recycle)
+    }
+
+    fn with_recycler<F>(size: usize, mut op: F, recycle: fn(&mut T)) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        trace!("Creating the ArcHandles of the ArcPool.");
+        let id = PoolId::next();
+        let mut objects = Vec::with_capacity(size);
+
+        for index in 0..size {
+            objects.push(ArcHandle::pooled(op(index), recycle, id, None, false));
+        }
+
+        ArcPool {
+            id,
+            handles: objects,
+            recycle,
+            recycler: None,
+            thread_affinity: false,
+        }
+    }
+
+    /// Returns the unique identifier of the pool.
+    ///
+    /// The `ArcHandle<T>`s created by this pool return the same `PoolId` with `ArcHandle::pool_id`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = ArcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    /// let other_pool = ArcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// assert_ne!(pool.id(), other_pool.id());
+    /// ```
+    pub fn id(&self) -> PoolId {
+        debug!("Getting the PoolId of the ArcPool.");
+        self.id
+    }
+
+    /// Returns an immutable slice of the vector of `ArcHandle<T>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = ArcPool::with_capacity(20, || {
+    ///     Monster::default()
+    /// });
+    /// let nb_lvl_6_monsters = pool.pool_slice()
+    /// .iter()
+    /// .filter(|handle| {
+    ///     handle.read().unwrap().level == 6
+    /// })
+    /// .count();
+    ///
+    /// //All monsters start at level 10, there is no monsters at level 6.
+    /// assert_eq!(nb_lvl_6_monsters, 0);
+    /// ```
+    pub fn pool_slice(&self) -> &[ArcHandle<T>] {
+        debug!("Getting an immutable slice of the vector containing all the ArcHandles.");
+        &self.handles
+    }
+
+    /// Ask the pool for an `ArcHandle<T>`, returning a `PoolResult<ArcHandle<T>>`. If you cannot increase the pool size because of
+    /// memory restrictions, this function may be more convenient than the "non-strict" one.
+    ///
+    /// # Errors
+    /// If all `ArcHandle<T>` are used, a PoolError is returned indicating that all `ArcHandle<T>` are used.
+    ///
+    /// Non-used `ArcHandle<T>` whose lock is poisoned are skipped, see `nb_available`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// # use std::error::Error;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let pool = ArcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create_strict()?;
+    /// assert!(pool.create_strict().is_err());
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #   try_main().unwrap();
+    /// # }
+    /// ```
+    pub fn create_strict(&self) -> PoolResult<ArcHandle<T>> {
+        debug!("The ArcPool is being asked an ArcHandle (strict).");
+        match self.claim_available() {
+            Some(handle) => {
+                trace!("A non-used ArcHandle has been claimed !");
+                Ok(handle)
+            },
+            None => {
+                error!("The ArcPool could not find an ArcHandle with a reference count of 1 !");
+                Err(PoolError::PoolError(String::from(
+                    "The ArcPool is out of objects !",
+                )))
+            },
+        }
+    }
+
+    /// Asks the pool for an `ArcHandle<T>`, returning an `Option<ArcHandle<T>>`.
+    ///
+    /// The non-used `ArcHandle<T>` with the lowest index is always returned. Under acquire/release churn,
+    /// the same few slots at the front of the pool are reused, keeping the working set small and hot in cache.
+    ///
+    /// If the pool has been created with `with_thread_affinity`, a non-used `ArcHandle<T>` last released by the calling
+    /// thread is preferred.
+    ///
+    /// Non-used `ArcHandle<T>` whose lock is poisoned are skipped: their objects cannot be read nor written.
+    /// `None` is returned if no `ArcHandle<T>` is available, see `nb_available`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = ArcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create();
+    /// assert!(a_monster.is_some());
+    /// assert!(pool.create().is_none());
+    ///
+    /// match pool.create() {
+    ///     Some(monster) => println!("will not happen."),
+    ///     None => {
+    ///         // do something, or nothing.
+    ///     },
+    /// }
+    /// ```
+    pub fn create(&self) -> Option<ArcHandle<T>> {
+        debug!("The ArcPool is being asked an ArcHandle.");
+        match self.claim_available() {
+            Some(handle) => {
+                trace!("A non-used ArcHandle has been claimed !");
+                Some(handle)
+            },
+            None => {
+                trace!("The ArcPool could not find an ArcHandle with a reference count of 1.");
+                None
+            },
+        }
+    }
+
+    /// Asks the pool for an `ArcHandle<T>`, returning the reason of the failure if no `ArcHandle<T>` can be given.
+    ///
+    /// `PoolError::Exhausted` is returned if all `ArcHandle<T>` are used, and `PoolError::AllPoisoned` if all the
+    /// non-used `ArcHandle<T>` have a poisoned lock. It helps the caller to decide between growing the pool,
+    /// retrying later, or failing. `create` is kept for the callers which do not need the reason.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{ArcPool, PoolError};
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create_detailed().unwrap();
+    /// match pool.create_detailed() {
+    ///     Err(PoolError::Exhausted) => {},
+    ///     _ => panic!("All the monsters are used."),
+    /// }
+    /// ```
+    pub fn create_detailed(&self) -> PoolResult<ArcHandle<T>> {
+        debug!("The ArcPool is being asked an ArcHandle, with the reason of a failure.");
+        if let Some(handle) = self.claim_available() {
+            trace!("A non-used ArcHandle has been claimed !");
+            return Ok(handle);
+        }
+
+        if self.nb_unused() > 0 {
+            error!("All the non-used ArcHandles of the ArcPool are poisoned !");
+            Err(PoolError::AllPoisoned)
+        } else {
+            error!("All the ArcHandles of the ArcPool are used !");
+            Err(PoolError::Exhausted)
+        }
+    }
+
+    /// Asks the pool for an `ArcHandle<T>` up to `attempts` times, yielding the thread between two attempts.
+    ///
+    /// It smooths over transient contention, when another thread is about to release an `ArcHandle<T>`.
+    /// `None` is returned if no `ArcHandle<T>` has been available during all the attempts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create_retry(3).unwrap();
+    /// assert!(pool.create_retry(3).is_none());
+    /// ```
+    pub fn create_retry(&self, attempts: usize) -> Option<ArcHandle<T>> {
+        debug!("The ArcPool is being asked an ArcHandle, in {} attempt(s).", attempts);
+        for attempt in 0..attempts {
+            if attempt > 0 {
+                trace!("Yielding the thread before the attempt {}.", attempt + 1);
+                thread::yield_now();
+            }
+            if let Some(handle) = self.create() {
+                return Some(handle);
+            }
+        }
+
+        trace!("The ArcPool could not give an ArcHandle in {} attempt(s).", attempts);
+        None
+    }
+
+    /// Acquires `n` `ArcHandle<T>`, and gives each of them to its own scoped thread, which runs `f` with a write access
+    /// to the object. The results of `f` are returned in the order of the threads.
+    ///
+    /// `None` is returned if the pool cannot give `n` `ArcHandle<T>`. In this case, no thread is spawned.
+    /// Each `ArcHandle<T>` is dropped, and its object recycled, at the end of its thread.
+    ///
+    /// # Panics
+    /// If a thread panics, the panic is propagated to the calling thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity(4, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let levels = pool.par_process(4, |monster| {
+    ///     monster.level_up();
+    ///     monster.level
+    /// }).unwrap();
+    ///
+    /// assert_eq!(levels, vec![11, 11, 11, 11]);
+    /// assert!(pool.par_process(5, |monster| monster.level).is_none());
+    /// ```
+    pub fn par_process<R, F>(&self, n: usize, f: F) -> Option<Vec<R>>
+    where
+        T: Send + Sync,
+        R: Send,
+        F: Fn(&mut T) -> R + Sync,
+    {
+        debug!("The ArcPool is processing {} ArcHandle(s) in scoped threads.", n);
+        let handles = self.create_n(n)?;
+
+        let f = &f;
+        let results = thread::scope(|scope| {
+            let workers: Vec<_> = handles
+                .into_iter()
+                .map(|handle| {
+                    scope.spawn(move || {
+                        let mut object = handle.write().expect("the lock of a pooled object is not poisoned");
+                        f(&mut object)
+                    })
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .map(|worker| worker.join().unwrap_or_else(|panic| ::std::panic::resume_unwind(panic)))
+                .collect()
+        });
+
+        Some(results)
+    }
+
+    /// Acquires `n` `ArcHandle<T>`, and gives each of them to its own thread spawned in a `crossbeam::scope`,
+    /// which runs `f` with a write access to the object. The results of `f` are returned in the order of the threads.
+    ///
+    /// It is the `crossbeam` counterpart of `par_process`, for the programs already using `crossbeam` for their
+    /// parallelism. It requires the `crossbeam` feature.
+    ///
+    /// `None` is returned if the pool cannot give `n` `ArcHandle<T>`. In this case, no thread is spawned.
+    /// Each `ArcHandle<T>` is dropped, and its object recycled, at the end of its thread.
+    ///
+    /// # Panics
+    /// If a thread panics, the panic is propagated to the calling thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity(4, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let levels = pool.crossbeam_process(4, |monster| {
+    ///     monster.level_up();
+    ///     monster.level
+    /// }).unwrap();
+    ///
+    /// assert_eq!(levels, vec![11, 11, 11, 11]);
+    /// assert!(pool.crossbeam_process(5, |monster| monster.level).is_none());
+    /// ```
+    #[cfg(feature = "crossbeam")]
+    pub fn crossbeam_process<R, F>(&self, n: usize, f: F) -> Option<Vec<R>>
+    where
+        T: Send + Sync,
+        R: Send,
+        F: Fn(&mut T) -> R + Sync,
+    {
+        debug!("The ArcPool is processing {} ArcHandle(s) in crossbeam scoped threads.", n);
+        let handles = self.create_n(n)?;
+
+        let f = &f;
+        let results = crossbeam::scope(|scope| {
+            let workers: Vec<_> = handles
+                .into_iter()
+                .map(|handle| {
+                    scope.spawn(move |_| {
+                        let mut object = handle.write().expect("the lock of a pooled object is not poisoned");
+                        f(&mut object)
+                    })
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .map(|worker| worker.join().unwrap_or_else(|panic| ::std::panic::resume_unwind(panic)))
+                .collect()
+        });
+
+        Some(results.unwrap_or_else(|panic| ::std::panic::resume_unwind(panic)))
+    }
+
+    /// Claims up to `n` non-used `ArcHandle<T>`s in a single pass over the pool, and returns them.
+    ///
+    /// Fewer `ArcHandle<T>`s are returned if the pool does not have enough non-used ones. The objects whose lock is
+    /// poisoned are skipped.
+    #[doc(hidden)]
+    pub fn claim_batch(&self, n: usize) -> Vec<ArcHandle<T>> {
+        trace!("Claiming up to {} ArcHandle(s) in a single pass.", n);
+        self.pool_slice()
+            .iter()
+            .filter(|obj| !obj.is_poisoned())
+            .filter_map(|obj| obj.try_claim())
+            .take(n)
+            .collect()
+    }
+
+    // Acquires n ArcHandles, or none of them if the pool cannot give n ArcHandles.
+    fn create_n(&self, n: usize) -> Option<Vec<ArcHandle<T>>> {
+        let mut handles = Vec::with_capacity(n);
+        for _ in 0..n {
+            match self.create() {
+                Some(handle) => handles.push(handle),
+                None => {
+                    trace!("The ArcPool could not give {} ArcHandle(s).", n);
+                    return None;
+                }
+            }
+        }
+        Some(handles)
+    }
+
+    /// Asks the pool for an `ArcHandle<T>`, and locks its object with write access in the same call, returning
+    /// an `Option<ArcHandleWrite<T>>`.
+    ///
+    /// Non-used `ArcHandle<T>` whose object is currently locked are skipped. No other thread can lock the object
+    /// between its acquisition and the return of this function. `None` is returned if no `ArcHandle<T>` is available.
+    /// This function never blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = ArcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = {
+    ///     let mut monster = pool.create_write().unwrap();
+    ///     monster.level_up();
+    ///     monster.into_handle()
+    /// };
+    ///
+    /// assert_eq!(monster.read().unwrap().level, 11);
+    /// assert!(pool.create_write().is_none());
+    /// ```
+    pub fn create_write(&self) -> Option<ArcHandleWrite<'_, T>> {
+        debug!("The ArcPool is being asked an ArcHandle, with a write lock on its object.");
+        trace!("Iterating over all the ArcHandles...");
+        for obj_ref in self.pool_slice()
+            .iter()
+            .filter(|obj| Self::is_available(obj))
+        {
+            // Lock before acquiring: the handle must not be dropped while its object is locked.
+            // The lock is taken on the Arc held by the pool, so the guard can outlive the returned handle.
+            if let Ok(object) = obj_ref.0.try_write() {
+                if let Some(handle) = obj_ref.try_claim() {
+                    trace!("A non-used ArcHandle has been locked with write access !");
+                    return Some(ArcHandleWrite::new(handle, object));
+                }
+                trace!("The ArcHandle has been claimed by another thread.");
+                continue;
+            }
+            trace!("A non-used ArcHandle is currently locked.");
+        }
+
+        trace!("The ArcPool could not find an ArcHandle with a reference count of 1 which could be locked.");
+        None
+    }
+
+    /// Asks the pool for an `ArcHandle<T>`, and locks its object with read access.
+    ///
+    /// It suits read-mostly pooled objects, like caches, acquired to be inspected. The returned `ArcHandleRead<T>`
+    /// dereferences to the object, and releases the lock before dropping the `ArcHandle<T>`.
+    ///
+    /// This function never blocks: non-used objects currently locked with write access are skipped.
+    /// `None` is returned if no non-used object can be locked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create_read().unwrap();
+    /// let another_monster = pool.create_read().unwrap();
+    /// assert_eq!(a_monster.level, 10);
+    /// assert_eq!(another_monster.level, 10);
+    /// assert!(pool.create_read().is_none());
+    ///
+    /// let a_monster = a_monster.into_handle();
+    /// assert!(a_monster.try_write().is_ok());
+    /// ```
+    pub fn create_read(&self) -> Option<ArcHandleRead<'_, T>> {
+        debug!("The ArcPool is being asked an ArcHandle, with a read lock on its object.");
+        trace!("Iterating over all the ArcHandles...");
+        for obj_ref in self.pool_slice()
+            .iter()
+            .filter(|obj| Self::is_available(obj))
+        {
+            // Lock before acquiring: the handle must not be dropped while its object is locked.
+            // The lock is taken on the Arc held by the pool, so the guard can outlive the returned handle.
+            if let Ok(object) = obj_ref.0.try_read() {
+                if let Some(handle) = obj_ref.try_claim() {
+                    trace!("A non-used ArcHandle has been locked with read access !");
+                    return Some(ArcHandleRead::new(handle, object));
+                }
+                trace!("The ArcHandle has been claimed by another thread.");
+                continue;
+            }
+            trace!("A non-used ArcHandle is currently locked.");
+        }
+
+        trace!("The ArcPool could not find an ArcHandle with a reference count of 1 which could be locked.");
+        None
+    }
+
+    /// Locks the objects of the given `ArcHandle<T>`s with write access, and returns the guards in the same order
+    /// as the handles.
+    ///
+    /// The objects are locked in the order of their slot in the pool, whatever the order of the handles. Two threads
+    /// locking the same objects with this function cannot deadlock, even if they give their handles in opposite orders.
+    /// This function blocks until all the objects are locked.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a handle does not come from this pool, if the same object is given twice, or if the
+    /// lock of an object is poisoned. The objects already locked are then unlocked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// # use std::error::Error;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let pool = ArcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create_strict()?;
+    /// let another_monster = pool.create_strict()?;
+    /// let handles = [another_monster, a_monster];
+    ///
+    /// let mut monsters = pool.write_many(&handles)?;
+    /// monsters[0].level_up();
+    /// assert_eq!(monsters[0].level, 11);
+    /// assert_eq!(monsters[1].level, 10);
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #   try_main().unwrap();
+    /// # }
+    /// ```
+    pub fn write_many<'a>(&self, handles: &'a [ArcHandle<T>]) -> PoolResult<Vec<RwLockWriteGuard<'a, T>>> {
+        debug!("Locking {} ArcHandle(s) with write access.", handles.len());
+        trace!("Finding the slots of the ArcHandles...");
+        let mut slots = Vec::with_capacity(handles.len());
+        for (position, handle) in handles.iter().enumerate() {
+            match self.pool_slice().iter().position(|obj| obj.ptr_eq(handle)) {
+                Some(index) => slots.push((index, position)),
+                None => {
+                    error!("An ArcHandle to lock does not come from this ArcPool !");
+                    return Err(PoolError::PoolError(String::from(
+                        "The ArcHandle does not come from this ArcPool !",
+                    )));
+                }
+            }
+        }
+
+        slots.sort_unstable();
+        if slots.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            error!("The same object would be locked twice !");
+            return Err(PoolError::PoolError(String::from(
+                "The same ArcHandle has been given twice !",
+            )));
+        }
+
+        trace!("Locking the ArcHandles in the order of their slots.");
+        let mut guards: Vec<Option<RwLockWriteGuard<'a, T>>> = handles.iter().map(|_| None).collect();
+        for (index, position) in slots {
+            match handles[position].write() {
+                Ok(guard) => guards[position] = Some(guard),
+                Err(_) => {
+                    error!("The lock of the object at index {} is poisoned !", index);
+                    return Err(PoolError::PoolError(format!(
+                        "The lock of the object at index {} is poisoned !",
+                        index
+                    )));
+                }
+            }
+        }
+
+        Ok(guards
+            .into_iter()
+            .map(|guard| guard.expect("every ArcHandle has been locked"))
+            .collect())
+    }
+
+    /// Returns a snapshot of the metrics of the pool.
+    ///
+    /// An `ArcPool<T>` does not track its peak usage nor its acquisitions: `peak_in_use` and `total_acquisitions` are `None`.
+    /// The poisoned objects are counted, used or not.
+    ///
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = ArcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create();
+    /// let stats = pool.stats();
+    /// assert_eq!(stats.in_use, 1);
+    /// assert_eq!(stats.unused, 2);
+    /// assert_eq!(stats.poisoned, 0);
+    /// assert_eq!(stats.peak_in_use, None);
+    /// ```
+    pub fn stats(&self) -> PoolStats {
+        debug!("Getting a snapshot of the metrics of the ArcPool.");
+        let unused = self.nb_unused();
+        PoolStats {
+            len: self.len(),
+            in_use: self.len() - unused,
+            unused,
+            peak_in_use: None,
+            total_acquisitions: None,
+            poisoned: self.pool_slice().iter().filter(|obj| obj.is_poisoned()).count(),
+        }
+    }
+
+    /// Returns the state of each slot of the pool: if its object is used, if its lock is poisoned,
+    /// and if its object is currently locked.
+    ///
+    /// The locks are probed with `try_write` and `try_read`, this function never blocks. A reader is reported only
+    /// if the object cannot be locked with write access but can be locked with read access, so the number of readers
+    /// is an estimate: 0 or 1.
+    ///
+    /// Probing the lock of a used object never blocks its `ArcHandle`s: if its last `ArcHandle` is dropped during a probe,
+    /// the reinitialization of the object is deferred until the pool hands it out again. The objects being reinitialized
+    /// are reported as locked with write access, without being probed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// let _writing = a_monster.write().unwrap();
+    ///
+    /// let health = pool.health();
+    /// assert_eq!(health.len(), 3);
+    /// assert!(health[0].in_use);
+    /// assert!(health[0].write_locked);
+    /// assert!(!health[1].in_use);
+    /// assert!(!health[1].write_locked);
+    /// ```
+    pub fn health(&self) -> Vec<SlotHealth> {
+        debug!("Getting the state of each slot of the ArcPool.");
+        self.pool_slice()
+            .iter()
+            .enumerate()
+            .map(|(index, handle)| {
+                trace!("Probing the lock of the ArcHandle at index {}.", index);
+                let (write_locked, read_lockers_estimate) = if handle.is_recycling() {
+                    trace!("The object at index {} is being reinitialized, its lock is not probed.", index);
+                    (true, 0)
+                } else {
+                    Self::probe_lock(handle)
+                };
+                SlotHealth {
+                    index,
+                    in_use: !handle.is_alone(),
+                    poisoned: handle.is_poisoned(),
+                    write_locked,
+                    read_lockers_estimate,
+                }
+            })
+            .collect()
+    }
+
+    /// Return the number of non-used `ArcHandle<T>` in the pool.
+    ///
+    /// Non-used `ArcHandle<T>` whose lock is poisoned are counted, even if `create` will not return them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = ArcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.nb_unused(), 2);
+    /// let a_monster = pool.create();
+    /// assert!(a_monster.is_some());
+    /// assert_eq!(pool.nb_unused(), 1);
+    /// ```
+    pub fn nb_unused(&self) -> usize {
+        debug!("Getting the number of unused ArcHandles in the ArcPool.");
+        trace!("Iterating over all the ArcHandles...");
+        self.pool_slice()
+            .iter()
+            .filter(|obj| obj.is_alone())
+            .count()
+    }
+
+    /// Returns the number of `ArcHandle<T>` which can be returned by `create`: the non-used `ArcHandle<T>`
+    /// whose lock is not poisoned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// use std::sync::Arc;
+    /// use std::thread;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// // Poison the lock of the first object.
+    /// let object = Arc::clone(&pool.pool_slice()[0].0);
+    /// let _ = thread::spawn(move || {
+    ///     let _guard = object.write().unwrap();
+    ///     panic!("poisoning the lock");
+    /// }).join();
+    ///
+    /// assert_eq!(pool.nb_unused(), 2);
+    /// assert_eq!(pool.nb_available(), 1);
+    /// ```
+    pub fn nb_available(&self) -> usize {
+        debug!("Getting the number of available ArcHandles in the ArcPool.");
+        trace!("Iterating over all the ArcHandles...");
+        self.pool_slice()
+            .iter()
+            .filter(|obj| Self::is_available(obj))
+            .count()
+    }
+
+    fn is_available(handle: &ArcHandle<T>) -> bool {
+        handle.is_alone() && !handle.is_poisoned()
+    }
+
+    // Returns whether the object is locked with write access, and an estimate of its number of readers.
+    fn probe_lock(handle: &ArcHandle<T>) -> (bool, usize) {
+        match handle.try_write() {
+            Ok(_) | Err(TryLockError::Poisoned(_)) => (false, 0),
+            Err(TryLockError::WouldBlock) => match handle.try_read() {
+                Ok(_) | Err(TryLockError::Poisoned(_)) => (false, 1),
+                Err(TryLockError::WouldBlock) => (true, 0),
+            },
+        }
+    }
+
+    // Claims the first available ArcHandle, preferring the ones last released by the calling thread
+    // if the pool has thread affinity. Two threads can never claim the same ArcHandle.
+    fn claim_available(&self) -> Option<ArcHandle<T>> {
+        if self.thread_affinity {
+            trace!("Iterating over the ArcHandles released by the current thread...");
+            let local = self
+                .pool_slice()
+                .iter()
+                .filter(|obj| obj.released_by_current_thread() && !obj.is_poisoned())
+                .filter_map(|obj| obj.try_claim())
+                .next();
+            if local.is_some() {
+                return local;
+            }
+        }
+
+        trace!("Iterating over all the ArcHandles...");
+        self.pool_slice()
+            .iter()
+            .filter(|obj| !obj.is_poisoned())
+            .filter_map(|obj| obj.try_claim())
+            .next()
+    }
+
+    /// Returns the total number of `ArcHandle<T>` the vector can hold without reallocating.
+    ///
+    /// This is the sum of `len()` and `reserved()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = ArcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.capacity(), 2);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        debug!("Getting the number of ArcHandle the ArcPool can hold without reallocating.");
+        self.handles.capacity()
+    }
+
+    /// Returns the number of `ArcHandle<T>` contained in the pool, used or not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        debug!("Getting the number of ArcHandle contained in the ArcPool.");
+        self.handles.len()
+    }
+
+    /// Returns `true` if the pool contains no `ArcHandle<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity(0, || {
     ///     Monster::default()
     /// });
-    /// let nb_lvl_6_monsters = pool.pool_slice()
-    /// .iter()
-    /// .filter(|handle| {
-    ///     handle.read().unwrap().level == 6
-    /// })
-    /// .count();
-    ///
-    /// //All monsters start at level 10, there is no monsters at level 6.
-    /// assert_eq!(nb_lvl_6_monsters, 0);
+    /// assert!(pool.is_empty());
     /// ```
-    pub fn pool_slice(&self) -> &[ArcHandle<T>] {
-        debug!("Getting an immutable slice of the vector containing all the ArcHandles.");
-        &self.0
+    pub fn is_empty(&self) -> bool {
+        debug!("Checking if the ArcPool contains no ArcHandle.");
+        self.handles.is_empty()
     }
 
-    /// Ask the pool for an `ArcHandle<T>`, returning a `PoolResult<ArcHandle<T>>`. If you cannot increase the pool size because of
-    /// memory restrictions, this function may be more convenient than the "non-strict" one.
-    ///
-    /// # Errors
-    /// If all `ArcHandle<T>` are used, a PoolError is returned indicating that all `ArcHandle<T>` are used.
+    /// Returns the number of extra slots allocated by the vector of `ArcHandle<T>`, which do not
+    /// contain any object yet.
     ///
     /// # Example
     ///
     /// ```rust
     /// use maskerad_object_pool::ArcPool;
     /// # use maskerad_object_pool::Recyclable;
-    /// # use std::error::Error;
     /// #
     /// # struct Monster {
     /// # hp :u32,
@@ -223,43 +1895,26 @@ impl<T: Recyclable> ArcPool<T> {
     /// #        self.level += 1;
     /// #    }
     /// # }
-    /// #
-    /// # fn try_main() -> Result<(), Box<Error>> {
-    /// let pool = ArcPool::with_capacity(1, || {
+    ///
+    /// let mut pool = ArcPool::with_capacity(2, || {
     ///     Monster::default()
     /// });
+    /// assert_eq!(pool.reserved(), 0);
     ///
-    /// let a_monster = pool.create_strict()?;
-    /// assert!(pool.create_strict().is_err());
-    /// #
-    /// #   Ok(())
-    /// # }
-    /// #
-    /// # fn main() {
-    /// #   try_main().unwrap();
-    /// # }
+    /// pool.grow(1, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.len(), 3);
+    /// assert_eq!(pool.reserved(), pool.capacity() - pool.len());
     /// ```
-    pub fn create_strict(&self) -> PoolResult<ArcHandle<T>> {
-        debug!("The ArcPool is being asked an ArcHandle (strict).");
-        trace!("Iterating over all the ArcHandles...");
-        match self.pool_slice()
-            .iter()
-            .find(|obj| Arc::strong_count(obj.as_ref()) == 1)
-        {
-            Some(obj_ref) => {
-                trace!("An ArcHandle with a reference count of 1 has been found !");
-                Ok(obj_ref.clone())
-            },
-            None => {
-                error!("The ArcPool could not find an ArcHandle with a reference count of 1 !");
-                Err(PoolError::PoolError(String::from(
-                    "The ArcPool is out of objects !",
-                )))
-            },
-        }
+    pub fn reserved(&self) -> usize {
+        debug!("Getting the number of spare slots of the ArcPool.");
+        self.handles.capacity() - self.handles.len()
     }
 
-    /// Asks the pool for an `ArcHandle<T>`, returning an `Option<ArcHandle<T>>`.
+    /// Adds `additional` objects to the pool, created with the given closure.
+    ///
+    /// The vector of `ArcHandle<T>` may reallocate, and reserve more slots than needed.
     ///
     /// # Example
     ///
@@ -292,40 +1947,36 @@ impl<T: Recyclable> ArcPool<T> {
     /// #        self.level += 1;
     /// #    }
     /// # }
-    /// let pool = ArcPool::with_capacity(1, || {
+    ///
+    /// let mut pool = ArcPool::with_capacity(2, || {
     ///     Monster::default()
     /// });
     ///
-    /// let a_monster = pool.create();
-    /// assert!(a_monster.is_some());
-    /// assert!(pool.create().is_none());
-    ///
-    /// match pool.create() {
-    ///     Some(monster) => println!("will not happen."),
-    ///     None => {
-    ///         // do something, or nothing.
-    ///     },
-    /// }
+    /// pool.grow(3, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.len(), 5);
+    /// assert_eq!(pool.nb_unused(), 5);
     /// ```
-    pub fn create(&self) -> Option<ArcHandle<T>> {
-        debug!("The ArcPool is being asked an ArcHandle.");
-        trace!("Iterating over all the ArcHandles...");
-        match self.pool_slice()
-            .iter()
-            .find(|obj| Arc::strong_count(obj.as_ref()) == 1)
-        {
-            Some(obj_ref) => {
-                trace!("An ArcHandle with a reference count of 1 has been found !");
-                Some(obj_ref.clone())
-            },
-            None => {
-                trace!("The ArcPool could not find an ArcHandle with a reference count of 1.");
-                None
-            },
+    pub fn grow<F>(&mut self, additional: usize, mut op: F)
+    where
+        F: FnMut() -> T,
+    {
+        debug!("Growing the ArcPool by {} ArcHandle(s).", additional);
+        self.handles.reserve(additional);
+
+        for _ in 0..additional {
+            let recycler = self.recycler.as_ref().map(|recycler| recycler.sender());
+            self.handles.push(ArcHandle::pooled(op(), self.recycle, self.id, recycler, self.thread_affinity));
         }
     }
 
-    /// Return the number of non-used `ArcHandle<T>` in the pool.
+    /// Converts this `ArcPool<T>` into an `RcPool<T>` holding the same objects, in the same order.
+    ///
+    /// The conversion requires that no `ArcHandle<T>` is outstanding. If some objects are used,
+    /// the `ArcPool<T>` is given back.
+    ///
+    /// The `RcPool<T>` has a new `PoolId`. The objects are recycled the same way as in the `ArcPool<T>`.
     ///
     /// # Example
     ///
@@ -333,6 +1984,7 @@ impl<T: Recyclable> ArcPool<T> {
     /// use maskerad_object_pool::ArcPool;
     /// # use maskerad_object_pool::Recyclable;
     /// #
+    /// # #[derive(Debug)]
     /// # struct Monster {
     /// # hp :u32,
     /// # pub level: u32,
@@ -358,24 +2010,47 @@ impl<T: Recyclable> ArcPool<T> {
     /// #        self.level += 1;
     /// #    }
     /// # }
+    ///
     /// let pool = ArcPool::with_capacity(2, || {
     ///     Monster::default()
     /// });
-    /// assert_eq!(pool.nb_unused(), 2);
-    /// let a_monster = pool.create();
-    /// assert!(a_monster.is_some());
-    /// assert_eq!(pool.nb_unused(), 1);
+    /// pool.pool_slice()[1].write().unwrap().level_up();
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// let pool = pool.into_rc_pool().unwrap_err();
+    /// drop(a_monster);
+    ///
+    /// let rc_pool = pool.into_rc_pool().unwrap();
+    /// assert_eq!(rc_pool.pool_slice()[1].borrow().level, 11);
     /// ```
-    pub fn nb_unused(&self) -> usize {
-        debug!("Getting the number of unused ArcHandles in the ArcPool.");
-        trace!("Iterating over all the ArcHandles...");
-        self.pool_slice()
-            .iter()
-            .filter(|obj| Arc::strong_count(obj.as_ref()) == 1)
-            .count()
+    pub fn into_rc_pool(self) -> Result<RcPool<T>, ArcPool<T>> {
+        debug!("Converting the ArcPool into an RcPool.");
+        if self.handles.iter().any(|handle| Arc::strong_count(handle.as_ref()) != 1) {
+            trace!("Some ArcHandles of the ArcPool are outstanding.");
+            return Err(self);
+        }
+
+        let recycle = self.recycle;
+        let objects = self.handles
+            .into_iter()
+            .map(|handle| match handle.try_into_inner() {
+                Ok(object) => object,
+                Err(_) => unreachable!("an unused ArcHandle is the only owner of its object"),
+            })
+            .collect();
+
+        Ok(RcPool::from_objects(objects, recycle))
     }
 
-    /// Returns the maximum capacity of the vector of `ArcHandle<T>`.
+    /// Consumes the pool, and returns all its objects in slot order, if none of them is used.
+    ///
+    /// It helps to reclaim the objects on a graceful shutdown. The objects are not reinitialized, and the objects
+    /// whose lock is poisoned are returned too.
+    ///
+    /// # Errors
+    ///
+    /// The pool is returned unchanged if an `ArcHandle<T>` is still used, or if an object is being recycled by the
+    /// background recycler of the pool.
     ///
     /// # Example
     ///
@@ -383,6 +2058,7 @@ impl<T: Recyclable> ArcPool<T> {
     /// use maskerad_object_pool::ArcPool;
     /// # use maskerad_object_pool::Recyclable;
     /// #
+    /// # #[derive(Debug)]
     /// # struct Monster {
     /// # hp :u32,
     /// # pub level: u32,
@@ -408,21 +2084,80 @@ impl<T: Recyclable> ArcPool<T> {
     /// #        self.level += 1;
     /// #    }
     /// # }
+    ///
     /// let pool = ArcPool::with_capacity(2, || {
     ///     Monster::default()
     /// });
-    /// assert_eq!(pool.capacity(), 2);
+    /// pool.pool_slice()[1].write().unwrap().level_up();
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// let pool = pool.into_values().unwrap_err();
+    /// drop(a_monster);
+    ///
+    /// let monsters = pool.into_values().unwrap();
+    /// assert_eq!(monsters.len(), 2);
+    /// assert_eq!(monsters[1].level, 11);
     /// ```
-    pub fn capacity(&self) -> usize {
-        debug!("Getting the number of ArcHandle contained in the ArcPool.");
-        self.0.capacity()
+    pub fn into_values(self) -> Result<Vec<T>, ArcPool<T>> {
+        debug!("Taking all the objects out of the ArcPool.");
+        if self.handles.iter().any(|handle| Arc::strong_count(handle.as_ref()) != 1) {
+            trace!("Some ArcHandles of the ArcPool are outstanding.");
+            return Err(self);
+        }
+
+        Ok(self.handles
+            .into_iter()
+            .map(|handle| match handle.try_into_inner() {
+                Ok(object) => object,
+                Err(_) => unreachable!("an unused ArcHandle is the only owner of its object"),
+            })
+            .collect())
+    }
+}
+
+// The objects are dumped only with the alternate formatter, `{:#?}`, to keep the logs readable.
+impl<T: Recyclable + fmt::Debug> fmt::Debug for ArcPool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let in_use = self.handles.iter().filter(|handle| !handle.is_alone()).count();
+        let alternate = f.alternate();
+        let mut debug = f.debug_struct("ArcPool");
+        debug
+            .field("len", &self.handles.len())
+            .field("in_use", &in_use)
+            .field("capacity", &self.handles.capacity());
+        if alternate {
+            debug.field("id", &self.id).field("objects", &self.handles);
+        }
+        debug.finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Recyclable + Serialize> Serialize for ArcPool<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        debug!("Serializing the ArcPool.");
+        serializer.collect_seq(self.handles.iter().map(|obj| &*obj.0))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Recyclable + Deserialize<'de>> Deserialize<'de> for ArcPool<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        debug!("Deserializing an ArcPool.");
+        let objects = Vec::<T>::deserialize(deserializer)?;
+        let size = objects.len();
+        let mut objects = objects.into_iter();
+        let mut pool = ArcPool::with_capacity(0, || unreachable!());
+        pool.grow(size, || objects.next().expect("as many objects as the size of the ArcPool"));
+        Ok(pool)
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::redundant_closure)]
 mod refcounted_objectpool_tests {
     use super::*;
-    use std::sync::Arc;
+    use std::sync::{Arc, Barrier};
     use pool_object::Recyclable;
 
     #[derive(Ord, PartialOrd, Eq, PartialEq, Debug)]
@@ -546,4 +2281,529 @@ mod refcounted_objectpool_tests {
         let _monster = monster_pool.create_strict().unwrap();
         assert!(monster_pool.create_strict().is_err());
     }
+
+    #[test]
+    fn test_handle_ptr_eq() {
+        let monster_pool = ArcPool::with_capacity(2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        let same_monster = monster.clone();
+        let other_monster = monster_pool.create().unwrap();
+
+        assert!(monster.ptr_eq(&same_monster));
+        assert!(same_monster.ptr_eq(&monster));
+        assert!(!monster.ptr_eq(&other_monster));
+    }
+
+    #[test]
+    fn test_len_reserved_capacity() {
+        let mut monster_pool = ArcPool::with_capacity(4, Monster::default);
+        assert_eq!(monster_pool.len(), 4);
+        assert_eq!(monster_pool.reserved(), 0);
+        assert_eq!(monster_pool.capacity(), 4);
+
+        monster_pool.grow(1, Monster::default);
+        assert_eq!(monster_pool.len(), 5);
+        assert!(monster_pool.capacity() > 4);
+        assert_eq!(monster_pool.reserved(), monster_pool.capacity() - 5);
+        assert_eq!(monster_pool.nb_unused(), 5);
+    }
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize_on_release() {
+        use zeroize::Zeroize;
+
+        #[derive(Default)]
+        struct Secret {
+            key: [u8; 16],
+            used: bool,
+        }
+
+        impl Zeroize for Secret {
+            fn zeroize(&mut self) {
+                self.key.zeroize();
+            }
+        }
+
+        impl Recyclable for Secret {
+            fn reinitialize(&mut self) {
+                self.used = false;
+            }
+        }
+
+        let secret_pool = ArcPool::with_capacity_zeroizing(1, Secret::default);
+        {
+            let secret = secret_pool.create().unwrap();
+            secret.write().unwrap().key = [0xAB; 16];
+            secret.write().unwrap().used = true;
+        }
+
+        let secret = secret_pool.pool_slice()[0].read().unwrap();
+        assert_eq!(secret.key, [0; 16]);
+        assert!(!secret.used);
+    }
+    #[test]
+    fn test_pool_id() {
+        let monster_pool = ArcPool::with_capacity(1, Monster::default);
+        let other_pool = ArcPool::with_capacity(1, Monster::default);
+        assert_ne!(monster_pool.id(), other_pool.id());
+
+        let monster = monster_pool.create().unwrap();
+        let other_monster = other_pool.create().unwrap();
+        assert_eq!(monster.pool_id(), Some(monster_pool.id()));
+        assert_eq!(other_monster.pool_id(), Some(other_pool.id()));
+    }
+    #[test]
+    fn test_with_capacity_indexed() {
+        let monster_pool = ArcPool::with_capacity_indexed(5, |index| Monster {
+            level: index as u8,
+            ..Default::default()
+        });
+        assert_eq!(monster_pool.len(), 5);
+        for (index, monster) in monster_pool.pool_slice().iter().enumerate() {
+            assert_eq!(monster.read().unwrap().level() as usize, index);
+        }
+    }
+    #[test]
+    fn test_create_write() {
+        let monster_pool = ArcPool::with_capacity(2, Monster::default);
+        {
+            let mut monster = monster_pool.create_write().unwrap();
+            monster.level_up();
+            assert_eq!(monster.level(), 11);
+            assert_eq!(monster_pool.nb_unused(), 1);
+            // The object is exclusively locked.
+            assert!(monster.handle().try_read().is_err());
+            assert!(monster.handle().try_write().is_err());
+        }
+        assert_eq!(monster_pool.nb_unused(), 2);
+        assert_eq!(monster_pool.pool_slice()[0].read().unwrap().level(), 1);
+
+        let monster = monster_pool.create_write().unwrap().into_handle();
+        assert!(monster.try_write().is_ok());
+        drop(monster);
+        assert_eq!(monster_pool.nb_unused(), 2);
+    }
+
+    #[test]
+    fn test_create_write_skips_locked() {
+        let monster_pool = ArcPool::with_capacity(2, Monster::default);
+        let lock = monster_pool.pool_slice()[0].write().unwrap();
+        let monster = monster_pool.create_write().unwrap();
+        assert!(monster.handle().ptr_eq(&monster_pool.pool_slice()[1]));
+        assert!(monster_pool.create_write().is_none());
+        drop(monster);
+        drop(lock);
+        assert_eq!(monster_pool.nb_unused(), 2);
+    }
+    #[test]
+    fn test_create_skips_poisoned() {
+        let monster_pool = ArcPool::with_capacity(2, Monster::default);
+        let object = Arc::clone(&monster_pool.pool_slice()[0].0);
+        let result = thread::spawn(move || {
+            let _guard = object.write().unwrap();
+            panic!("poisoning the lock");
+        }).join();
+        assert!(result.is_err());
+        assert!(monster_pool.pool_slice()[0].is_poisoned());
+        assert_eq!(monster_pool.nb_unused(), 2);
+        assert_eq!(monster_pool.nb_available(), 1);
+
+        let monster = monster_pool.create().unwrap();
+        assert!(monster.ptr_eq(&monster_pool.pool_slice()[1]));
+        assert!(monster.read().is_ok());
+        assert!(monster_pool.create().is_none());
+        assert!(monster_pool.create_strict().is_err());
+        assert!(monster_pool.create_write().is_none());
+    }
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_with_capacity_parallel() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let built = AtomicUsize::new(0);
+        let monster_pool = ArcPool::with_capacity_parallel(10_000, || {
+            built.fetch_add(1, Ordering::Relaxed);
+            Monster::default()
+        });
+
+        assert_eq!(built.load(Ordering::Relaxed), 10_000);
+        assert_eq!(monster_pool.len(), 10_000);
+        assert_eq!(monster_pool.nb_unused(), 10_000);
+        assert!(monster_pool
+            .pool_slice()
+            .iter()
+            .all(|monster| monster.read().unwrap().level() == 10 && monster.pool_id() == Some(monster_pool.id())));
+
+        let monster = monster_pool.create().unwrap();
+        monster.write().unwrap().level_up();
+        drop(monster);
+        assert_eq!(monster_pool.pool_slice()[0].read().unwrap().level(), 1);
+    }
+    #[test]
+    fn test_create_retry() {
+        let monster_pool = Arc::new(ArcPool::with_capacity(1, Monster::default));
+        let barrier = Arc::new(Barrier::new(2));
+        assert!(monster_pool.create_retry(0).is_none());
+
+        let monster = monster_pool.create().unwrap();
+        assert!(monster_pool.create_retry(10).is_none());
+
+        let releaser = {
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                drop(monster);
+            })
+        };
+
+        barrier.wait();
+        let monster = monster_pool.create_retry(100_000_000).unwrap();
+        assert_eq!(monster.read().unwrap().level(), 1);
+        releaser.join().unwrap();
+    }
+    #[test]
+    fn test_handle_is_unique() {
+        let monster_pool = ArcPool::with_capacity(2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        assert!(monster.is_unique());
+
+        let same_monster = monster.clone();
+        assert!(!monster.is_unique());
+        assert!(!same_monster.is_unique());
+
+        drop(same_monster);
+        assert!(monster.is_unique());
+        assert!(ArcHandle::new(Monster::default()).is_unique());
+    }
+    #[test]
+    fn test_with_capacity_consistent() {
+        let monster_pool = ArcPool::with_capacity_consistent(3, Monster::default);
+        assert!(monster_pool
+            .pool_slice()
+            .iter()
+            .all(|monster| monster.read().unwrap().level() == 1 && monster.read().unwrap().hp() == 1));
+
+        let fresh_level = monster_pool.create().unwrap().read().unwrap().level();
+        let recycled_level = monster_pool.create().unwrap().read().unwrap().level();
+        assert_eq!(fresh_level, recycled_level);
+    }
+    #[test]
+    fn test_par_process() {
+        let monster_pool = ArcPool::with_capacity_indexed(5, |index| Monster {
+            level: index as u8,
+            ..Default::default()
+        });
+        let _monster = monster_pool.create().unwrap();
+
+        let levels = monster_pool
+            .par_process(4, |monster| {
+                monster.level_up();
+                (thread::current().id(), monster.level())
+            })
+            .unwrap();
+        let level_values: Vec<u8> = levels.iter().map(|&(_, level)| level).collect();
+        assert_eq!(level_values, vec![2, 3, 4, 5]);
+        assert!(levels.iter().all(|&(id, _)| id != thread::current().id()));
+        assert_eq!(monster_pool.nb_unused(), 4);
+        assert_eq!(monster_pool.pool_slice()[1].read().unwrap().level(), 1);
+
+        assert!(monster_pool.par_process(5, |monster| monster.level()).is_none());
+        assert_eq!(monster_pool.nb_unused(), 4);
+    }
+    #[test]
+    fn test_write_many_opposite_orders() {
+        let monster_pool = Arc::new(ArcPool::with_capacity(2, Monster::default));
+        let a_monster = monster_pool.create().unwrap();
+        let another_monster = monster_pool.create().unwrap();
+        let barrier = Arc::new(Barrier::new(2));
+
+        let threads: Vec<_> = vec![
+            vec![a_monster.clone(), another_monster.clone()],
+            vec![another_monster.clone(), a_monster.clone()],
+        ].into_iter()
+            .map(|handles| {
+                let monster_pool = monster_pool.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    for _ in 0..1000 {
+                        let mut monsters = monster_pool.write_many(&handles).unwrap();
+                        monsters[0].hp += 1;
+                        thread::yield_now();
+                        monsters[1].hp += 1;
+                    }
+                })
+            })
+            .collect();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(a_monster.read().unwrap().hp(), 2010);
+        assert_eq!(another_monster.read().unwrap().hp(), 2010);
+    }
+    #[test]
+    fn test_write_many_errors() {
+        let monster_pool = ArcPool::with_capacity(2, Monster::default);
+        let other_pool = ArcPool::with_capacity(1, Monster::default);
+        let a_monster = monster_pool.create().unwrap();
+        let foreign_monster = other_pool.create().unwrap();
+
+        assert!(monster_pool.write_many(&[a_monster.clone(), foreign_monster]).is_err());
+        assert!(monster_pool.write_many(&[a_monster.clone(), a_monster.clone()]).is_err());
+
+        // The objects locked before the error are unlocked.
+        assert!(a_monster.try_write().is_ok());
+        assert_eq!(monster_pool.write_many(&[]).unwrap().len(), 0);
+    }
+    #[test]
+    fn test_stats() {
+        let monster_pool = ArcPool::with_capacity(3, Monster::default);
+        let object = Arc::clone(&monster_pool.pool_slice()[0].0);
+        let result = thread::spawn(move || {
+            let _guard = object.write().unwrap();
+            panic!("poisoning the lock");
+        }).join();
+        assert!(result.is_err());
+        let _monster = monster_pool.create().unwrap();
+
+        let stats = monster_pool.stats();
+        assert_eq!(stats.len, 3);
+        assert_eq!(stats.in_use, 1);
+        assert_eq!(stats.unused, 2);
+        assert_eq!(stats.poisoned, 1);
+        assert_eq!(stats.total_acquisitions, None);
+    }
+    #[test]
+    fn test_with_thread_affinity() {
+        let monster_pool = ArcPool::with_thread_affinity(3, Monster::default);
+        let first = monster_pool.create().unwrap();
+        let second = monster_pool.create().unwrap();
+        let _third = monster_pool.create().unwrap();
+
+        drop(second);
+        thread::spawn(move || drop(first)).join().unwrap();
+
+        // The first slot is free, but the second one has been released by this thread.
+        let monster = monster_pool.create().unwrap();
+        assert!(monster.ptr_eq(&monster_pool.pool_slice()[1]));
+
+        // Falls back to any free slot.
+        let monster = monster_pool.create().unwrap();
+        assert!(monster.ptr_eq(&monster_pool.pool_slice()[0]));
+    }
+    #[cfg(feature = "crossbeam")]
+    #[test]
+    fn test_crossbeam_process() {
+        let monster_pool = ArcPool::with_capacity_indexed(5, |index| Monster {
+            level: index as u8,
+            ..Default::default()
+        });
+        let _monster = monster_pool.create().unwrap();
+
+        let levels = monster_pool
+            .crossbeam_process(4, |monster| {
+                monster.level_up();
+                (thread::current().id(), monster.level())
+            })
+            .unwrap();
+        let level_values: Vec<u8> = levels.iter().map(|&(_, level)| level).collect();
+        assert_eq!(level_values, vec![2, 3, 4, 5]);
+        assert!(levels.iter().all(|&(id, _)| id != thread::current().id()));
+        assert_eq!(monster_pool.nb_unused(), 4);
+
+        assert!(monster_pool.crossbeam_process(5, |monster| monster.level()).is_none());
+        assert_eq!(monster_pool.nb_unused(), 4);
+    }
+    #[test]
+    fn test_create_detailed() {
+        let monster_pool = ArcPool::with_capacity(2, Monster::default);
+        let object = Arc::clone(&monster_pool.pool_slice()[0].0);
+        let result = thread::spawn(move || {
+            let _guard = object.write().unwrap();
+            panic!("poisoning the lock");
+        }).join();
+        assert!(result.is_err());
+
+        let monster = monster_pool.create_detailed().unwrap();
+        assert!(monster.ptr_eq(&monster_pool.pool_slice()[1]));
+        match monster_pool.create_detailed() {
+            Err(PoolError::AllPoisoned) => {}
+            _ => panic!("The only non-used monster is poisoned."),
+        }
+
+        let monster_pool = ArcPool::with_capacity(1, Monster::default);
+        let _monster = monster_pool.create_detailed().unwrap();
+        match monster_pool.create_detailed() {
+            Err(PoolError::Exhausted) => {}
+            _ => panic!("All the monsters are used."),
+        }
+    }
+    #[test]
+    fn test_debug() {
+        let monster_pool = ArcPool::with_capacity(2, Monster::default);
+        let _monster = monster_pool.create().unwrap();
+
+        assert_eq!(
+            format!("{:?}", monster_pool),
+            format!("ArcPool {{ len: 2, in_use: 1, capacity: {} }}", monster_pool.capacity())
+        );
+
+        let alternate = format!("{:#?}", monster_pool);
+        assert!(alternate.starts_with("ArcPool {\n    len: 2,\n    in_use: 1,\n"));
+        assert!(alternate.contains("objects: ["));
+        assert_eq!(alternate.matches("default name").count(), 2);
+    }
+    #[test]
+    fn test_handle_replace() {
+        let monster_pool = ArcPool::with_capacity(2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster.write().unwrap().level_up();
+
+        let replacement = Monster {
+            name: String::from("replacement"),
+            level: 42,
+            hp: 7,
+        };
+        let old_monster = monster.replace(replacement).unwrap();
+        assert_eq!(old_monster.level(), 11);
+        assert_eq!(monster.read().unwrap().level(), 42);
+        assert_eq!(monster.read().unwrap().name, "replacement");
+
+        // A poisoned object is replaced too.
+        let object = Arc::clone(&monster_pool.pool_slice()[1].0);
+        let result = thread::spawn(move || {
+            let _guard = object.write().unwrap();
+            panic!("poisoning the lock");
+        }).join();
+        assert!(result.is_err());
+        let old_monster = monster_pool.pool_slice()[1]
+            .replace(Monster {
+                name: String::from("cured"),
+                level: 3,
+                hp: 3,
+            })
+            .unwrap_err()
+            .into_inner();
+        assert_eq!(old_monster.level(), 10);
+        let poisoned = &monster_pool.pool_slice()[1].0;
+        assert_eq!(poisoned.read().unwrap_or_else(|error| error.into_inner()).level(), 3);
+    }
+    #[test]
+    fn test_health() {
+        let monster_pool = ArcPool::with_capacity(4, Monster::default);
+        let object = Arc::clone(&monster_pool.pool_slice()[0].0);
+        let result = thread::spawn(move || {
+            let _guard = object.write().unwrap();
+            panic!("poisoning the lock");
+        }).join();
+        assert!(result.is_err());
+
+        let written_monster = monster_pool.create().unwrap();
+        let read_monster = monster_pool.create().unwrap();
+        let _writing = written_monster.write().unwrap();
+        let _reading = read_monster.read().unwrap();
+        let _other_reading = read_monster.read().unwrap();
+
+        let health = monster_pool.health();
+        assert_eq!(
+            health,
+            vec![
+                SlotHealth {
+                    index: 0,
+                    in_use: false,
+                    poisoned: true,
+                    write_locked: false,
+                    read_lockers_estimate: 0,
+                },
+                SlotHealth {
+                    index: 1,
+                    in_use: true,
+                    poisoned: false,
+                    write_locked: true,
+                    read_lockers_estimate: 0,
+                },
+                SlotHealth {
+                    index: 2,
+                    in_use: true,
+                    poisoned: false,
+                    write_locked: false,
+                    read_lockers_estimate: 1,
+                },
+                SlotHealth {
+                    index: 3,
+                    in_use: false,
+                    poisoned: false,
+                    write_locked: false,
+                    read_lockers_estimate: 0,
+                },
+            ]
+        );
+    }
+    #[test]
+    fn test_create_read() {
+        let monster_pool = ArcPool::with_capacity(3, Monster::default);
+        monster_pool.pool_slice()[0].write().unwrap().level_up();
+        monster_pool.pool_slice()[1].write().unwrap().level_up();
+
+        {
+            let first_monster = monster_pool.create_read().unwrap();
+            let second_monster = monster_pool.create_read().unwrap();
+            assert!(first_monster.handle().ptr_eq(&monster_pool.pool_slice()[0]));
+            assert!(second_monster.handle().ptr_eq(&monster_pool.pool_slice()[1]));
+            assert_eq!(first_monster.level(), 11);
+            assert_eq!(second_monster.level(), 11);
+            assert!(first_monster.handle().try_write().is_err());
+            assert_eq!(monster_pool.nb_unused(), 1);
+        }
+
+        // Both the locks and the handles have been released, the objects have been recycled.
+        assert_eq!(monster_pool.nb_unused(), 3);
+        assert_eq!(monster_pool.pool_slice()[0].read().unwrap().level(), 1);
+        assert_eq!(monster_pool.pool_slice()[1].read().unwrap().level(), 1);
+
+        let _writing = monster_pool.pool_slice()[0].write().unwrap();
+        let monster = monster_pool.create_read().unwrap();
+        assert!(monster.handle().ptr_eq(&monster_pool.pool_slice()[1]));
+    }
+    #[test]
+    fn test_into_values() {
+        let monster_pool = ArcPool::with_capacity(3, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster.write().unwrap().level_up();
+        let another_monster = monster_pool.create().unwrap();
+
+        let monster_pool = monster_pool.into_values().unwrap_err();
+        assert_eq!(monster_pool.len(), 3);
+        assert_eq!(monster_pool.nb_unused(), 1);
+        drop(monster);
+        let monster_pool = monster_pool.into_values().unwrap_err();
+        assert_eq!(monster_pool.nb_unused(), 2);
+
+        another_monster.write().unwrap().level_up();
+        drop(another_monster);
+        let monsters = monster_pool.into_values().unwrap();
+        assert_eq!(monsters.len(), 3);
+        // The objects have been reinitialized when they were released, not when they were taken out.
+        assert_eq!(monsters[0].level(), 1);
+        assert_eq!(monsters[1].level(), 1);
+        assert_eq!(monsters[2].level(), 10);
+    }    #[test]
+    fn test_drop_while_the_caller_reads_the_slot() {
+        let monster_pool = ArcPool::with_capacity(1, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster.write().unwrap().level_up();
+
+        // Dropping the last ArcHandle while its slot is read neither blocks nor panics.
+        let reading = monster_pool.pool_slice()[0].read().unwrap();
+        drop(monster);
+        assert_eq!(monster_pool.nb_unused(), 1);
+        // The object cannot be reinitialized while it is read, so it is not handed out.
+        assert!(monster_pool.create().is_none());
+        drop(reading);
+
+        let monster = monster_pool.create().unwrap();
+        assert_eq!(monster.read().unwrap().level(), 1);
+    }
 }
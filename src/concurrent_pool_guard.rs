@@ -0,0 +1,104 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::sync::{RwLockReadGuard, RwLockWriteGuard};
+use std::ops::{Deref, DerefMut};
+use concurrent_pool_handler::ArcHandle;
+use pool_object::Recyclable;
+
+/// An `ArcHandle<T>` returned with a write lock on its object, by `ArcPool::create_write`.
+///
+/// It dereferences to the pooled object. The lock is borrowed from the `ArcPool`, and is always released before the
+/// `ArcHandle<T>` is dropped, so the object can be recycled.
+///
+/// Use `into_handle` to release the lock and keep the `ArcHandle<T>`.
+#[derive(Debug)]
+pub struct ArcHandleWrite<'a, T: Recyclable + 'a> {
+    // Fields are dropped in declaration order: the lock must be released first.
+    object: RwLockWriteGuard<'a, T>,
+    handle: ArcHandle<T>,
+}
+
+impl<'a, T: Recyclable> ArcHandleWrite<'a, T> {
+    #[doc(hidden)]
+    pub fn new(handle: ArcHandle<T>, object: RwLockWriteGuard<'a, T>) -> Self {
+        debug!("Creating an ArcHandleWrite.");
+        ArcHandleWrite { object, handle }
+    }
+
+    /// Returns a reference to the `ArcHandle<T>` of the locked object.
+    pub fn handle(&self) -> &ArcHandle<T> {
+        debug!("Getting the ArcHandle of the ArcHandleWrite.");
+        &self.handle
+    }
+
+    /// Releases the write lock, and returns the `ArcHandle<T>`.
+    pub fn into_handle(self) -> ArcHandle<T> {
+        debug!("Releasing the write lock of the ArcHandleWrite.");
+        let ArcHandleWrite { object, handle } = self;
+        drop(object);
+        handle
+    }
+}
+
+impl<'a, T: Recyclable> Deref for ArcHandleWrite<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.object
+    }
+}
+
+impl<'a, T: Recyclable> DerefMut for ArcHandleWrite<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.object
+    }
+}
+
+/// An `ArcHandle<T>` returned with a read lock on its object, by `ArcPool::create_read`.
+///
+/// It dereferences to the pooled object. The lock is borrowed from the `ArcPool`, and is always released before the
+/// `ArcHandle<T>` is dropped, so the object can be recycled. A `(ArcHandle<T>, RwLockReadGuard<T>)` tuple would drop
+/// the handle first, and the recycling of the object would wait for a lock which is never released.
+///
+/// Use `into_handle` to release the lock and keep the `ArcHandle<T>`.
+#[derive(Debug)]
+pub struct ArcHandleRead<'a, T: Recyclable + 'a> {
+    // Fields are dropped in declaration order: the lock must be released first.
+    object: RwLockReadGuard<'a, T>,
+    handle: ArcHandle<T>,
+}
+
+impl<'a, T: Recyclable> ArcHandleRead<'a, T> {
+    #[doc(hidden)]
+    pub fn new(handle: ArcHandle<T>, object: RwLockReadGuard<'a, T>) -> Self {
+        debug!("Creating an ArcHandleRead.");
+        ArcHandleRead { object, handle }
+    }
+
+    /// Returns a reference to the `ArcHandle<T>` of the locked object.
+    pub fn handle(&self) -> &ArcHandle<T> {
+        debug!("Getting the ArcHandle of the ArcHandleRead.");
+        &self.handle
+    }
+
+    /// Releases the read lock, and returns the `ArcHandle<T>`.
+    pub fn into_handle(self) -> ArcHandle<T> {
+        debug!("Releasing the read lock of the ArcHandleRead.");
+        let ArcHandleRead { object, handle } = self;
+        drop(object);
+        handle
+    }
+}
+
+impl<'a, T: Recyclable> Deref for ArcHandleRead<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.object
+    }
+}
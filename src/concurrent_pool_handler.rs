@@ -5,9 +5,22 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
-use std::sync::{Arc, LockResult, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError,
+use std::mem;
+use std::sync::{Arc, LockResult, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError,
                 TryLockResult};
-use pool_object::Recyclable;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use pool_object::{skip_recycle, Recyclable};
+use pool_id::PoolId;
+use background_recycler::{RecycleJob, RecycleSender};
+
+// The number of ArcHandles sharing an object while it is being recycled.
+const RECYCLING: usize = usize::MAX;
+
+/// The number of `ArcHandle`s sharing a non-used object whose reinitialization has been deferred, because its lock
+/// was held when its last `ArcHandle` was dropped. The object is reinitialized before being handed out again.
+#[doc(hidden)]
+pub const DEFERRED: usize = usize::MAX - 1;
 
 /// A wrapper around a `Arc` pointer to a `RwLock<Poolable>` object.
 ///
@@ -19,8 +32,37 @@ use pool_object::Recyclable;
 /// This wrapper allows a custom `Drop` implementation: when an `ArcHandle` is dropped, the contained `Poolable` object is reinitialized
 /// if its strong reference count is equal to two. If it is the case, the object is reinitialized, the inner `Arc` is dropped and the strong
 /// reference count decrease to 1, meaning that the only structure holding a reference is the `ArcPool` itself.
+///
+/// The `ArcHandle`s sharing an object are counted atomically, so the object is reinitialized exactly once when the
+/// last of them is dropped, even if several of them are dropped at the same time on different threads. It can be dropped
+/// on another thread than the one which acquired it.
+///
+/// If its pool has been created with `ArcPool::with_background_recycler`, the object is sent to the recycler thread
+/// of the pool instead of being reinitialized in the `Drop` implementation.
+///
+/// If the object is locked when the last `ArcHandle` is dropped, for example through `ArcPool::pool_slice`, it cannot be
+/// reinitialized without blocking: its reinitialization is deferred, and done by the pool right before handing it out.
+///
+/// If its pool has been created with `ArcPool::with_thread_affinity`, the thread releasing the object is recorded,
+/// so the pool can give the object back to the same thread.
+///
+/// Use `ptr_eq` to know if two handles point to the *same* object.
 #[derive(Debug)]
-pub struct ArcHandle<T: Recyclable>(pub Arc<RwLock<T>>);
+pub struct ArcHandle<T: Recyclable>(
+    pub Arc<RwLock<T>>,
+    fn(&mut T),
+    Option<PoolId>,
+    Arc<AtomicUsize>,
+    Option<RecycleSender<T>>,
+    Option<Arc<AtomicUsize>>,
+);
+
+// Returns a token identifying the calling thread, never 0.
+fn current_thread_token() -> usize {
+    static NEXT_TOKEN: AtomicUsize = AtomicUsize::new(1);
+    thread_local!(static TOKEN: usize = NEXT_TOKEN.fetch_add(1, Ordering::Relaxed));
+    TOKEN.with(|token| *token)
+}
 
 impl<T: Recyclable> AsRef<Arc<RwLock<T>>> for ArcHandle<T> {
     fn as_ref(&self) -> &Arc<RwLock<T>> {
@@ -33,7 +75,174 @@ impl<T: Recyclable> ArcHandle<T> {
     #[doc(hidden)]
     pub fn new(item: T) -> Self {
         debug!("Creating a new ArcHandle.");
-        ArcHandle(Arc::new(RwLock::new(item)))
+        ArcHandle(Arc::new(RwLock::new(item)), T::reinitialize, None, Arc::new(AtomicUsize::new(1)), None, None)
+    }
+
+    /// Creates a new `ArcHandle` from a `Recyclable` object, belonging to the given pool and
+    /// recycled with the given function, by the background recycler of the pool if any.
+    ///
+    /// If `track_releaser` is `true`, the thread releasing the object is recorded.
+    #[doc(hidden)]
+    pub fn pooled(
+        item: T,
+        recycle: fn(&mut T),
+        pool_id: PoolId,
+        recycler: Option<RecycleSender<T>>,
+        track_releaser: bool,
+    ) -> Self {
+        debug!("Creating a new ArcHandle for the pool {}.", pool_id);
+        let releaser = if track_releaser {
+            Some(Arc::new(AtomicUsize::new(0)))
+        } else {
+            None
+        };
+        ArcHandle(
+            Arc::new(RwLock::new(item)),
+            recycle,
+            Some(pool_id),
+            Arc::new(AtomicUsize::new(1)),
+            recycler,
+            releaser,
+        )
+    }
+
+    /// Returns `true` if the object has last been released by the calling thread.
+    ///
+    /// Always `false` if the pool of the object does not record the releasing threads.
+    #[doc(hidden)]
+    pub fn released_by_current_thread(&self) -> bool {
+        self.5
+            .as_ref()
+            .is_some_and(|releaser| releaser.load(Ordering::Relaxed) == current_thread_token())
+    }
+
+    /// Returns `true` if this `ArcHandle` is the only one pointing to its object, and the object is not being recycled.
+    #[doc(hidden)]
+    pub fn is_alone(&self) -> bool {
+        let users = self.3.load(Ordering::Acquire);
+        users == 1 || users == DEFERRED
+    }
+
+    /// Returns `true` if the object is currently being reinitialized.
+    #[doc(hidden)]
+    pub fn is_recycling(&self) -> bool {
+        self.3.load(Ordering::Acquire) == RECYCLING
+    }
+
+    /// Returns a new `ArcHandle` pointing to the same object, if this `ArcHandle` is the only one pointing to it.
+    ///
+    /// Unlike a check of `is_alone` followed by a `clone`, it cannot give the same object to two threads.
+    /// An object whose reinitialization has been deferred is reinitialized first, and is not claimed if it is
+    /// still locked.
+    #[doc(hidden)]
+    pub fn try_claim(&self) -> Option<ArcHandle<T>> {
+        if self.3.compare_exchange(1, 2, Ordering::AcqRel, Ordering::Acquire).is_err() {
+            if self.3.compare_exchange(DEFERRED, RECYCLING, Ordering::AcqRel, Ordering::Acquire).is_err() {
+                return None;
+            }
+            match self.0.try_write() {
+                Ok(mut object) => {
+                    trace!("Reinitializing an object whose reinitialization has been deferred.");
+                    (self.1)(&mut object);
+                }
+                Err(TryLockError::Poisoned(_)) => {
+                    error!("The lock of an object whose reinitialization has been deferred is poisoned !");
+                }
+                Err(TryLockError::WouldBlock) => {
+                    trace!("The object whose reinitialization has been deferred is still locked.");
+                    self.3.store(DEFERRED, Ordering::Release);
+                    return None;
+                }
+            }
+            self.3.store(2, Ordering::Release);
+        }
+        trace!("The ArcHandle has been claimed.");
+        Some(ArcHandle(self.0.clone(), self.1, self.2, self.3.clone(), self.4.clone(), self.5.clone()))
+    }
+
+    /// Returns the inner object if this `ArcHandle` is the only one pointing to it, or gives the `ArcHandle` back.
+    ///
+    /// The object is not reinitialized. A poisoned lock is ignored.
+    #[doc(hidden)]
+    pub fn try_into_inner(mut self) -> Result<T, ArcHandle<T>> {
+        trace!("Trying to take the inner object out of the ArcHandle.");
+        if Arc::strong_count(&self.0) != 1 {
+            trace!("Other ArcHandles point to the inner object.");
+            return Err(self);
+        }
+
+        // Keep the object alive, and drop the handle without recycling the object.
+        let recycle = self.1;
+        let deferred = self.3.load(Ordering::Acquire) == DEFERRED;
+        let inner = self.0.clone();
+        self.1 = skip_recycle::<T>;
+        drop(self);
+        match Arc::try_unwrap(inner) {
+            Ok(object) => {
+                let mut object = object.into_inner().unwrap_or_else(|error| error.into_inner());
+                if deferred {
+                    trace!("Reinitializing an object whose reinitialization has been deferred.");
+                    recycle(&mut object);
+                }
+                Ok(object)
+            }
+            Err(_) => unreachable!("the ArcHandle was the only owner of its object"),
+        }
+    }
+
+    /// Returns the `PoolId` of the pool which created this `ArcHandle`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// # use std::error::Error;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let pool = ArcPool::with_capacity(10, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create_strict()?;
+    /// assert_eq!(monster.pool_id(), Some(pool.id()));
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #   try_main().unwrap();
+    /// # }
+    /// ```
+    pub fn pool_id(&self) -> Option<PoolId> {
+        debug!("Getting the PoolId of the ArcHandle.");
+        self.2
     }
 
     /// Locks this rwlock with shared read access, blocking the current thread until it can be acquired.
@@ -99,7 +308,7 @@ impl<T: Recyclable> ArcHandle<T> {
     /// #   try_main().unwrap();
     /// # }
     /// ```
-    pub fn read(&self) -> LockResult<RwLockReadGuard<T>> {
+    pub fn read(&self) -> LockResult<RwLockReadGuard<'_, T>> {
         debug!("Locking this ArcHandle to get read access to the inner object.");
         self.0.read()
     }
@@ -164,7 +373,7 @@ impl<T: Recyclable> ArcHandle<T> {
     /// #   try_main().unwrap();
     /// # }
     /// ```
-    pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<T>> {
+    pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, T>> {
         debug!("Trying to lock this ArcHandle to get read access to the inner object.");
         self.0.try_read()
     }
@@ -233,7 +442,7 @@ impl<T: Recyclable> ArcHandle<T> {
     /// #   try_main().unwrap();
     /// # }
     /// ```
-    pub fn write(&self) -> LockResult<RwLockWriteGuard<T>> {
+    pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
         debug!("Locking this ArcHandle to get write access to the inner object.");
         self.0.write()
     }
@@ -303,7 +512,7 @@ impl<T: Recyclable> ArcHandle<T> {
     /// #   try_main().unwrap();
     /// # }
     /// ```
-    pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<T>> {
+    pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<'_, T>> {
         debug!("Trying to lock this ArcHandle to get write access to the inner object.");
         self.0.try_write()
     }
@@ -317,26 +526,244 @@ impl<T: Recyclable> ArcHandle<T> {
         self.0.is_poisoned()
     }
 
-    fn drop_handle(&mut self) -> Result<(), TryLockError<RwLockWriteGuard<T>>> {
+    /// Returns `true` if this `ArcHandle` is the only one pointing to its object, besides the one held by the pool.
+    ///
+    /// Nobody else can access the object through a clone of this `ArcHandle`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create().unwrap();
+    /// assert!(monster.is_unique());
+    ///
+    /// let same_monster = monster.clone();
+    /// assert!(!monster.is_unique());
+    /// ```
+    pub fn is_unique(&self) -> bool {
+        debug!("Checking if the ArcHandle is the only one pointing to its object.");
+        // A handle created outside of a pool has no pool holding a reference to its object.
+        let owners = if self.2.is_some() { 2 } else { 1 };
+        Arc::strong_count(&self.0) == owners
+    }
+
+    /// Returns `true` if the two `ArcHandle`s point to the same object, in the same slot of the pool.
+    ///
+    /// Refer to the [Arc::ptr_eq](https://doc.rust-lang.org/std/sync/struct.Arc.html#method.ptr_eq)
+    /// method for more information.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// # use std::error::Error;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let pool = ArcPool::with_capacity(10, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create_strict()?;
+    /// let same_monster = monster.clone();
+    /// let other_monster = pool.create_strict()?;
+    ///
+    /// assert!(monster.ptr_eq(&same_monster));
+    /// assert!(!monster.ptr_eq(&other_monster));
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #   try_main().unwrap();
+    /// # }
+    /// ```
+    pub fn ptr_eq(&self, other: &ArcHandle<T>) -> bool {
+        debug!("Checking if two ArcHandles point to the same object.");
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Locks the object with write access, replaces it with the given value, and returns the old object,
+    /// like `std::mem::replace`.
+    ///
+    /// It helps to reset an object to an explicit state while it is used. The old object is not reinitialized.
+    /// This function blocks the current thread until the object can be locked with write access.
+    ///
+    /// # Errors
+    ///
+    /// If the lock is poisoned, the object is still replaced, and the old object is returned in the error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::ArcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = ArcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// let old_monster = a_monster.replace(Monster { hp: 50, level: 5 }).unwrap();
+    /// assert_eq!(old_monster.level, 10);
+    /// assert_eq!(a_monster.read().unwrap().level, 5);
+    /// ```
+    pub fn replace(&self, value: T) -> LockResult<T> {
+        debug!("Replacing the object of the ArcHandle.");
+        match self.write() {
+            Ok(mut object) => Ok(mem::replace(&mut *object, value)),
+            Err(error) => {
+                error!("The lock of the ArcHandle is poisoned, replacing its object anyway.");
+                let mut object = error.into_inner();
+                Err(PoisonError::new(mem::replace(&mut *object, value)))
+            }
+        }
+    }
+
+    fn drop_handle(&mut self) -> Result<(), TryLockError<RwLockWriteGuard<'_, T>>> {
         trace!("Dropping the ArcHandle.");
-        // Outer(Inner) -> Outer is dropped, then Inner is dropped.
-        // That's why we check if the refcount is equal to 2 :
-        // PoolObjectHandler is dropped (refcount == 2), then Rc<RefCell<T>> is dropped (refcount == 1 -> only the pool has a ref to the data).
-        if Arc::strong_count(self.as_ref()) == 2 {
-            trace!("The reference count of the ArcHandle is equal to 2.");
-            //We use try_write. Using write is a blocking operations, and this function is called from the destructor.
-            match self.try_write() {
-                Ok(mut guard) => {
-                    trace!("The ArcHandle has been successfully locked with write access. Reinitializing the inner object.");
-                    (*guard).reinitialize();
-                }
-                Err(error) => {
-                    error!("Could not lock the ArcHandle with write access !");
-                    return Err(error);
+        // If 2 ArcHandles share the object, the other one is held by the pool: the object must be reinitialized.
+        // The counter is set to RECYCLING while the object is reinitialized, so the pool cannot give it
+        // and no other ArcHandle can be dropped at the same time.
+        let mut users = self.3.load(Ordering::Acquire);
+        loop {
+            let next = match users {
+                RECYCLING => {
+                    thread::yield_now();
+                    users = self.3.load(Ordering::Acquire);
+                    continue;
                 }
+                2 => RECYCLING,
+                DEFERRED => 0,
+                _ => users - 1,
+            };
+            match self.3.compare_exchange_weak(users, next, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) if next == RECYCLING => break,
+                Ok(_) => return Ok(()),
+                Err(current) => users = current,
+            }
+        }
+
+        trace!("The ArcHandle is the last one sharing its object with the pool.");
+        if let Some(ref releaser) = self.5 {
+            releaser.store(current_thread_token(), Ordering::Relaxed);
+        }
+
+        // The background recycler marks the object as non-used once it is reinitialized.
+        if let Some(ref recycler) = self.4 {
+            let job = RecycleJob::new(self.0.clone(), self.1, self.3.clone());
+            match recycler.send(Some(job)) {
+                Ok(()) => trace!("The object has been sent to the background recycler."),
+                // The job given back by the channel reinitializes the object when it is dropped.
+                Err(_) => trace!("The background recycler has stopped, the object has been reinitialized in place."),
             }
+            return Ok(());
         }
-        Ok(())
+
+        //We use try_write. Using write is a blocking operations, and this function is called from the destructor.
+        let result = match self.0.try_write() {
+            Ok(mut guard) => {
+                trace!("The ArcHandle has been successfully locked with write access. Reinitializing the inner object.");
+                (self.1)(&mut *guard);
+                Ok(())
+            }
+            Err(TryLockError::WouldBlock) => {
+                // The lock may be held by the caller, through `pool_slice`: waiting for it could never end.
+                warn!("The ArcHandle is locked elsewhere, the reinitialization of its object is deferred.");
+                self.3.store(DEFERRED, Ordering::Release);
+                return Ok(());
+            }
+            Err(error) => {
+                error!("Could not lock the ArcHandle with write access !");
+                Err(error)
+            }
+        };
+        self.3.store(1, Ordering::Release);
+        result
     }
 }
 
@@ -353,6 +780,32 @@ impl<T: Recyclable> Drop for ArcHandle<T> {
 
 impl<T: Recyclable> Clone for ArcHandle<T> {
     fn clone(&self) -> Self {
-        ArcHandle(self.0.clone())
+        let mut users = self.3.load(Ordering::Acquire);
+        loop {
+            if users == RECYCLING {
+                thread::yield_now();
+                users = self.3.load(Ordering::Acquire);
+                continue;
+            }
+            if users == DEFERRED {
+                // The object is handed out: its deferred reinitialization must happen now.
+                match self.try_claim() {
+                    Some(handle) => return handle,
+                    None => {
+                        warn!("The object whose reinitialization has been deferred is cloned while locked, it is not reinitialized.");
+                        users = match self.3.compare_exchange(DEFERRED, 2, Ordering::AcqRel, Ordering::Acquire) {
+                            Ok(_) => break,
+                            Err(current) => current,
+                        };
+                        continue;
+                    }
+                }
+            }
+            match self.3.compare_exchange_weak(users, users + 1, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => break,
+                Err(current) => users = current,
+            }
+        }
+        ArcHandle(self.0.clone(), self.1, self.2, self.3.clone(), self.4.clone(), self.5.clone())
     }
 }
@@ -0,0 +1,66 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::ops::{Deref, DerefMut};
+use pool_object::Recyclable;
+
+/// A wrapper making any `Default` type `Recyclable`, by resetting the object to its `Default` value.
+///
+/// A blanket implementation of `Recyclable` for all the `Default` types would conflict with the custom
+/// implementations, this wrapper lets you pool a `Default` type without writing `reinitialize`.
+/// It dereferences to the object.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::{DefaultRecyclable, RcPool};
+///
+/// #[derive(Default)]
+/// struct Monster {
+///     hp: u32,
+///     level: u32,
+/// }
+///
+/// let pool = RcPool::with_capacity(2, DefaultRecyclable::<Monster>::default);
+///
+/// {
+///     let monster = pool.create().unwrap();
+///     monster.borrow_mut().level += 1;
+///     assert_eq!(monster.borrow().level, 1);
+/// }
+///
+/// assert_eq!(pool.pool_slice()[0].borrow().level, 0);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DefaultRecyclable<T: Default>(pub T);
+
+impl<T: Default> DefaultRecyclable<T> {
+    /// Consumes the wrapper, returning the object.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Default> Recyclable for DefaultRecyclable<T> {
+    fn reinitialize(&mut self) {
+        self.0 = T::default();
+    }
+}
+
+impl<T: Default> Deref for DefaultRecyclable<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Default> DerefMut for DefaultRecyclable<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
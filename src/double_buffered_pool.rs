@@ -0,0 +1,184 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use refcounted_pool_allocator::RcPool;
+use pool_object::Recyclable;
+
+/// Two `RcPool`s holding the data of the current frame and of the previous frame, like the two buffers of a swap chain.
+///
+/// The data of the frame N is acquired from the `current` pool, while the data of the frame N-1 can still be read
+/// from the `previous` pool. At the end of a frame, `flip` swaps the two pools and reinitializes the objects of the
+/// new current pool.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::{DoubleBufferedPool, Recyclable};
+///
+/// #[derive(Default)]
+/// struct Transform {
+///     x: f32,
+/// }
+///
+/// impl Recyclable for Transform {
+///     fn reinitialize(&mut self) {
+///         self.x = 0.0;
+///     }
+/// }
+///
+/// let mut pool = DoubleBufferedPool::with_capacity(2, Transform::default);
+///
+/// // Frame N.
+/// pool.current().pool_slice()[0].borrow_mut().x = 4.0;
+/// pool.flip();
+///
+/// // Frame N + 1: the data of the frame N can still be read.
+/// assert_eq!(pool.previous().pool_slice()[0].borrow().x, 4.0);
+/// assert_eq!(pool.current().pool_slice()[0].borrow().x, 0.0);
+/// ```
+#[derive(Debug)]
+pub struct DoubleBufferedPool<T: Recyclable> {
+    buffers: [RcPool<T>; 2],
+    // The index of the current buffer in `buffers`.
+    current: usize,
+}
+
+impl<T: Recyclable> DoubleBufferedPool<T> {
+    /// Create two object pools with the given capacity, and instantiate the given number of objects in each of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::DoubleBufferedPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # #[derive(Default)]
+    /// # struct Transform {
+    /// #     x: f32,
+    /// # }
+    /// #
+    /// # impl Recyclable for Transform {
+    /// #     fn reinitialize(&mut self) {
+    /// #         self.x = 0.0;
+    /// #     }
+    /// # }
+    ///
+    /// let pool = DoubleBufferedPool::with_capacity(10, Transform::default);
+    /// assert_eq!(pool.current().len(), 10);
+    /// assert_eq!(pool.previous().len(), 10);
+    /// ```
+    pub fn with_capacity<F>(size: usize, op: F) -> Self
+    where
+        F: Fn() -> T,
+    {
+        debug!("Creating a DoubleBufferedPool with a size of {} RcHandle(s) per buffer", size);
+        DoubleBufferedPool {
+            buffers: [RcPool::with_capacity(size, &op), RcPool::with_capacity(size, &op)],
+            current: 0,
+        }
+    }
+
+    /// Returns the pool holding the data of the current frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::DoubleBufferedPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # #[derive(Default)]
+    /// # struct Transform {
+    /// #     x: f32,
+    /// # }
+    /// #
+    /// # impl Recyclable for Transform {
+    /// #     fn reinitialize(&mut self) {
+    /// #         self.x = 0.0;
+    /// #     }
+    /// # }
+    ///
+    /// let pool = DoubleBufferedPool::with_capacity(2, Transform::default);
+    /// let transform = pool.current().create().unwrap();
+    /// assert_eq!(pool.current().nb_unused(), 1);
+    /// assert_eq!(pool.previous().nb_unused(), 2);
+    /// ```
+    pub fn current(&self) -> &RcPool<T> {
+        debug!("Getting the current buffer of the DoubleBufferedPool.");
+        &self.buffers[self.current]
+    }
+
+    /// Returns the pool holding the data of the previous frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::DoubleBufferedPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # #[derive(Default)]
+    /// # struct Transform {
+    /// #     x: f32,
+    /// # }
+    /// #
+    /// # impl Recyclable for Transform {
+    /// #     fn reinitialize(&mut self) {
+    /// #         self.x = 0.0;
+    /// #     }
+    /// # }
+    ///
+    /// let mut pool = DoubleBufferedPool::with_capacity(2, Transform::default);
+    /// let id = pool.current().id();
+    /// pool.flip();
+    /// assert_eq!(pool.previous().id(), id);
+    /// ```
+    pub fn previous(&self) -> &RcPool<T> {
+        debug!("Getting the previous buffer of the DoubleBufferedPool.");
+        &self.buffers[1 - self.current]
+    }
+
+    /// Swaps the current and the previous pools, and reinitializes the non-used objects of the new current pool.
+    ///
+    /// The objects of the new current pool which are still used, or borrowed, since two frames cannot be reinitialized:
+    /// an error is logged for each of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::DoubleBufferedPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # #[derive(Default)]
+    /// # struct Transform {
+    /// #     x: f32,
+    /// # }
+    /// #
+    /// # impl Recyclable for Transform {
+    /// #     fn reinitialize(&mut self) {
+    /// #         self.x = 0.0;
+    /// #     }
+    /// # }
+    ///
+    /// let mut pool = DoubleBufferedPool::with_capacity(1, Transform::default);
+    /// pool.current().pool_slice()[0].borrow_mut().x = 1.0;
+    /// pool.flip();
+    /// pool.current().pool_slice()[0].borrow_mut().x = 2.0;
+    /// pool.flip();
+    ///
+    /// // The data of two frames ago has been reinitialized.
+    /// assert_eq!(pool.current().pool_slice()[0].borrow().x, 0.0);
+    /// assert_eq!(pool.previous().pool_slice()[0].borrow().x, 2.0);
+    /// ```
+    pub fn flip(&mut self) {
+        debug!("Flipping the buffers of the DoubleBufferedPool.");
+        self.current = 1 - self.current;
+        let current = &self.buffers[self.current];
+        for index in 0..current.len() {
+            // The failures are logged by the pool.
+            let _ = current.reinitialize_slot(index);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use refcounted_pool_handler::RcHandle;
+use concurrent_pool_handler::ArcHandle;
+use pool_object::Recyclable;
+use pool_id::PoolId;
+
+use std::rc::Rc;
+
+/// An object-safe interface over the handles of the pools, whatever the type of their object.
+///
+/// It allows handles of different types, from different pools, to be stored together as `Box<dyn ErasedHandle>`,
+/// like in a registry of pooled resources. The objects go back to their pool when the boxes are dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::{ErasedHandle, RcPool};
+///
+/// let swords = RcPool::with_capacity(1, || Some(String::from("sword")));
+/// let arrows = RcPool::with_capacity(1, || Some(20u32));
+///
+/// let registry: Vec<Box<dyn ErasedHandle>> = vec![
+///     swords.create().unwrap().into_erased(),
+///     arrows.create().unwrap().into_erased(),
+/// ];
+/// assert!(registry.iter().all(|handle| handle.is_in_use()));
+/// assert_eq!(swords.nb_unused(), 0);
+///
+/// drop(registry);
+/// assert_eq!(swords.nb_unused(), 1);
+/// assert_eq!(arrows.nb_unused(), 1);
+/// ```
+pub trait ErasedHandle {
+    /// Returns `true` if the object is used, meaning that it is not available in its pool.
+    ///
+    /// A handle created outside of a pool is always in use.
+    fn is_in_use(&self) -> bool;
+
+    /// Returns the id of the pool of the object, `None` if the handle has been created outside of a pool.
+    fn pool_id(&self) -> Option<PoolId>;
+
+    /// Boxes the handle, to store it with handles of other types.
+    fn into_erased(self) -> Box<dyn ErasedHandle>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+impl<T: Recyclable> ErasedHandle for RcHandle<T> {
+    fn is_in_use(&self) -> bool {
+        debug!("Checking if the object of the RcHandle is in use.");
+        // The pool holds a reference to the object of each of its slots.
+        !self.is_pooled() || Rc::strong_count(self.as_ref()) > 1
+    }
+
+    fn pool_id(&self) -> Option<PoolId> {
+        RcHandle::pool_id(self)
+    }
+}
+
+impl<T: Recyclable> ErasedHandle for ArcHandle<T> {
+    fn is_in_use(&self) -> bool {
+        debug!("Checking if the object of the ArcHandle is in use.");
+        ArcHandle::pool_id(self).is_none() || !self.is_alone()
+    }
+
+    fn pool_id(&self) -> Option<PoolId> {
+        ArcHandle::pool_id(self)
+    }
+}
@@ -12,6 +12,16 @@ use std::error::Error;
 #[derive(Debug)]
 pub enum PoolError {
     PoolError(String),
+    /// The memory needed by the requested capacity overflows `usize`, or exceeds `isize::MAX` bytes.
+    CapacityOverflow,
+    /// All the objects of the pool are used.
+    Exhausted,
+    /// All the non-used objects of the pool have a poisoned lock.
+    AllPoisoned,
+    /// All the non-used objects of the pool are not valid, according to their `Validate` implementation.
+    AllInvalid,
+    /// The acquisitions of the pool are paused.
+    Paused,
 }
 
 unsafe impl Send for PoolError {}
@@ -20,9 +30,24 @@ unsafe impl Sync for PoolError {}
 impl fmt::Display for PoolError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            &PoolError::PoolError(ref description) => {
+            PoolError::PoolError(description) => {
                 write!(f, "Object Pool Error: {}", description)
             }
+            PoolError::CapacityOverflow => {
+                write!(f, "Object Pool Error: the capacity of the pool overflows")
+            }
+            PoolError::Exhausted => {
+                write!(f, "Object Pool Error: all the objects of the pool are used")
+            }
+            PoolError::AllPoisoned => {
+                write!(f, "Object Pool Error: all the non-used objects of the pool are poisoned")
+            }
+            PoolError::AllInvalid => {
+                write!(f, "Object Pool Error: all the non-used objects of the pool are not valid")
+            }
+            PoolError::Paused => {
+                write!(f, "Object Pool Error: the acquisitions of the pool are paused")
+            }
         }
     }
 }
@@ -30,13 +55,23 @@ impl fmt::Display for PoolError {
 impl Error for PoolError {
     fn description(&self) -> &str {
         match self {
-            &PoolError::PoolError(_) => "PoolError",
+            PoolError::PoolError(_) => "PoolError",
+            PoolError::CapacityOverflow => "CapacityOverflow",
+            PoolError::Exhausted => "Exhausted",
+            PoolError::AllPoisoned => "AllPoisoned",
+            PoolError::AllInvalid => "AllInvalid",
+            PoolError::Paused => "Paused",
         }
     }
 
-    fn cause(&self) -> Option<&Error> {
+    fn cause(&self) -> Option<&dyn Error> {
         match self {
-            &PoolError::PoolError(_) => None,
+            PoolError::PoolError(_) => None,
+            PoolError::CapacityOverflow => None,
+            PoolError::Exhausted => None,
+            PoolError::AllPoisoned => None,
+            PoolError::AllInvalid => None,
+            PoolError::Paused => None,
         }
     }
 }
@@ -0,0 +1,134 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use refcounted_pool_allocator::RcPool;
+use refcounted_pool_handler::RcHandle;
+use erased_handle::ErasedHandle;
+use pool_object::Recyclable;
+
+/// A `RcPool` deduplicating its used objects by value, for immutable shared values like strings or geometry.
+///
+/// Interning a value returns a `RcHandle<T>` to a used object equal to this value if there is one. Otherwise, a
+/// non-used object is acquired and the value is stored in it. An object goes back to the pool, and is reinitialized,
+/// when the last `RcHandle<T>` pointing to it is dropped.
+///
+/// The objects should not be mutated through their `RcHandle<T>`: a mutated object is only shared again for values
+/// equal to its new value and with the same hash as its interned value.
+/// The pool dereferences to a `RcPool<T>`, giving access to all its functions.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::InterningPool;
+///
+/// let pool = InterningPool::with_capacity(2, || None);
+///
+/// let a_name = pool.intern(Some(String::from("goblin"))).unwrap();
+/// let same_name = pool.intern(Some(String::from("goblin"))).unwrap();
+/// assert!(a_name.ptr_eq(&same_name));
+/// assert_eq!(pool.nb_unused(), 1);
+/// ```
+#[derive(Debug)]
+pub struct InterningPool<T: Recyclable + Eq + Hash> {
+    pool: RcPool<T>,
+    // The indices of the slots storing an interned value, by hash of the value. The entries of the slots
+    // which went back to the pool are removed lazily.
+    slots: RefCell<HashMap<u64, Vec<usize>>>,
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<T: Recyclable + Eq + Hash> InterningPool<T> {
+    /// Create an object pool with the given capacity, and instantiate the given number of objects.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::InterningPool;
+    ///
+    /// let pool: InterningPool<Option<String>> = InterningPool::with_capacity(10, || None);
+    /// assert_eq!(pool.nb_unused(), 10);
+    /// ```
+    pub fn with_capacity<F>(size: usize, op: F) -> Self
+    where
+        F: Fn() -> T,
+    {
+        debug!("Creating an InterningPool with a size of {} RcHandle(s)", size);
+        InterningPool {
+            pool: RcPool::with_capacity(size, op),
+            slots: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns a `RcHandle<T>` to a used object equal to the given value, or stores the value in a non-used object.
+    ///
+    /// The given value is dropped if an equal object is used. `None` is returned if no used object is equal to the
+    /// value and all the `RcHandle<T>` are used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a used object with the same hash as the value is currently mutably borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::InterningPool;
+    ///
+    /// let pool = InterningPool::with_capacity(2, || None);
+    ///
+    /// let a_goblin = pool.intern(Some("goblin")).unwrap();
+    /// let an_orc = pool.intern(Some("orc")).unwrap();
+    /// assert!(!a_goblin.ptr_eq(&an_orc));
+    ///
+    /// // The goblin is shared, there is no need for another object.
+    /// let another_goblin = pool.intern(Some("goblin")).unwrap();
+    /// assert!(a_goblin.ptr_eq(&another_goblin));
+    /// assert!(pool.intern(Some("troll")).is_none());
+    /// ```
+    pub fn intern(&self, value: T) -> Option<RcHandle<T>> {
+        debug!("Interning a value in the InterningPool.");
+        let hash = hash_of(&value);
+        let mut slots = self.slots.borrow_mut();
+
+        if let Some(indices) = slots.get_mut(&hash) {
+            let handles = self.pool.pool_slice();
+            indices.retain(|&index| handles.get(index).is_some_and(|handle| handle.is_in_use()));
+            if let Some(&index) = indices.iter().find(|&&index| *handles[index].borrow() == value) {
+                trace!("An equal object is used at index {}, sharing it.", index);
+                return Some(handles[index].clone());
+            }
+        }
+        if slots.get(&hash).is_some_and(Vec::is_empty) {
+            slots.remove(&hash);
+        }
+
+        trace!("No used object is equal to the value, storing it in a non-used object.");
+        let handle = self.pool.create()?;
+        *handle.borrow_mut() = value;
+        if let Some(index) = handle.slot_index() {
+            slots.entry(hash).or_default().push(index);
+        }
+        Some(handle)
+    }
+}
+
+impl<T: Recyclable + Eq + Hash> Deref for InterningPool<T> {
+    type Target = RcPool<T>;
+
+    fn deref(&self) -> &RcPool<T> {
+        &self.pool
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::cell::RefCell;
+use std::ops::Deref;
+use refcounted_pool_allocator::RcPool;
+use refcounted_pool_handler::RcHandle;
+use acquisition_policy::{AcquisitionPolicy, SlotInfo};
+use pool_object::Recyclable;
+
+/// A `RcPool` whose objects are configured for a key, like connections configured for a destination.
+///
+/// When an object is asked for a key, the pool prefers a non-used object already configured for this key.
+/// Otherwise, a non-used object is configured for the key with the callback given at the creation of the pool.
+///
+/// The configuration of an object is kept when it goes back to the pool: `Recyclable::reinitialize` should
+/// not undo it. The pool dereferences to a `RcPool<T>`, giving access to all its functions.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::{KeyedPool, Recyclable};
+///
+/// struct Connection {
+///     host: String,
+///     nb_requests: u32,
+/// }
+///
+/// impl Recyclable for Connection {
+///     fn reinitialize(&mut self) {
+///         self.nb_requests = 0;
+///     }
+/// }
+///
+/// let pool = KeyedPool::with_capacity(
+///     2,
+///     || Connection { host: String::new(), nb_requests: 0 },
+///     |connection, host: &String| connection.host = host.clone(),
+/// );
+///
+/// let connection = pool.acquire(&String::from("example.com")).unwrap();
+/// assert_eq!(connection.borrow().host, "example.com");
+/// drop(connection);
+///
+/// // The connection configured for this host is reused.
+/// let connection = pool.acquire(&String::from("example.com")).unwrap();
+/// assert!(connection.ptr_eq(&pool.pool_slice()[0]));
+/// ```
+#[derive(Debug)]
+pub struct KeyedPool<K: PartialEq + Clone, T: Recyclable> {
+    pool: RcPool<T>,
+    // The key each object is configured for, if any.
+    keys: RefCell<Vec<Option<K>>>,
+    configure: fn(&mut T, &K),
+}
+
+// Selects a non-used slot configured for the key, or the first non-used slot which can be configured.
+struct KeyAffinity<'a, K: 'a> {
+    keys: &'a [Option<K>],
+    key: &'a K,
+}
+
+impl<'a, K: PartialEq, T: Recyclable> AcquisitionPolicy<T> for KeyAffinity<'a, K> {
+    fn select(&self, slots: &[SlotInfo<T>]) -> Option<usize> {
+        let free = || slots.iter().filter(|slot| !slot.in_use());
+        free()
+            .find(|slot| self.keys[slot.index()].as_ref() == Some(self.key))
+            .or_else(|| free().find(|slot| slot.handle().try_borrow_mut().is_ok()))
+            .map(|slot| slot.index())
+    }
+}
+
+impl<K: PartialEq + Clone, T: Recyclable> KeyedPool<K, T> {
+    /// Create an object pool with the given capacity, and instantiate the given number of objects,
+    /// configured for no key.
+    ///
+    /// The `configure` function is called to configure an object for a key, when no non-used object is
+    /// configured for this key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::KeyedPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Connection {
+    /// #     host: String,
+    /// #     nb_requests: u32,
+    /// # }
+    /// #
+    /// # impl Recyclable for Connection {
+    /// #     fn reinitialize(&mut self) {
+    /// #         self.nb_requests = 0;
+    /// #     }
+    /// # }
+    /// #
+    /// # fn connection() -> Connection {
+    /// #     Connection { host: String::new(), nb_requests: 0 }
+    /// # }
+    /// #
+    /// # fn configure(connection: &mut Connection, host: &&str) {
+    /// #     connection.host = host.to_string();
+    /// # }
+    ///
+    /// let pool = KeyedPool::with_capacity(10, connection, configure);
+    /// assert_eq!(pool.nb_unused(), 10);
+    /// assert_eq!(pool.key_of(0), None);
+    /// ```
+    pub fn with_capacity<F>(size: usize, op: F, configure: fn(&mut T, &K)) -> Self
+    where
+        F: Fn() -> T,
+    {
+        debug!("Creating a KeyedPool with a size of {} RcHandle(s)", size);
+        KeyedPool {
+            pool: RcPool::with_capacity(size, op),
+            keys: RefCell::new((0..size).map(|_| None).collect()),
+            configure,
+        }
+    }
+
+    /// Asks the pool for an `RcHandle<T>` whose object is configured for the given key.
+    ///
+    /// A non-used object already configured for the key is preferred. Otherwise, the first non-used object
+    /// which is not borrowed is configured for the key. `None` is returned if all `RcHandle<T>` are used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::KeyedPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Connection {
+    /// #     host: String,
+    /// #     nb_requests: u32,
+    /// # }
+    /// #
+    /// # impl Recyclable for Connection {
+    /// #     fn reinitialize(&mut self) {
+    /// #         self.nb_requests = 0;
+    /// #     }
+    /// # }
+    /// #
+    /// # fn connection() -> Connection {
+    /// #     Connection { host: String::new(), nb_requests: 0 }
+    /// # }
+    /// #
+    /// # fn configure(connection: &mut Connection, host: &&str) {
+    /// #     connection.host = host.to_string();
+    /// # }
+    ///
+    /// let pool = KeyedPool::with_capacity(2, connection, configure);
+    ///
+    /// let a_connection = pool.acquire(&"example.com").unwrap();
+    /// let another_connection = pool.acquire(&"example.org").unwrap();
+    /// assert_eq!(another_connection.borrow().host, "example.org");
+    /// assert!(pool.acquire(&"example.net").is_none());
+    /// ```
+    pub fn acquire(&self, key: &K) -> Option<RcHandle<T>> {
+        debug!("The KeyedPool is being asked a RcHandle for a key.");
+        let mut keys = self.keys.borrow_mut();
+        let handle = self.pool.create_with_policy(&KeyAffinity { keys: &keys, key })?;
+        let index = handle
+            .slot_index()
+            .expect("a RcHandle given by a pool belongs to a slot");
+
+        if keys[index].as_ref() != Some(key) {
+            trace!("Configuring the object at index {} for a new key.", index);
+            (self.configure)(&mut handle.borrow_mut(), key);
+            keys[index] = Some(key.clone());
+        }
+        Some(handle)
+    }
+
+    /// Returns the key the object at the given index is configured for.
+    ///
+    /// `None` is returned if the index is out of bounds, or if the object has never been configured.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::KeyedPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Connection {
+    /// #     host: String,
+    /// #     nb_requests: u32,
+    /// # }
+    /// #
+    /// # impl Recyclable for Connection {
+    /// #     fn reinitialize(&mut self) {
+    /// #         self.nb_requests = 0;
+    /// #     }
+    /// # }
+    /// #
+    /// # fn connection() -> Connection {
+    /// #     Connection { host: String::new(), nb_requests: 0 }
+    /// # }
+    /// #
+    /// # fn configure(connection: &mut Connection, host: &&str) {
+    /// #     connection.host = host.to_string();
+    /// # }
+    ///
+    /// let pool = KeyedPool::with_capacity(2, connection, configure);
+    ///
+    /// let a_connection = pool.acquire(&"example.com").unwrap();
+    /// assert_eq!(pool.key_of(0), Some("example.com"));
+    /// assert_eq!(pool.key_of(1), None);
+    /// ```
+    pub fn key_of(&self, index: usize) -> Option<K> {
+        debug!("Getting the key of the object at index {} of the KeyedPool.", index);
+        self.keys.borrow().get(index).cloned().and_then(|key| key)
+    }
+}
+
+impl<K: PartialEq + Clone, T: Recyclable> Deref for KeyedPool<K, T> {
+    type Target = RcPool<T>;
+
+    fn deref(&self) -> &RcPool<T> {
+        &self.pool
+    }
+}
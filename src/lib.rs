@@ -81,12 +81,10 @@
 //!     assert_eq!(monster.borrow().level, 11);
 //!
 //!     //He is the only monster in the pool to have a level > 10.
-//!     let nb_monster_lvl_10 = pool.pool_slice()
-//!     .iter()
-//!     .filter(|monster| {
-//!         monster.borrow().level <= 10
+//!     let nb_monster_lvl_10 = pool.filter_handles(|monster| {
+//!         monster.level <= 10
 //!     })
-//!     .count();
+//!     .len();
 //!
 //!     assert_eq!(nb_monster_lvl_10, 19);
 //!
@@ -120,17 +118,90 @@
 extern crate serde;
 #[macro_use]
 extern crate log;
+#[cfg(feature = "zeroize")]
+extern crate zeroize;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "crossbeam")]
+extern crate crossbeam;
+#[cfg(feature = "derive")]
+extern crate maskerad_object_pool_derive;
+
+/// The target of all the log records of the crate, so they can be filtered together.
+pub const LOG_TARGET: &str = "maskerad_object_pool";
+
+// Those macros shadow the ones of the `log` crate, to give all the records the same target.
+macro_rules! error {
+    ($($arg:tt)+) => (log!(target: $crate::LOG_TARGET, ::log::Level::Error, $($arg)+));
+}
+
+macro_rules! warn {
+    ($($arg:tt)+) => (log!(target: $crate::LOG_TARGET, ::log::Level::Warn, $($arg)+));
+}
+
+macro_rules! debug {
+    ($($arg:tt)+) => (log!(target: $crate::LOG_TARGET, ::log::Level::Debug, $($arg)+));
+}
+
+macro_rules! trace {
+    ($($arg:tt)+) => (log!(target: $crate::LOG_TARGET, ::log::Level::Trace, $($arg)+));
+}
 
 mod refcounted_pool_allocator;
 mod concurrent_pool_allocator;
 mod concurrent_pool_handler;
 mod refcounted_pool_handler;
+mod refcounted_pool_guard;
+mod concurrent_pool_guard;
 mod pool_object;
 mod errors;
+mod reservation_token;
+mod pool_id;
+mod acquisition_policy;
+mod template_pool;
+mod default_recyclable;
+mod pool_stats;
+mod overflow_policy;
+mod keyed_pool;
+mod background_recycler;
+mod double_buffered_pool;
+mod any_pool;
+mod capacity;
+mod slot_health;
+mod erased_handle;
+mod interning_pool;
+mod pool_observer;
+mod pool_group;
+mod thread_local_cache;
+#[cfg(feature = "serde")]
+mod serializable_handle;
 
 pub use refcounted_pool_allocator::RcPool;
-pub use pool_object::Recyclable;
+pub use pool_object::{HeapSize, Recyclable, Validate};
 pub use errors::{PoolError, PoolResult};
 pub use refcounted_pool_handler::RcHandle;
+pub use refcounted_pool_guard::RcHandleMut;
 pub use concurrent_pool_handler::ArcHandle;
+pub use erased_handle::ErasedHandle;
+pub use concurrent_pool_guard::{ArcHandleRead, ArcHandleWrite};
 pub use concurrent_pool_allocator::ArcPool;
+pub use thread_local_cache::ThreadLocalCache;
+pub use reservation_token::ReservationToken;
+pub use pool_id::PoolId;
+pub use template_pool::{TemplatePool, Templated};
+pub use default_recyclable::DefaultRecyclable;
+pub use pool_stats::PoolStats;
+pub use pool_observer::PoolObserver;
+pub use slot_health::SlotHealth;
+pub use overflow_policy::OverflowPolicy;
+pub use keyed_pool::KeyedPool;
+pub use interning_pool::InterningPool;
+pub use double_buffered_pool::DoubleBufferedPool;
+pub use pool_group::PoolGroup;
+pub use any_pool::{AnyPool, TypedHandle};
+pub use capacity::recommended_capacity;
+pub use acquisition_policy::{AcquisitionPolicy, FirstFree, Lru, MinOrd, Mru, SlotInfo};
+#[cfg(feature = "serde")]
+pub use serializable_handle::SerializableHandle;
+#[cfg(feature = "derive")]
+pub use maskerad_object_pool_derive::Recyclable;
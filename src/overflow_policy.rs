@@ -0,0 +1,20 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+/// What `RcPool::create` does when all the objects of the pool are used.
+///
+/// Set with `RcPool::set_overflow_policy`.
+#[derive(Debug, Clone, Copy)]
+pub enum OverflowPolicy<T> {
+    /// `None` is returned. This is the policy of a new pool.
+    Fail,
+    /// An object is created with the given function, outside of the pool.
+    ///
+    /// The returned `RcHandle<T>` is not pooled: its object is dropped with its last `RcHandle<T>`,
+    /// and never goes back to the pool.
+    Allocate(fn() -> T),
+}
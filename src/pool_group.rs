@@ -0,0 +1,200 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use refcounted_pool_allocator::RcPool;
+use errors::{PoolError, PoolResult};
+use pool_object::Recyclable;
+
+/// Several `RcPool`s sharing a budget: the total number of objects of all the pools cannot exceed it.
+///
+/// The pools are registered in the group, which owns them, and must be grown through the group.
+/// It centralizes the memory budget of a program using many small pools.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::{PoolGroup, RcPool};
+///
+/// let mut group = PoolGroup::new(10);
+/// let swords = group.register(RcPool::with_capacity(4, || Some(String::from("sword")))).unwrap();
+/// let shields = group.register(RcPool::with_capacity(4, || Some(String::from("shield")))).unwrap();
+/// assert_eq!(group.total_capacity(), 8);
+///
+/// // Only 2 objects are left in the budget.
+/// assert!(group.grow(swords, 3, || Some(String::from("sword"))).is_err());
+/// group.grow(shields, 2, || Some(String::from("shield"))).unwrap();
+/// assert_eq!(group.pool(shields).unwrap().len(), 6);
+/// ```
+#[derive(Debug)]
+pub struct PoolGroup<T: Recyclable> {
+    pools: Vec<RcPool<T>>,
+    // The maximum number of objects of all the pools.
+    budget: usize,
+}
+
+impl<T: Recyclable> PoolGroup<T> {
+    /// Create an empty group, whose pools cannot have more than `budget` objects in total.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::PoolGroup;
+    ///
+    /// let group: PoolGroup<Option<String>> = PoolGroup::new(100);
+    /// assert_eq!(group.budget(), 100);
+    /// assert_eq!(group.total_capacity(), 0);
+    /// ```
+    pub fn new(budget: usize) -> Self {
+        debug!("Creating a PoolGroup with a budget of {} object(s).", budget);
+        PoolGroup {
+            pools: Vec::new(),
+            budget,
+        }
+    }
+
+    /// Adds the pool to the group, and returns its index in the group.
+    ///
+    /// # Errors
+    ///
+    /// A `PoolError` is returned, and the pool is dropped, if its objects do not fit in the remaining budget.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{PoolGroup, RcPool};
+    ///
+    /// let mut group = PoolGroup::new(5);
+    /// assert_eq!(group.register(RcPool::with_capacity(3, || Some(1u32))).unwrap(), 0);
+    /// assert!(group.register(RcPool::with_capacity(3, || Some(2u32))).is_err());
+    /// assert_eq!(group.len(), 1);
+    /// ```
+    pub fn register(&mut self, pool: RcPool<T>) -> PoolResult<usize> {
+        debug!("Registering a RcPool of {} RcHandle(s) in the PoolGroup.", pool.len());
+        self.check_budget(pool.len())?;
+        self.pools.push(pool);
+        Ok(self.pools.len() - 1)
+    }
+
+    /// Grows the pool at the given index by `additional` objects, created with the given closure, if they fit in
+    /// the remaining budget.
+    ///
+    /// # Errors
+    ///
+    /// A `PoolError` is returned, and the pool is left untouched, if the index is out of bounds, or if the new
+    /// objects do not fit in the remaining budget.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{PoolGroup, RcPool};
+    ///
+    /// let mut group = PoolGroup::new(5);
+    /// let arrows = group.register(RcPool::with_capacity(3, || Some(20u32))).unwrap();
+    ///
+    /// group.grow(arrows, 2, || Some(20u32)).unwrap();
+    /// assert_eq!(group.total_capacity(), 5);
+    /// assert!(group.grow(arrows, 1, || Some(20u32)).is_err());
+    /// ```
+    pub fn grow<F>(&mut self, index: usize, additional: usize, op: F) -> PoolResult<()>
+    where
+        F: FnMut() -> T,
+    {
+        debug!("Growing the RcPool at index {} of the PoolGroup by {} RcHandle(s).", index, additional);
+        if index >= self.pools.len() {
+            error!("The PoolGroup has no RcPool at index {} !", index);
+            return Err(PoolError::PoolError(format!("The PoolGroup has no RcPool at index {} !", index)));
+        }
+        self.check_budget(additional)?;
+        self.pools[index].grow(additional, op);
+        Ok(())
+    }
+
+    /// Returns a reference to the pool at the given index, `None` if the index is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{PoolGroup, RcPool};
+    ///
+    /// let mut group = PoolGroup::new(5);
+    /// let arrows = group.register(RcPool::with_capacity(3, || Some(20u32))).unwrap();
+    ///
+    /// let an_arrow = group.pool(arrows).unwrap().create().unwrap();
+    /// assert!(group.pool(1).is_none());
+    /// ```
+    pub fn pool(&self, index: usize) -> Option<&RcPool<T>> {
+        debug!("Getting the RcPool at index {} of the PoolGroup.", index);
+        self.pools.get(index)
+    }
+
+    /// Returns the number of pools in the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::PoolGroup;
+    ///
+    /// let group: PoolGroup<Option<u32>> = PoolGroup::new(5);
+    /// assert_eq!(group.len(), 0);
+    /// assert!(group.is_empty());
+    /// ```
+    pub fn len(&self) -> usize {
+        debug!("Getting the number of RcPools of the PoolGroup.");
+        self.pools.len()
+    }
+
+    /// Returns `true` if the group has no pool.
+    pub fn is_empty(&self) -> bool {
+        debug!("Checking if the PoolGroup has no RcPool.");
+        self.pools.is_empty()
+    }
+
+    /// Returns the total number of objects of all the pools of the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{PoolGroup, RcPool};
+    ///
+    /// let mut group = PoolGroup::new(10);
+    /// group.register(RcPool::with_capacity(3, || Some(1u32))).unwrap();
+    /// group.register(RcPool::with_capacity(4, || Some(2u32))).unwrap();
+    /// assert_eq!(group.total_capacity(), 7);
+    /// assert_eq!(group.remaining(), 3);
+    /// ```
+    pub fn total_capacity(&self) -> usize {
+        debug!("Getting the total number of objects of the PoolGroup.");
+        self.pools.iter().map(|pool| pool.len()).sum()
+    }
+
+    /// Returns the maximum number of objects of all the pools of the group.
+    pub fn budget(&self) -> usize {
+        debug!("Getting the budget of the PoolGroup.");
+        self.budget
+    }
+
+    /// Returns the number of objects which can still be added to the pools of the group.
+    pub fn remaining(&self) -> usize {
+        debug!("Getting the remaining budget of the PoolGroup.");
+        self.budget.saturating_sub(self.total_capacity())
+    }
+
+    fn check_budget(&self, additional: usize) -> PoolResult<()> {
+        let remaining = self.remaining();
+        if additional > remaining {
+            error!(
+                "{} object(s) exceed the remaining budget of {} object(s) of the PoolGroup !",
+                additional, remaining
+            );
+            return Err(PoolError::PoolError(format!(
+                "{} object(s) exceed the remaining budget of {} object(s) of the PoolGroup !",
+                additional, remaining
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_POOL_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A unique identifier, given to each `RcPool` and `ArcPool` at its creation.
+///
+/// It allows to know from which pool a handle comes from, when several pools of the same type exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PoolId(usize);
+
+impl PoolId {
+    #[doc(hidden)]
+    pub fn next() -> Self {
+        trace!("Generating a new PoolId.");
+        PoolId(NEXT_POOL_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl fmt::Display for PoolId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Pool #{}", self.0)
+    }
+}
@@ -5,18 +5,59 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 /// If we want to create a pool of `T`, `T` must implement `Recyclable`.
 ///
 /// A pool item must have the following properties:
 ///
 /// - Be *shareable*. outside code ask the pool for an object, and the pool give them the first
-/// *free* handle it can find. Once those pool items are no longer used by outside code, they must go back in the pool
-/// automatically. This functionality is provided by the `RcHandle<T>`s and `ArcHandle<T>`s,
-/// returned by the `RcPool<T>`s and `ArcPool<T>`s. Those types are reference counted smart pointers
-/// with interior mutability and a custom `Drop` implementation.
+///   *free* handle it can find. Once those pool items are no longer used by outside code, they must go back in the pool
+///   automatically. This functionality is provided by the `RcHandle<T>`s and `ArcHandle<T>`s,
+///   returned by the `RcPool<T>`s and `ArcPool<T>`s. Those types are reference counted smart pointers
+///   with interior mutability and a custom `Drop` implementation.
 ///
 /// - Be *recyclable*. When a pool item is no longer used by outside code, the pool item must reinitialize
-/// its object to a given state. This functionality is provided by this trait.
+///   its object to a given state. This functionality is provided by this trait.
+///
+/// With the `derive` feature, `#[derive(Recyclable)]` generates a `reinitialize` function setting each field
+/// of a struct to its `Default` value. A field annotated with `#[recycle(with = "expr")]` is set to the value
+/// of `expr` instead.
 pub trait Recyclable {
     fn reinitialize(&mut self);
 }
+
+/// Reports the heap memory owned by an object, in bytes, excluding the size of the object itself.
+///
+/// Used by `RcPool::capacity_bytes_with_heap` to estimate the memory footprint of a pool.
+pub trait HeapSize {
+    fn heap_size(&self) -> usize;
+}
+
+/// Reports whether a pooled object can still be used, like a pooled connection which may have timed out.
+///
+/// Used by `RcPool::create_valid` and `RcPool::create_valid_or_renew`, which never give an invalid object.
+pub trait Validate {
+    fn is_valid(&self) -> bool;
+}
+
+/// A pool of `Option<T>` is a pool of slots which can be logically empty.
+///
+/// Recycling an `Option<T>` sets it back to `None`, dropping the object it contained.
+impl<T> Recyclable for Option<T> {
+    fn reinitialize(&mut self) {
+        *self = None;
+    }
+}
+
+/// Zeroes the memory of a pooled object with `Zeroize`, then reinitializes it with `Recyclable`.
+#[cfg(feature = "zeroize")]
+pub fn zeroize_and_reinitialize<T: Recyclable + Zeroize>(object: &mut T) {
+    trace!("Zeroing the pooled object before reinitializing it.");
+    object.zeroize();
+    object.reinitialize();
+}
+
+/// Does nothing. Used instead of `Recyclable::reinitialize` when a pooled object must not be recycled.
+pub fn skip_recycle<T>(_object: &mut T) {}
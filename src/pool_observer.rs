@@ -0,0 +1,76 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+/// Receives the events of a `RcPool`, to feed a metrics or tracing system without a logging backend.
+///
+/// All the methods do nothing by default: implement only the events you need.
+/// Set the observer of a pool with `RcPool::set_observer`.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::{PoolObserver, RcPool};
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// struct AcquisitionCounter(Rc<Cell<usize>>);
+///
+/// impl PoolObserver for AcquisitionCounter {
+///     fn on_create(&self, _index: usize) {
+///         self.0.set(self.0.get() + 1);
+///     }
+/// }
+///
+/// let pool = RcPool::with_capacity(2, || Some(String::from("sword")));
+/// let nb_acquisitions = Rc::new(Cell::new(0));
+/// pool.set_observer(Box::new(AcquisitionCounter(nb_acquisitions.clone())));
+///
+/// let a_sword = pool.create().unwrap();
+/// let another_sword = pool.create().unwrap();
+/// assert_eq!(nb_acquisitions.get(), 2);
+/// ```
+pub trait PoolObserver {
+    /// Called when the object at the given index is handed out by the pool.
+    fn on_create(&self, _index: usize) {}
+
+    /// Called when the last `RcHandle` of the object at the given index is dropped, and the object goes back to the pool.
+    fn on_release(&self, _index: usize) {}
+
+    /// Called when an object is asked to the pool but all its objects are used.
+    fn on_exhausted(&self) {}
+
+    /// Called when the given number of objects are added to the pool.
+    fn on_grow(&self, _additional: usize) {}
+}
+
+// The observer of a pool, shared by the pool, its clones and its RcHandles.
+#[doc(hidden)]
+#[derive(Clone, Default)]
+pub struct SharedObserver(Rc<RefCell<Option<Rc<dyn PoolObserver>>>>);
+
+impl SharedObserver {
+    #[doc(hidden)]
+    pub fn set(&self, observer: Box<dyn PoolObserver>) {
+        *self.0.borrow_mut() = Some(Rc::from(observer));
+    }
+
+    // The observer is cloned before being called, so it can be replaced from one of its methods.
+    #[doc(hidden)]
+    pub fn get(&self) -> Option<Rc<dyn PoolObserver>> {
+        self.0.borrow().clone()
+    }
+}
+
+impl fmt::Debug for SharedObserver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SharedObserver")
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+/// A snapshot of the metrics of a pool, returned by `RcPool::stats` and `ArcPool::stats`.
+///
+/// With the `serde` feature, it can be serialized, to send the health of a pool to a telemetry pipeline.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::RcPool;
+///
+/// let pool = RcPool::with_capacity(3, || {
+///     Some(String::from("sword"))
+/// });
+///
+/// let a_sword = pool.create().unwrap();
+/// let stats = pool.stats();
+/// assert_eq!(stats.len, 3);
+/// assert_eq!(stats.in_use, 1);
+/// assert_eq!(stats.unused, 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PoolStats {
+    /// The number of objects in the pool.
+    pub len: usize,
+    /// The number of used objects. The objects detached from an `RcPool` are neither used nor non-used.
+    pub in_use: usize,
+    /// The number of non-used objects.
+    pub unused: usize,
+    /// The highest number of objects used at the same time, `None` if the pool does not track it.
+    pub peak_in_use: Option<usize>,
+    /// The number of acquisitions, `None` if the pool does not track it.
+    pub total_acquisitions: Option<usize>,
+    /// The number of objects whose lock is poisoned. Always zero for a `RcPool`.
+    pub poisoned: usize,
+}
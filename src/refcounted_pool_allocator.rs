@@ -6,10 +6,34 @@
 // copied, modified, or distributed except according to those terms.
 
 use errors::{PoolError, PoolResult};
-use refcounted_pool_handler::RcHandle;
-use pool_object::Recyclable;
+use refcounted_pool_handler::{RcHandle, SlotCounters};
+use refcounted_pool_guard::RcHandleMut;
+use concurrent_pool_allocator::ArcPool;
+use acquisition_policy::{AcquisitionPolicy, SlotInfo};
+use reservation_token::ReservationToken;
+use pool_stats::PoolStats;
+use overflow_policy::OverflowPolicy;
+use capacity::recommended_capacity;
+use pool_observer::{PoolObserver, SharedObserver};
+#[cfg(feature = "serde")]
+use serializable_handle::SerializableHandle;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde")]
+use serde::de::Error;
+use pool_object::{skip_recycle, HeapSize, Recyclable, Validate};
+use pool_id::PoolId;
+#[cfg(feature = "zeroize")]
+use pool_object::zeroize_and_reinitialize;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 use std::rc::Rc;
+use std::cell::{Cell, RefCell};
+use std::cmp::{Ordering, Reverse};
+use std::mem;
+use std::fmt;
+use std::time::Instant;
 
 /// A wrapper around a vector of `RcHandle<T>`.
 ///
@@ -76,9 +100,59 @@ use std::rc::Rc;
 /// #   try_main().unwrap();
 /// # }
 /// ```
-#[derive(Debug, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct RcPool<T: Recyclable>(Vec<RcHandle<T>>);
+///
+/// With the `serde` feature, the pool is serialized as the values of its objects, along with the generations
+/// of its slots, used by `SerializableHandle`s. The way the objects are recycled is not serialized: a deserialized
+/// pool reinitializes its objects with `Recyclable::reinitialize`, even if it has been serialized from a pool created
+/// with `with_capacity_no_reset` or `with_capacity_zeroizing`.
+#[derive(Clone)]
+pub struct RcPool<T: Recyclable> {
+    id: PoolId,
+    handles: Vec<RcHandle<T>>,
+    // The acquisition order of each slot, used by the LRU policy.
+    stamps: Vec<Cell<u64>>,
+    // The instant of the last acquisition of each slot.
+    acquired_at: Vec<Cell<Option<Instant>>>,
+    next_stamp: Cell<u64>,
+    recycle: fn(&mut T),
+    peak_in_use: Cell<usize>,
+    total_acquisitions: Cell<usize>,
+    on_evict: RefCell<Option<EvictionCallback>>,
+    // Shared with the RcHandles, which reinitialize their object only if it is true.
+    recycle_enabled: Rc<Cell<bool>>,
+    // The size the pool cannot exceed when growing with `create_or_grow`.
+    max_capacity: Option<usize>,
+    overflow_policy: OverflowPolicy<T>,
+    // Shared by the clones of the pool, no RcHandle is given while it is true.
+    paused: Rc<Cell<bool>>,
+    // Shared by the clones of the pool and its RcHandles, which notify the releases.
+    observer: SharedObserver,
+    // The number of unused objects only `create_priority` can give.
+    priority_reserve: usize,
+    // The number of unused and detached objects, shared with the RcHandles, which update them when they are
+    // acquired, released or detached.
+    counters: Rc<SlotCounters>,
+    // The PoolId of the serialized pool, if this pool has been deserialized. Its SerializableHandles still resolve.
+    #[cfg(feature = "serde")]
+    origin: Option<PoolId>,
+}
+
+// The callback given to `RcPool::on_evict`, shared by the clones of the pool.
+#[derive(Clone)]
+struct EvictionCallback(Rc<dyn Fn(usize)>);
+
+impl fmt::Debug for EvictionCallback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EvictionCallback")
+    }
+}
+
+// Rejects a capacity of zero given to `RcPool::with_const_capacity`, when the constant is evaluated at compile time.
+struct NonZeroCapacity<const N: usize>;
+
+impl<const N: usize> NonZeroCapacity<N> {
+    const ASSERT: () = assert!(N > 0, "the capacity of a RcPool cannot be zero");
+}
 
 impl<T: Recyclable> RcPool<T> {
     /// Create an object pool with the given capacity, and instantiate the given number of object.
@@ -124,16 +198,13 @@ impl<T: Recyclable> RcPool<T> {
         F: Fn() -> T,
     {
         debug!("Creating a RcPool with a size of {} RcHandle(s)", size);
-        let mut objects = Vec::with_capacity(size);
-
-        for _ in 0..size {
-            objects.push(RcHandle::new(op()));
-        }
-
-        RcPool(objects)
+        RcPool::with_recycler(size, |_| op(), T::reinitialize)
     }
 
-    /// Returns an immutable slice of the vector of `RcHandle<T>`
+    /// Create an object pool sized for the given number of objects used at the same time, multiplied by the given
+    /// safety factor, and instantiate all its objects.
+    ///
+    /// The capacity is computed with `recommended_capacity`: it is rounded up.
     ///
     /// # Example
     ///
@@ -166,38 +237,104 @@ impl<T: Recyclable> RcPool<T> {
     /// #        self.level += 1;
     /// #    }
     /// # }
-    /// let pool = RcPool::with_capacity(10, || {
+    ///
+    /// // 10 monsters are expected to be alive at the same time, keep 50% more just in case.
+    /// let pool = RcPool::with_concurrency(10, 1.5, || {
     ///     Monster::default()
     /// });
+    /// assert_eq!(pool.nb_unused(), 15);
+    /// ```
+    pub fn with_concurrency<F>(expected_concurrent: usize, safety_factor: f32, op: F) -> Self
+    where
+        F: Fn() -> T,
+    {
+        debug!(
+            "Creating a RcPool for {} concurrent object(s), with a safety factor of {}.",
+            expected_concurrent, safety_factor
+        );
+        RcPool::with_capacity(recommended_capacity(expected_concurrent, safety_factor), op)
+    }
+
+    /// Create an object pool with the given capacity, and instantiate the given number of object, after checking that
+    /// the memory needed by the pool can be allocated.
     ///
-    /// //The pool slice can be useful if you need tou iterate over the collection.
-    /// let nb_lvl_5_monsters = pool.pool_slice()
-    /// .iter()
-    /// .filter(|handle| {
-    ///     handle.borrow().level == 5
-    /// })
-    /// .count();
+    /// Use it when the size of the pool comes from user input: an absurd size returns an error, instead of aborting the
+    /// program when the memory of the pool is allocated.
     ///
-    /// //All monsters begin at level 10, there is no monsters at level 5.
-    /// assert_eq!(nb_lvl_5_monsters, 0);
+    /// # Errors
+    ///
+    /// `PoolError::CapacityOverflow` is returned if the memory needed by the pool overflows `usize`, or exceeds
+    /// `isize::MAX` bytes. The closure is not called in this case.
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{PoolError, RcPool};
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity_checked(20, || {
+    ///     Monster::default()
+    /// }).unwrap();
+    /// assert_eq!(pool.nb_unused(), 20);
+    ///
+    /// match RcPool::with_capacity_checked(usize::MAX, Monster::default) {
+    ///     Err(PoolError::CapacityOverflow) => {},
+    ///     _ => panic!("the capacity should overflow"),
+    /// }
     /// ```
-    pub fn pool_slice(&self) -> &[RcHandle<T>] {
-        debug!("Getting an immutable slice of the vector containing all the RcHandles.");
-        &self.0
+    pub fn with_capacity_checked<F>(size: usize, op: F) -> PoolResult<Self>
+    where
+        F: Fn() -> T,
+    {
+        debug!("Creating a RcPool with a checked size of {} RcHandle(s)", size);
+        // The RcHandle, the stamp and the acquisition instant of each slot, and the Rc allocation of its object.
+        let slot_bytes = mem::size_of::<RcHandle<T>>()
+            + mem::size_of::<Cell<u64>>()
+            + mem::size_of::<Cell<Option<Instant>>>()
+            + 2 * mem::size_of::<usize>()
+            + mem::size_of::<RefCell<T>>();
+
+        match size.checked_mul(slot_bytes) {
+            Some(bytes) if bytes <= isize::MAX as usize => Ok(RcPool::with_capacity(size, op)),
+            _ => {
+                error!("The memory needed by a RcPool of {} RcHandle(s) overflows !", size);
+                Err(PoolError::CapacityOverflow)
+            }
+        }
     }
 
-    /// Ask the pool for an `RcHandle<T>`, returning a `PoolResult<RcHandle<T>>`. If you cannot increase the pool size because of
-    /// memory restrictions, this function may be more convenient than the "non-strict" one.
+    /// Create an object pool with the given capacity, and instantiate the given number of object.
     ///
-    /// # Errors
-    /// If all `RcHandle<T>` are used, a PoolError is returned indicating that all `RcHandle<T>` are used.
+    /// The closure is given the index of the slot of each object.
     ///
     /// # Example
     ///
     /// ```rust
     /// use maskerad_object_pool::RcPool;
     /// # use maskerad_object_pool::Recyclable;
-    /// # use std::error::Error;
     /// #
     /// # struct Monster {
     /// # hp :u32,
@@ -224,43 +361,82 @@ impl<T: Recyclable> RcPool<T> {
     /// #        self.level += 1;
     /// #    }
     /// # }
-    /// #
-    /// # fn try_main() -> Result<(), Box<Error>> {
-    /// let pool = RcPool::with_capacity(1, || {
-    ///     Monster::default()
+    /// let pool = RcPool::with_capacity_indexed(20, |index| {
+    ///     Monster {
+    ///         hp: 10,
+    ///         level: index as u32,
+    ///     }
     /// });
+    /// assert_eq!(pool.nb_unused(), 20);
+    /// ```
+    pub fn with_capacity_indexed<F>(size: usize, op: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        debug!("Creating a RcPool with a size of {} RcHandle(s), from their indices", size);
+        RcPool::with_recycler(size, op, T::reinitialize)
+    }
+
+    /// Create an object pool with one object per seed, each object being built from its seed with the given closure.
     ///
-    /// let a_monster = pool.create_strict()?;
-    /// assert!(pool.create_strict().is_err());
+    /// The object at index `i` is built from `seeds[i]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
     /// #
-    /// #   Ok(())
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
     /// # }
     /// #
-    /// # fn main() {
-    /// #   try_main().unwrap();
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
     /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let levels = [1, 5, 10];
+    /// let pool = RcPool::from_seeds(&levels, |level| {
+    ///     Monster {
+    ///         hp: 10,
+    ///         level: *level,
+    ///     }
+    /// });
+    /// assert_eq!(pool.nb_unused(), 3);
+    /// assert_eq!(pool.pool_slice()[1].borrow().level, 5);
     /// ```
-    pub fn create_strict(&self) -> PoolResult<RcHandle<T>> {
-        debug!("The RcPool is being asked a RcHandle (strict).");
-        trace!("Iterating over all the RcHandles...");
-        match self.pool_slice()
-            .iter()
-            .find(|obj| Rc::strong_count(obj.as_ref()) == 1)
-        {
-            Some(obj_ref) => {
-                trace!("A RcHandle with a reference count of 1 has been found !");
-                Ok(obj_ref.clone())
-            },
-            None => {
-                error!("The RcPool could not find a RcHandle with a reference count of 1 !");
-                Err(PoolError::PoolError(String::from(
-                    "The RcPool is out of objects !",
-                )))
-            },
-        }
+    pub fn from_seeds<S, F>(seeds: &[S], mut make: F) -> Self
+    where
+        F: FnMut(&S) -> T,
+    {
+        debug!("Creating a RcPool with a size of {} RcHandle(s), from their seeds", seeds.len());
+        RcPool::with_recycler(seeds.len(), |index| make(&seeds[index]), T::reinitialize)
     }
 
-    /// Asks the pool for an `RcHandle<T>`, returning an `Option<RcHandle<T>>`.
+    /// Create an object pool with the given capacity, and reserve the given number of objects for priority
+    /// acquisitions.
+    ///
+    /// All the acquisitions, like `create`, `create_many`, `find` or `create_with_policy`, fail when only the reserved
+    /// objects are not used, so low-priority callers cannot starve the critical ones. The forced acquisitions recycle
+    /// a used object instead. `create_priority` can give all the objects of the pool.
     ///
     /// # Example
     ///
@@ -293,40 +469,27 @@ impl<T: Recyclable> RcPool<T> {
     /// #        self.level += 1;
     /// #    }
     /// # }
-    /// let pool = RcPool::with_capacity(1, || {
+    ///
+    /// let pool = RcPool::with_priority_reserve(10, 2, || {
     ///     Monster::default()
     /// });
-    ///
-    /// let a_monster = pool.create();
-    /// assert!(a_monster.is_some());
-    /// assert!(pool.create().is_none());
-    ///
-    /// match pool.create() {
-    ///     Some(monster) => println!("will not happen."),
-    ///     None => {
-    ///         // do something, or nothing.
-    ///     },
-    /// }
+    /// assert_eq!(pool.nb_unused(), 10);
+    /// assert_eq!(pool.priority_reserve(), 2);
     /// ```
-    pub fn create(&self) -> Option<RcHandle<T>> {
-        debug!("The pool is being asked a RcHandle.");
-        trace!("Iterating over all the RcHandles...");
-        match self.pool_slice()
-            .iter()
-            .find(|obj| Rc::strong_count(obj.as_ref()) == 1)
-        {
-            Some(obj_ref) => {
-                trace!("An object with a reference count of 1 has been found !");
-                Some(obj_ref.clone())
-            },
-            None => {
-                trace!("The pool could not find an object with a reference count of 1.");
-                None
-            },
-        }
+    pub fn with_priority_reserve<F>(size: usize, reserved_for_priority: usize, op: F) -> Self
+    where
+        F: Fn() -> T,
+    {
+        debug!(
+            "Creating a RcPool with a size of {} RcHandle(s), {} of them reserved for priority acquisitions",
+            size, reserved_for_priority
+        );
+        let mut pool = RcPool::with_capacity(size, op);
+        pool.priority_reserve = reserved_for_priority;
+        pool
     }
 
-    /// Return the number of non-used `RcHandle<T>` in the pool.
+    /// Returns the number of non-used objects reserved for `create_priority`.
     ///
     /// # Example
     ///
@@ -360,24 +523,20 @@ impl<T: Recyclable> RcPool<T> {
     /// #    }
     /// # }
     ///
-    /// let pool = RcPool::with_capacity(2, || {
+    /// let pool = RcPool::with_capacity(10, || {
     ///     Monster::default()
     /// });
-    /// assert_eq!(pool.nb_unused(), 2);
-    /// let a_monster = pool.create();
-    /// assert!(a_monster.is_some());
-    /// assert_eq!(pool.nb_unused(), 1);
+    /// assert_eq!(pool.priority_reserve(), 0);
     /// ```
-    pub fn nb_unused(&self) -> usize {
-        debug!("Getting the number of unused RcHandles in the RcPool.");
-        trace!("Iterating over all the RcHandles...");
-        self.pool_slice()
-            .iter()
-            .filter(|obj| Rc::strong_count(obj.as_ref()) == 1)
-            .count()
+    pub fn priority_reserve(&self) -> usize {
+        debug!("Getting the priority reserve of the RcPool.");
+        self.priority_reserve
     }
 
-    /// Returns the maximum capacity of the vector of `RcHandle<T>`.
+    /// Create an object pool with the given capacity, and instantiate the given number of object,
+    /// reinitializing each object with `Recyclable` right after its construction.
+    ///
+    /// Fresh objects start in the same state as recycled ones.
     ///
     /// # Example
     ///
@@ -411,69 +570,5042 @@ impl<T: Recyclable> RcPool<T> {
     /// #    }
     /// # }
     ///
-    /// let pool = RcPool::with_capacity(2, || {
+    /// let pool = RcPool::with_capacity_consistent(2, || {
     ///     Monster::default()
     /// });
-    /// assert_eq!(pool.capacity(), 2);
+    ///
+    /// // Monster's default initialization set their level at 10, but they are reinitialized at level 1.
+    /// assert_eq!(pool.pool_slice()[0].borrow().level, 1);
     /// ```
-    pub fn capacity(&self) -> usize {
-        debug!("Getting the number of RcHandle contained in the RcPool.");
-        self.0.capacity()
-    }
-}
-
-#[cfg(test)]
-mod refcounted_objectpool_tests {
-    use super::*;
-    use std::rc::Rc;
-    use pool_object::Recyclable;
-
-    #[derive(Ord, PartialOrd, Eq, PartialEq, Debug)]
-    pub struct Monster {
-        name: String,
-        level: u8,
-        hp: u32,
-    }
-
-    impl Default for Monster {
-        fn default() -> Self {
-            Monster {
-                name: String::from("default name"),
-                level: 10,
-                hp: 10,
-            }
-        }
+    pub fn with_capacity_consistent<F>(size: usize, op: F) -> Self
+    where
+        F: Fn() -> T,
+    {
+        debug!("Creating a consistent RcPool with a size of {} RcHandle(s)", size);
+        RcPool::with_recycler(
+            size,
+            |_| {
+                let mut object = op();
+                object.reinitialize();
+                object
+            },
+            T::reinitialize,
+        )
     }
 
-    impl Monster {
-        pub fn level_up(&mut self) {
-            self.level += 1;
-        }
-
-        pub fn level(&self) -> u8 {
-            self.level
-        }
-
-        pub fn hp(&self) -> u32 {
-            self.hp
-        }
-    }
+    /// Create an object pool with the given capacity, whose objects are clones of a single placeholder, initialized
+    /// when they are acquired for the first time.
+    ///
+    /// The closure is called only once, to create the placeholder. Each object is reinitialized with
+    /// `Recyclable::reinitialize` right before its first acquisition, instead of being initialized when the pool is created.
+    /// This avoids running an expensive initialization for all the objects, some of them never being used.
+    ///
+    /// `T` must implement `Clone`, and `reinitialize` must set the whole state of the object: until their first acquisition,
+    /// the objects seen through `pool_slice` are clones of the placeholder.
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{RcPool, Recyclable};
+    ///
+    /// #[derive(Clone)]
+    /// struct Monster {
+    ///     hp: u32,
+    ///     level: u32,
+    /// }
+    ///
+    /// impl Recyclable for Monster {
+    ///     fn reinitialize(&mut self) {
+    ///         self.hp = 10;
+    ///         self.level = 1;
+    ///     }
+    /// }
+    ///
+    /// let pool = RcPool::with_capacity_lazy(20, || {
+    ///     Monster { hp: 0, level: 0 }
+    /// });
+    ///
+    /// // The monsters are initialized when they are acquired.
+    /// assert_eq!(pool.pool_slice()[0].borrow().level, 0);
+    /// let monster = pool.create().unwrap();
+    /// assert_eq!(monster.borrow().level, 1);
+    /// ```
+    pub fn with_capacity_lazy<F>(size: usize, op: F) -> Self
+    where
+        F: FnOnce() -> T,
+        T: Clone,
+    {
+        debug!("Creating a lazily initialized RcPool with a size of {} RcHandle(s)", size);
+        let placeholder = op();
+        let pool = RcPool::with_recycler(size, |_| placeholder.clone(), T::reinitialize);
 
-    impl Recyclable for Monster {
-        fn reinitialize(&mut self) {
-            self.level = 1;
-            self.hp = 1;
+        trace!("Deferring the initialization of the objects to their first acquisition.");
+        for handle in pool.handles.iter() {
+            handle.defer_reinitialization();
         }
+        pool
     }
 
-    #[test]
-    fn test_len() {
-        let simple_pool = RcPool::with_capacity(26, || Monster::default());
-        assert_eq!(simple_pool.capacity(), 26);
-    }
-
-    #[test]
-    fn test_is_used_at_initialization() {
+    /// Create an object pool with a capacity known at compile time, and instantiate the given number of object.
+    ///
+    /// A capacity of zero is rejected at compile time, when the crate using the pool is built: `cargo check` does not
+    /// report it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_const_capacity::<4>(|| {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.nb_unused(), 4);
+    /// ```
+    pub fn with_const_capacity<const N: usize>(op: impl Fn() -> T) -> Self {
+        let () = NonZeroCapacity::<N>::ASSERT;
+        debug!("Creating a RcPool with a constant size of {} RcHandle(s)", N);
+        RcPool::with_recycler(N, |_| op(), T::reinitialize)
+    }
+
+    /// Create an object pool with the given capacity, whose objects are never reinitialized.
+    ///
+    /// `Recyclable::reinitialize` is pure overhead for a `Copy` type that the user always fully overwrites
+    /// after acquiring it. With this pool, an object keeps the value it had when it went back to the pool:
+    /// **the user must fully initialize each object right after acquiring it**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{Recyclable, RcPool};
+    ///
+    /// #[derive(Clone, Copy, Default)]
+    /// struct Vertex {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// impl Recyclable for Vertex {
+    ///     fn reinitialize(&mut self) {
+    ///         *self = Vertex::default();
+    ///     }
+    /// }
+    ///
+    /// let pool = RcPool::with_capacity_no_reset(1, Vertex::default);
+    ///
+    /// {
+    ///     let vertex = pool.create().unwrap();
+    ///     *vertex.borrow_mut() = Vertex { x: 1.0, y: 2.0 };
+    /// }
+    ///
+    /// // The old value is still there: it must be overwritten.
+    /// let vertex = pool.create().unwrap();
+    /// assert_eq!(vertex.borrow().x, 1.0);
+    /// *vertex.borrow_mut() = Vertex { x: 3.0, y: 4.0 };
+    /// ```
+    pub fn with_capacity_no_reset<F>(size: usize, op: F) -> Self
+    where
+        F: Fn() -> T,
+        T: Copy,
+    {
+        debug!("Creating a RcPool with a size of {} RcHandle(s), never reinitialized", size);
+        RcPool::with_recycler(size, |_| op(), skip_recycle::<T>)
+    }
+
+    /// Create an object pool with the given capacity, whose objects are zeroed before being reinitialized,
+    /// when they go back to the pool.
+    ///
+    /// Use this pool for sensitive data, like keys or tokens: `Recyclable::reinitialize` may leave old bytes readable.
+    ///
+    /// This function is only available with the `zeroize` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate maskerad_object_pool;
+    /// # extern crate zeroize;
+    /// use maskerad_object_pool::RcPool;
+    /// use maskerad_object_pool::Recyclable;
+    /// use zeroize::Zeroize;
+    ///
+    /// #[derive(Default)]
+    /// struct Secret {
+    ///     key: [u8; 4],
+    /// }
+    ///
+    /// impl Zeroize for Secret {
+    ///     fn zeroize(&mut self) {
+    ///         self.key.zeroize();
+    ///     }
+    /// }
+    ///
+    /// impl Recyclable for Secret {
+    ///     fn reinitialize(&mut self) {}
+    /// }
+    ///
+    /// # fn main() {
+    /// let pool = RcPool::with_capacity_zeroizing(1, || {
+    ///     Secret::default()
+    /// });
+    ///
+    /// {
+    ///     let secret = pool.create().unwrap();
+    ///     secret.borrow_mut().key = [1, 2, 3, 4];
+    /// }
+    ///
+    /// assert_eq!(pool.pool_slice()[0].borrow().key, [0; 4]);
+    /// # }
+    /// ```
+    #[cfg(feature = "zeroize")]
+    pub fn with_capacity_zeroizing<F>(size: usize, op: F) -> Self
+    where
+        F: Fn() -> T,
+        T: Zeroize,
+    {
+        debug!("Creating a zeroizing RcPool with a size of {} RcHandle(s)", size);
+        RcPool::with_recycler(size, |_| op(), zeroize_and_reinitialize::<T>)
+    }
+
+    #[doc(hidden)]
+    pub fn from_objects(objects: Vec<T>, recycle: fn(&mut T)) -> Self {
+        trace!("Creating a RcPool from {} objects.", objects.len());
+        let size = objects.len();
+        let mut objects = objects.into_iter();
+        Self::with_recycler(size, |_| objects.next().expect("as many objects as the size of the RcPool"), recycle)
+    }
+
+    fn with_recycler<F>(size: usize, mut op: F, recycle: fn(&mut T)) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        trace!("Creating the RcHandles of the RcPool.");
+        let id = PoolId::next();
+        let recycle_enabled = Rc::new(Cell::new(true));
+        let observer = SharedObserver::default();
+        let counters = Rc::new(SlotCounters::new(size));
+        let mut objects = Vec::with_capacity(size);
+
+        for index in 0..size {
+            objects.push(RcHandle::pooled(
+                op(index),
+                recycle,
+                id,
+                index,
+                recycle_enabled.clone(),
+                observer.clone(),
+                counters.clone(),
+            ));
+        }
+
+        RcPool {
+            id,
+            stamps: (0..size).map(|_| Cell::new(0)).collect(),
+            acquired_at: (0..size).map(|_| Cell::new(None)).collect(),
+            next_stamp: Cell::new(0),
+            handles: objects,
+            recycle,
+            peak_in_use: Cell::new(0),
+            total_acquisitions: Cell::new(0),
+            on_evict: RefCell::new(None),
+            recycle_enabled,
+            max_capacity: None,
+            overflow_policy: OverflowPolicy::Fail,
+            paused: Rc::new(Cell::new(false)),
+            observer,
+            priority_reserve: 0,
+            counters,
+            #[cfg(feature = "serde")]
+            origin: None,
+        }
+    }
+
+    /// Enables or disables the reinitialization of the objects, when their last `RcHandle<T>` is dropped.
+    ///
+    /// When recycling is disabled, the released objects go back to the non-used state but keep their last values,
+    /// which helps to inspect them when chasing a use-after-recycle bug. Recycling is enabled by default.
+    ///
+    /// The objects recycled by force, with `force_create_lru` or `force_create_mru`, or with `reinitialize_slot`,
+    /// are still reinitialized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// pool.set_recycle_enabled(false);
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// a_monster.borrow_mut().level_up();
+    /// drop(a_monster);
+    ///
+    /// // The monster is not used anymore, but it has not been reinitialized.
+    /// assert_eq!(pool.nb_unused(), 1);
+    /// assert_eq!(pool.pool_slice()[0].borrow().level, 11);
+    /// ```
+    pub fn set_recycle_enabled(&self, enabled: bool) {
+        debug!("Setting the recycling of the RcPool to {}.", enabled);
+        self.recycle_enabled.set(enabled);
+    }
+
+    /// Returns `true` if the objects are reinitialized when their last `RcHandle<T>` is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// assert!(pool.is_recycle_enabled());
+    /// pool.set_recycle_enabled(false);
+    /// assert!(!pool.is_recycle_enabled());
+    /// ```
+    pub fn is_recycle_enabled(&self) -> bool {
+        debug!("Checking if the recycling of the RcPool is enabled.");
+        self.recycle_enabled.get()
+    }
+
+    /// Pauses the acquisitions: until `resume` is called, the pool gives no `RcHandle<T>`.
+    ///
+    /// It prevents new acquisitions during a critical section, like a snapshot of the pool, without tearing down
+    /// the pool. While the pool is paused, the functions returning an `Option` return `None`, and the ones returning
+    /// a `PoolResult` return `PoolError::Paused`. The used objects can still be released. The clones of the pool
+    /// are paused too.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// pool.pause();
+    /// assert!(pool.create().is_none());
+    /// assert!(pool.create_strict().is_err());
+    ///
+    /// pool.resume();
+    /// assert!(pool.create().is_some());
+    /// ```
+    pub fn pause(&self) {
+        debug!("Pausing the acquisitions of the RcPool.");
+        self.paused.set(true);
+    }
+
+    /// Resumes the acquisitions paused by `pause`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// pool.pause();
+    /// assert!(pool.is_paused());
+    /// pool.resume();
+    /// assert!(!pool.is_paused());
+    /// ```
+    pub fn resume(&self) {
+        debug!("Resuming the acquisitions of the RcPool.");
+        self.paused.set(false);
+    }
+
+    /// Returns `true` if the acquisitions are paused, see `pause`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// assert!(!pool.is_paused());
+    /// ```
+    pub fn is_paused(&self) -> bool {
+        trace!("Checking if the acquisitions of the RcPool are paused.");
+        self.paused.get()
+    }
+
+    /// Returns the unique identifier of the pool.
+    ///
+    /// The `RcHandle<T>`s created by this pool return the same `PoolId` with `RcHandle::pool_id`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    /// let other_pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// assert_ne!(pool.id(), other_pool.id());
+    /// ```
+    pub fn id(&self) -> PoolId {
+        debug!("Getting the PoolId of the RcPool.");
+        self.id
+    }
+
+    /// Returns an immutable slice of the vector of `RcHandle<T>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(10, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// //The pool slice can be useful if you need tou iterate over the collection.
+    /// let nb_lvl_5_monsters = pool.pool_slice()
+    /// .iter()
+    /// .filter(|handle| {
+    ///     handle.borrow().level == 5
+    /// })
+    /// .count();
+    ///
+    /// //All monsters begin at level 10, there is no monsters at level 5.
+    /// assert_eq!(nb_lvl_5_monsters, 0);
+    /// ```
+    pub fn pool_slice(&self) -> &[RcHandle<T>] {
+        debug!("Getting an immutable slice of the vector containing all the RcHandles.");
+        &self.handles
+    }
+
+    /// Returns an iterator over the slots of the pool, yielding the index of each slot, whether it is
+    /// in use, and its `RcHandle<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    /// let a_monster = pool.create().unwrap();
+    ///
+    /// let states: Vec<(usize, bool)> = pool.slots().map(|(index, in_use, _)| (index, in_use)).collect();
+    /// assert_eq!(states, vec![(0, true), (1, false), (2, false)]);
+    /// ```
+    pub fn slots(&self) -> impl Iterator<Item = (usize, bool, &RcHandle<T>)> + '_ {
+        debug!("Iterating over the slots of the RcPool.");
+        self.handles
+            .iter()
+            .enumerate()
+            .map(|(index, handle)| (index, !Self::is_free(handle), handle))
+    }
+
+    /// Returns the `RcHandle<T>`s whose object matches the given predicate, used or not, without acquiring them.
+    ///
+    /// The objects currently mutably borrowed are skipped, so this function never panics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(10, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// a_monster.borrow_mut().level_up();
+    ///
+    /// // Used or not, only one monster is above level 10.
+    /// let strong_monsters = pool.filter_handles(|monster| monster.level > 10);
+    /// assert_eq!(strong_monsters.len(), 1);
+    /// assert!(strong_monsters[0].ptr_eq(&a_monster));
+    /// ```
+    pub fn filter_handles<P>(&self, pred: P) -> Vec<&RcHandle<T>>
+    where
+        P: Fn(&T) -> bool,
+    {
+        debug!("Getting the RcHandles matching a predicate.");
+        trace!("Iterating over all the RcHandles...");
+        self.handles
+            .iter()
+            .filter(|handle| match handle.try_borrow() {
+                Ok(object) => pred(&object),
+                Err(_) => {
+                    trace!("An object is mutably borrowed, skipping it.");
+                    false
+                }
+            })
+            .collect()
+    }
+
+    /// Ask the pool for an `RcHandle<T>`, returning a `PoolResult<RcHandle<T>>`. If you cannot increase the pool size because of
+    /// memory restrictions, this function may be more convenient than the "non-strict" one.
+    ///
+    /// # Errors
+    /// If all `RcHandle<T>` are used, a PoolError is returned indicating that all `RcHandle<T>` are used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// # use std::error::Error;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create_strict()?;
+    /// assert!(pool.create_strict().is_err());
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #   try_main().unwrap();
+    /// # }
+    /// ```
+    pub fn create_strict(&self) -> PoolResult<RcHandle<T>> {
+        debug!("The RcPool is being asked a RcHandle (strict).");
+        if self.is_paused() {
+            return Err(PoolError::Paused);
+        }
+        trace!("Iterating over all the RcHandles...");
+        match self.first_unused_beyond_reserve() {
+            Some(index) => {
+                trace!("A RcHandle with a reference count of 1 has been found !");
+                let handle = self.acquire(index);
+                self.update_peak_in_use();
+                Ok(handle)
+            },
+            None => {
+                error!("The RcPool could not find a RcHandle with a reference count of 1 !");
+                self.notify_exhausted();
+                Err(PoolError::PoolError(String::from(
+                    "The RcPool is out of objects !",
+                )))
+            },
+        }
+    }
+
+    /// Asks the pool for an `RcHandle<T>`, returning an `Option<RcHandle<T>>`.
+    ///
+    /// The non-used `RcHandle<T>` with the lowest index is always returned. Under acquire/release churn,
+    /// the same few slots at the front of the pool are reused, keeping the working set small and hot in cache.
+    ///
+    /// If all `RcHandle<T>` are used, `None` is returned, unless the `OverflowPolicy::Allocate` policy is set:
+    /// a non-pooled `RcHandle<T>` is then returned, see `set_overflow_policy`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create();
+    /// assert!(a_monster.is_some());
+    /// assert!(pool.create().is_none());
+    ///
+    /// match pool.create() {
+    ///     Some(monster) => println!("will not happen."),
+    ///     None => {
+    ///         // do something, or nothing.
+    ///     },
+    /// }
+    /// ```
+    pub fn create(&self) -> Option<RcHandle<T>> {
+        debug!("The pool is being asked a RcHandle.");
+        self.create_from(false)
+    }
+
+    /// Asks the pool for a `RcHandle<T>`, using the objects reserved for priority acquisitions if needed.
+    ///
+    /// Unlike `create`, this function can give the last non-used objects of a pool created with
+    /// `with_priority_reserve`. Otherwise, it behaves like `create`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_priority_reserve(2, 1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// assert!(pool.create().is_none());
+    ///
+    /// let a_critical_monster = pool.create_priority().unwrap();
+    /// assert!(pool.create_priority().is_none());
+    /// ```
+    pub fn create_priority(&self) -> Option<RcHandle<T>> {
+        debug!("The pool is being asked a RcHandle, with priority.");
+        self.create_from(true)
+    }
+
+    // Gives the first non-used RcHandle, or applies the overflow policy. The objects reserved for priority
+    // acquisitions are given only if `priority` is true.
+    fn create_from(&self, priority: bool) -> Option<RcHandle<T>> {
+        if self.is_paused() {
+            return None;
+        }
+        if let Some(handle) = self.acquire_first_unused(priority) {
+            return Some(handle);
+        }
+
+        match self.overflow_policy {
+            OverflowPolicy::Fail => None,
+            OverflowPolicy::Allocate(op) => {
+                warn!("The RcPool is saturated, allocating an object outside of the pool.");
+                Some(RcHandle::overflow(op(), self.id))
+            }
+        }
+    }
+
+    // Gives the first non-used RcHandle, ignoring the overflow policy. The objects reserved for priority
+    // acquisitions are given only if `priority` is true.
+    fn acquire_first_unused(&self, priority: bool) -> Option<RcHandle<T>> {
+        if self.is_paused() {
+            return None;
+        }
+        trace!("Iterating over all the RcHandles...");
+        let index = if priority {
+            self.first_unused()
+        } else {
+            self.first_unused_beyond_reserve()
+        };
+        match index {
+            Some(index) => {
+                trace!("An object with a reference count of 1 has been found !");
+                let handle = self.acquire(index);
+                self.update_peak_in_use();
+                Some(handle)
+            },
+            None => {
+                trace!("The pool could not find an object with a reference count of 1.");
+                self.notify_exhausted();
+                None
+            },
+        }
+    }
+
+    /// Asks the pool for a `RcHandle<T>`, and returns it along with the index of its slot in the pool.
+    ///
+    /// It helps to build external index structures, without looking up the slot of the `RcHandle<T>` afterwards.
+    ///
+    /// Unlike `create`, the overflow policy is ignored: an object allocated outside of the pool has no slot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let (index, a_monster) = pool.create_indexed().unwrap();
+    /// assert_eq!(index, 0);
+    /// assert!(a_monster.ptr_eq(&pool.pool_slice()[index]));
+    ///
+    /// let (index, another_monster) = pool.create_indexed().unwrap();
+    /// assert_eq!(index, 1);
+    /// assert!(pool.create_indexed().is_none());
+    /// ```
+    pub fn create_indexed(&self) -> Option<(usize, RcHandle<T>)> {
+        debug!("The pool is being asked a RcHandle and the index of its slot.");
+        if self.is_paused() {
+            return None;
+        }
+        trace!("Iterating over all the RcHandles...");
+        match self.first_unused_beyond_reserve() {
+            Some(index) => {
+                trace!("An object with a reference count of 1 has been found at index {} !", index);
+                let handle = self.acquire(index);
+                self.update_peak_in_use();
+                Some((index, handle))
+            },
+            None => {
+                trace!("The pool could not find an object with a reference count of 1.");
+                self.notify_exhausted();
+                None
+            },
+        }
+    }
+
+    /// Returns owned clones of all the `RcHandle<T>` of the pool, in slot order.
+    ///
+    /// Unlike `pool_slice`, the returned `RcHandle<T>` do not borrow the pool, so they can be kept to be processed later.
+    /// While the snapshot is held, **all the objects of the pool are considered as used**: the pool cannot give
+    /// any of them. When the snapshot is dropped, the objects go back to the pool, and the non-used ones are reinitialized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let snapshot = pool.clone_all_handles();
+    /// assert_eq!(snapshot.len(), 3);
+    /// assert_eq!(pool.nb_unused(), 0);
+    ///
+    /// drop(snapshot);
+    /// assert_eq!(pool.nb_unused(), 3);
+    /// ```
+    pub fn clone_all_handles(&self) -> Vec<RcHandle<T>> {
+        debug!("Cloning all the RcHandles of the RcPool.");
+        self.handles.to_vec()
+    }
+
+    /// Asks the pool for an `RcHandle<T>`, returning the reason of the failure if no `RcHandle<T>` can be given.
+    ///
+    /// `PoolError::Exhausted` is returned if all `RcHandle<T>` are used, and the overflow policy of the pool
+    /// does not allocate an object. It helps the caller to decide between growing the pool, retrying later, or failing.
+    /// `create` is kept for the callers which do not need the reason.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{PoolError, RcPool};
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create_detailed().unwrap();
+    /// match pool.create_detailed() {
+    ///     Err(PoolError::Exhausted) => {},
+    ///     _ => panic!("All the monsters are used."),
+    /// }
+    /// ```
+    pub fn create_detailed(&self) -> PoolResult<RcHandle<T>> {
+        debug!("The RcPool is being asked a RcHandle, with the reason of a failure.");
+        if self.is_paused() {
+            return Err(PoolError::Paused);
+        }
+        self.create().ok_or_else(|| {
+            error!("All the RcHandles of the RcPool are used !");
+            PoolError::Exhausted
+        })
+    }
+
+    /// Returns clones of the values of all the objects of the pool, used or not, in slot order, in a contiguous `Vec<T>`.
+    ///
+    /// The objects live behind `RefCell`s, so they cannot be accessed as a contiguous `&[T]`. This snapshot can be used
+    /// for bulk numeric processing, like SIMD. Modifying the returned values does not modify the objects of the pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an object is currently mutably borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # #[derive(Clone)]
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    /// pool.pool_slice()[1].borrow_mut().level_up();
+    ///
+    /// let levels: Vec<u32> = pool.collect_values().iter().map(|monster| monster.level).collect();
+    /// assert_eq!(levels, vec![10, 11, 10]);
+    /// ```
+    pub fn collect_values(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        debug!("Collecting the values of all the objects of the RcPool.");
+        self.handles.iter().map(|handle| handle.borrow().clone()).collect()
+    }
+
+    /// Returns `true` if an object of the pool is equal to the given value.
+    ///
+    /// Only the used objects are compared, unless `include_free` is `true`: the non-used objects are then compared
+    /// too, in their recycled state. The objects currently mutably borrowed are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # #[derive(PartialEq)]
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// a_monster.borrow_mut().level_up();
+    ///
+    /// assert!(pool.contains_value(&Monster { hp: 10, level: 11 }, false));
+    /// assert!(!pool.contains_value(&Monster::default(), false));
+    /// assert!(pool.contains_value(&Monster::default(), true));
+    /// ```
+    pub fn contains_value(&self, value: &T, include_free: bool) -> bool
+    where
+        T: PartialEq,
+    {
+        debug!("Checking if an object of the RcPool is equal to a value.");
+        self.handles
+            .iter()
+            .filter(|handle| include_free || !Self::is_free(handle))
+            .any(|handle| handle.try_borrow().is_ok_and(|object| *object == *value))
+    }
+
+    /// Asks the pool for an `RcHandle<T>`, letting an `AcquisitionPolicy` choose the slot to acquire.
+    ///
+    /// `None` is returned if the policy does not select a non-used slot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{Mru, RcPool};
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// let another_monster = pool.create().unwrap();
+    /// drop(a_monster);
+    /// drop(another_monster);
+    ///
+    /// // The second slot is the most recently acquired one.
+    /// let monster = pool.create_with_policy(&Mru).unwrap();
+    /// assert!(monster.ptr_eq(&pool.pool_slice()[1]));
+    /// ```
+    pub fn create_with_policy<P>(&self, policy: &P) -> Option<RcHandle<T>>
+    where
+        P: AcquisitionPolicy<T>,
+    {
+        debug!("The RcPool is being asked a RcHandle, with an acquisition policy.");
+        if self.is_paused() || self.nb_available() == 0 {
+            return None;
+        }
+        let slots: Vec<SlotInfo<T>> = self.handles
+            .iter()
+            .enumerate()
+            .map(|(index, handle)| SlotInfo::new(index, !self.is_unused(index), self.stamps[index].get(), handle))
+            .collect();
+
+        match policy.select(&slots) {
+            Some(index) if index < self.handles.len() && self.is_unused(index) => {
+                trace!("The acquisition policy selected the non-used RcHandle at index {}.", index);
+                let handle = self.acquire(index);
+                self.update_peak_in_use();
+                Some(handle)
+            }
+            _ => {
+                trace!("The acquisition policy did not select a non-used RcHandle.");
+                None
+            }
+        }
+    }
+
+    /// Asks the pool for the first non-used `RcHandle<T>` whose object matches the given predicate.
+    ///
+    /// Only the non-used objects are given to the predicate, and the objects currently mutably borrowed are skipped.
+    /// `None` is returned if no non-used object matches the predicate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// pool.pool_slice()[2].borrow_mut().level_up();
+    ///
+    /// let strong_monster = pool.find(|monster| monster.level > 10).unwrap();
+    /// assert!(strong_monster.ptr_eq(&pool.pool_slice()[2]));
+    ///
+    /// // The only monster with a level greater than 10 is used.
+    /// assert!(pool.find(|monster| monster.level > 10).is_none());
+    /// ```
+    pub fn find<P>(&self, pred: P) -> Option<RcHandle<T>>
+    where
+        P: Fn(&T) -> bool,
+    {
+        debug!("The RcPool is being asked a RcHandle matching a predicate.");
+        if self.is_paused() || self.nb_available() == 0 {
+            return None;
+        }
+        trace!("Iterating over all the non-used RcHandles...");
+        let found = (0..self.handles.len()).find(|&index| {
+            if !self.is_unused(index) {
+                return false;
+            }
+            self.handles[index].reinitialize_if_needed();
+            match self.handles[index].try_borrow() {
+                Ok(object) => pred(&object),
+                Err(_) => {
+                    trace!("The object at index {} is borrowed, skipping it.", index);
+                    false
+                }
+            }
+        });
+
+        match found {
+            Some(index) => {
+                trace!("The non-used object at index {} matches the predicate.", index);
+                let handle = self.acquire(index);
+                self.update_peak_in_use();
+                Some(handle)
+            }
+            None => {
+                trace!("No non-used object matches the predicate.");
+                None
+            }
+        }
+    }
+
+    /// Asks the pool for an `RcHandle<T>` whose object is valid, according to its `Validate` implementation.
+    ///
+    /// The non-used objects which are not valid are skipped. `None` is returned if no non-used object is valid.
+    /// Use `create_valid_or_renew` to rebuild the invalid objects instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{RcPool, Recyclable, Validate};
+    /// #
+    /// # struct Connection {
+    /// #     alive: bool,
+    /// # }
+    /// #
+    /// # impl Recyclable for Connection {
+    /// #     fn reinitialize(&mut self) {}
+    /// # }
+    /// #
+    /// # impl Validate for Connection {
+    /// #     fn is_valid(&self) -> bool {
+    /// #         self.alive
+    /// #     }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity_indexed(2, |index| {
+    ///     Connection { alive: index == 1 }
+    /// });
+    ///
+    /// // The first connection has timed out.
+    /// let connection = pool.create_valid().unwrap();
+    /// assert!(connection.ptr_eq(&pool.pool_slice()[1]));
+    /// assert!(pool.create_valid().is_none());
+    /// ```
+    pub fn create_valid(&self) -> Option<RcHandle<T>>
+    where
+        T: Validate,
+    {
+        debug!("The RcPool is being asked a RcHandle whose object is valid.");
+        self.create_validated(None)
+    }
+
+    /// Asks the pool for an `RcHandle<T>` whose object is valid, according to its `Validate` implementation,
+    /// rebuilding the invalid objects with the given closure.
+    ///
+    /// The non-used objects which are not valid are replaced by the objects created by `renew`. `None` is returned
+    /// if all `RcHandle<T>` are used, or if no renewed object is valid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{RcPool, Recyclable, Validate};
+    /// #
+    /// # struct Connection {
+    /// #     alive: bool,
+    /// # }
+    /// #
+    /// # impl Recyclable for Connection {
+    /// #     fn reinitialize(&mut self) {}
+    /// # }
+    /// #
+    /// # impl Validate for Connection {
+    /// #     fn is_valid(&self) -> bool {
+    /// #         self.alive
+    /// #     }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Connection { alive: false }
+    /// });
+    ///
+    /// // The first connection has timed out, it is renewed.
+    /// let connection = pool.create_valid_or_renew(|| Connection { alive: true }).unwrap();
+    /// assert!(connection.ptr_eq(&pool.pool_slice()[0]));
+    /// assert!(connection.borrow().alive);
+    /// ```
+    pub fn create_valid_or_renew<F>(&self, mut renew: F) -> Option<RcHandle<T>>
+    where
+        T: Validate,
+        F: FnMut() -> T,
+    {
+        debug!("The RcPool is being asked a RcHandle whose object is valid, renewing the invalid ones.");
+        self.create_validated(Some(&mut renew))
+    }
+
+    /// Asks the pool for an `RcHandle<T>` whose object is valid, returning the reason of the failure if no
+    /// `RcHandle<T>` can be given.
+    ///
+    /// `PoolError::Exhausted` is returned if all `RcHandle<T>` are used, and `PoolError::AllInvalid` if no non-used
+    /// object is valid, according to its `Validate` implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{PoolError, RcPool, Recyclable, Validate};
+    /// #
+    /// # struct Connection {
+    /// #     alive: bool,
+    /// # }
+    /// #
+    /// # impl Recyclable for Connection {
+    /// #     fn reinitialize(&mut self) {}
+    /// # }
+    /// #
+    /// # impl Validate for Connection {
+    /// #     fn is_valid(&self) -> bool {
+    /// #         self.alive
+    /// #     }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Connection { alive: false }
+    /// });
+    ///
+    /// match pool.create_valid_detailed() {
+    ///     Err(PoolError::AllInvalid) => {},
+    ///     _ => panic!("The connection has timed out."),
+    /// }
+    /// ```
+    pub fn create_valid_detailed(&self) -> PoolResult<RcHandle<T>>
+    where
+        T: Validate,
+    {
+        debug!("The RcPool is being asked a RcHandle whose object is valid, with the reason of a failure.");
+        if self.is_paused() {
+            return Err(PoolError::Paused);
+        }
+        if let Some(handle) = self.create_validated(None) {
+            return Ok(handle);
+        }
+
+        if self.nb_available() > 0 {
+            error!("All the non-used objects of the RcPool are not valid !");
+            Err(PoolError::AllInvalid)
+        } else {
+            error!("All the RcHandles of the RcPool are used !");
+            Err(PoolError::Exhausted)
+        }
+    }
+
+    // Acquires the first non-used valid object, renewing the invalid ones if a closure is given.
+    fn create_validated(&self, mut renew: Option<&mut dyn FnMut() -> T>) -> Option<RcHandle<T>>
+    where
+        T: Validate,
+    {
+        if self.is_paused() || self.nb_available() == 0 {
+            return None;
+        }
+        trace!("Iterating over all the non-used RcHandles...");
+        for index in 0..self.handles.len() {
+            if !self.is_unused(index) {
+                continue;
+            }
+            self.handles[index].reinitialize_if_needed();
+            let mut object = match self.handles[index].try_borrow_mut() {
+                Ok(object) => object,
+                Err(_) => {
+                    trace!("The object at index {} is borrowed, skipping it.", index);
+                    continue;
+                }
+            };
+
+            if !object.is_valid() {
+                match renew {
+                    Some(ref mut renew) => {
+                        warn!("The object at index {} is not valid, renewing it.", index);
+                        *object = renew();
+                        if !object.is_valid() {
+                            warn!("The renewed object at index {} is not valid, skipping it.", index);
+                            continue;
+                        }
+                    }
+                    None => {
+                        trace!("The object at index {} is not valid, skipping it.", index);
+                        continue;
+                    }
+                }
+            }
+
+            drop(object);
+            let handle = self.acquire(index);
+            self.update_peak_in_use();
+            return Some(handle);
+        }
+
+        trace!("No non-used object is valid.");
+        None
+    }
+
+    /// Asks the pool for an `RcHandle<T>`, and mutably borrows its object in the same call, returning
+    /// an `Option<RcHandleMut<T>>`.
+    ///
+    /// `None` is returned if all `RcHandle<T>` are used, or if the object of the first non-used `RcHandle<T>`
+    /// is currently borrowed. This function never panics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = {
+    ///     let mut monster = pool.create_and_borrow_mut().unwrap();
+    ///     monster.level_up();
+    ///     monster.into_handle()
+    /// };
+    ///
+    /// assert_eq!(monster.borrow().level, 11);
+    /// assert!(pool.create_and_borrow_mut().is_none());
+    /// ```
+    pub fn create_and_borrow_mut(&self) -> Option<RcHandleMut<'_, T>> {
+        debug!("The RcPool is being asked a RcHandle, with a mutable borrow of its object.");
+        if self.is_paused() {
+            return None;
+        }
+        let index = match self.first_unused_beyond_reserve() {
+            Some(index) => index,
+            None => {
+                trace!("The pool could not find an object with a reference count of 1.");
+                return None;
+            }
+        };
+
+        // Borrow before acquiring: the handle must not be dropped while its object is borrowed.
+        self.handles[index].reinitialize_if_needed();
+        match self.handles[index].try_borrow_mut() {
+            Ok(object) => {
+                trace!("The object at index {} has been mutably borrowed.", index);
+                let handle = self.acquire(index);
+                self.update_peak_in_use();
+                Some(RcHandleMut::new(handle, object))
+            }
+            Err(_) => {
+                trace!("The object at index {} is currently borrowed.", index);
+                None
+            }
+        }
+    }
+
+    /// Asks the pool for an `RcHandle<T>`, and configures its object with the given closure before returning it.
+    ///
+    /// `None` is returned if all `RcHandle<T>` are used, or if the object of the first non-used `RcHandle<T>`
+    /// is currently borrowed, like `create_and_borrow_mut`. The closure is not called in this case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create_with(|monster| monster.level = 5).unwrap();
+    /// assert_eq!(monster.borrow().level, 5);
+    /// assert!(pool.create_with(|monster| monster.level = 5).is_none());
+    /// ```
+    pub fn create_with<F>(&self, init: F) -> Option<RcHandle<T>>
+    where
+        F: FnOnce(&mut T),
+    {
+        debug!("The RcPool is being asked a RcHandle, configured by a closure.");
+        let mut object = self.create_and_borrow_mut()?;
+        trace!("Configuring the object of the RcHandle.");
+        init(&mut object);
+        Some(object.into_handle())
+    }
+
+    /// Sets the callback called when `force_create_lru` or `force_create_mru` recycles by force an object
+    /// which is still used. It is given the index of the object in the pool.
+    ///
+    /// The callback is not called when an object is recycled normally, after all its `RcHandle<T>`s have been dropped.
+    /// It replaces the previous callback, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let evicted = Rc::new(Cell::new(None));
+    /// let evicted_clone = evicted.clone();
+    /// pool.on_evict(Box::new(move |index| evicted_clone.set(Some(index))));
+    ///
+    /// let old_monster = pool.create().unwrap();
+    /// let monster = pool.force_create_lru().unwrap();
+    /// assert_eq!(evicted.get(), Some(0));
+    /// ```
+    pub fn on_evict(&self, f: Box<dyn Fn(usize)>) {
+        debug!("Setting the eviction callback of the RcPool.");
+        *self.on_evict.borrow_mut() = Some(EvictionCallback(Rc::from(f)));
+    }
+
+    /// Sets the observer notified of the events of the pool: the acquisitions, the releases, the exhaustion and
+    /// the growth of the pool. It replaces the previous observer, if any.
+    ///
+    /// The observer is shared with the clones of the pool. The log records of the pool are still emitted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{PoolObserver, RcPool};
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// struct ExhaustionCounter(Rc<Cell<usize>>);
+    ///
+    /// impl PoolObserver for ExhaustionCounter {
+    ///     fn on_exhausted(&self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    /// let nb_exhaustions = Rc::new(Cell::new(0));
+    /// pool.set_observer(Box::new(ExhaustionCounter(nb_exhaustions.clone())));
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// assert!(pool.create().is_none());
+    /// assert_eq!(nb_exhaustions.get(), 1);
+    /// ```
+    pub fn set_observer(&self, observer: Box<dyn PoolObserver>) {
+        debug!("Setting the observer of the RcPool.");
+        self.observer.set(observer);
+    }
+
+    /// Asks the pool for an `RcHandle<T>`, recycling by force the least recently acquired object if all
+    /// `RcHandle<T>` are used.
+    ///
+    /// The recycled object is reinitialized, even if other `RcHandle<T>`s still point to it: they will see its
+    /// new state. Objects currently borrowed are never recycled.
+    ///
+    /// Returns `None` if the pool is empty, or if all objects are currently borrowed. The overflow policy is ignored:
+    /// an object is recycled instead of being allocated outside of the pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let oldest_monster = pool.create().unwrap();
+    /// oldest_monster.borrow_mut().level_up();
+    /// let newest_monster = pool.create().unwrap();
+    ///
+    /// let monster = pool.force_create_lru().unwrap();
+    /// assert!(monster.ptr_eq(&oldest_monster));
+    /// assert_eq!(oldest_monster.borrow().level, 1);
+    /// ```
+    pub fn force_create_lru(&self) -> Option<RcHandle<T>> {
+        debug!("The RcPool is being asked a RcHandle, recycling the least recently acquired one if needed.");
+        if let Some(handle) = self.acquire_first_unused(false) {
+            return Some(handle);
+        }
+
+        trace!("All the RcHandles are used, looking for the least recently acquired one...");
+        let mut indices: Vec<usize> = (0..self.handles.len()).collect();
+        indices.sort_by_key(|&index| self.stamps[index].get());
+        self.force_recycle(indices)
+    }
+
+    /// Asks the pool for an `RcHandle<T>`, recycling by force the most recently acquired object if all
+    /// `RcHandle<T>` are used.
+    ///
+    /// This is the counterpart of `force_create_lru`, useful to keep a working set of older objects.
+    /// The recycled object is reinitialized, even if other `RcHandle<T>`s still point to it: they will see its
+    /// new state. Objects currently borrowed are never recycled.
+    ///
+    /// Returns `None` if the pool is empty, or if all objects are currently borrowed. The overflow policy is ignored:
+    /// an object is recycled instead of being allocated outside of the pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let oldest_monster = pool.create().unwrap();
+    /// let newest_monster = pool.create().unwrap();
+    /// newest_monster.borrow_mut().level_up();
+    ///
+    /// let monster = pool.force_create_mru().unwrap();
+    /// assert!(monster.ptr_eq(&newest_monster));
+    /// assert_eq!(newest_monster.borrow().level, 1);
+    /// ```
+    pub fn force_create_mru(&self) -> Option<RcHandle<T>> {
+        debug!("The RcPool is being asked a RcHandle, recycling the most recently acquired one if needed.");
+        if let Some(handle) = self.acquire_first_unused(false) {
+            return Some(handle);
+        }
+
+        trace!("All the RcHandles are used, looking for the most recently acquired one...");
+        let mut indices: Vec<usize> = (0..self.handles.len()).collect();
+        indices.sort_by_key(|&index| Reverse(self.stamps[index].get()));
+        self.force_recycle(indices)
+    }
+
+    /// Asks the pool for `n` `RcHandle<T>` at once, returning `None` if the pool does not have
+    /// enough non-used `RcHandle<T>`.
+    ///
+    /// The request is rejected before any `RcHandle<T>` is acquired: the pool is left untouched when it fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monsters = pool.create_many(2).unwrap();
+    /// assert_eq!(monsters.len(), 2);
+    /// assert_eq!(pool.nb_unused(), 1);
+    ///
+    /// assert!(pool.create_many(2).is_none());
+    /// assert_eq!(pool.nb_unused(), 1);
+    /// ```
+    pub fn create_many(&self, n: usize) -> Option<Vec<RcHandle<T>>> {
+        debug!("The RcPool is being asked {} RcHandle(s).", n);
+        if self.is_paused() {
+            return None;
+        }
+        let indices = self.unused_indices(n)?;
+        let handles = indices
+            .into_iter()
+            .map(|index| self.acquire(index))
+            .collect();
+        self.update_peak_in_use();
+
+        Some(handles)
+    }
+
+    /// Reserves `n` non-used `RcHandle<T>` at once, returning a `ReservationToken<T>`, or `None`
+    /// if the pool does not have enough non-used `RcHandle<T>`.
+    ///
+    /// The reserved objects are considered used, and cannot be given by any other function of the pool.
+    /// They can be taken from the token with `take_from_reservation`. The objects which have not been taken
+    /// go back to the pool when the token is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// {
+    ///     let mut token = pool.reserve_group(2).unwrap();
+    ///     assert_eq!(pool.nb_unused(), 1);
+    ///     assert!(pool.reserve_group(2).is_none());
+    ///
+    ///     let a_monster = pool.take_from_reservation(&mut token);
+    ///     assert!(a_monster.is_some());
+    ///     assert_eq!(token.remaining(), 1);
+    /// }
+    ///
+    /// assert_eq!(pool.nb_unused(), 3);
+    /// ```
+    pub fn reserve_group(&self, n: usize) -> Option<ReservationToken<T>> {
+        debug!("The RcPool is being asked to reserve {} RcHandle(s).", n);
+        if self.is_paused() {
+            return None;
+        }
+        let indices = self.unused_indices(n)?;
+        // The token gives its handles from the end of the vector.
+        let handles = indices
+            .into_iter()
+            .rev()
+            .map(|index| self.acquire(index))
+            .collect();
+        self.update_peak_in_use();
+
+        Some(ReservationToken::new(handles))
+    }
+
+    /// Takes a `RcHandle<T>` from a `ReservationToken<T>` created by this pool, returning `None`
+    /// if all the reserved `RcHandle<T>` have been taken.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let mut token = pool.reserve_group(1).unwrap();
+    /// assert!(pool.take_from_reservation(&mut token).is_some());
+    /// assert!(pool.take_from_reservation(&mut token).is_none());
+    /// ```
+    pub fn take_from_reservation(&self, token: &mut ReservationToken<T>) -> Option<RcHandle<T>> {
+        debug!("Taking a RcHandle from a ReservationToken of the RcPool.");
+        token.take()
+    }
+
+    /// Gives a `RcHandle<T>` back to the pool explicitly, instead of relying on its `Drop` implementation.
+    ///
+    /// The object is reinitialized and can be given again by the pool.
+    ///
+    /// # Errors
+    /// If the `RcHandle<T>` does not come from this pool, or if other clones of the `RcHandle<T>`
+    /// still exist, a PoolError is returned along with the `RcHandle<T>`, so it can be used or released again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// # use std::error::Error;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create_strict()?;
+    /// a_monster.borrow_mut().level_up();
+    /// assert_eq!(pool.nb_unused(), 1);
+    ///
+    /// pool.release(a_monster).map_err(|(error, _)| error)?;
+    /// assert_eq!(pool.nb_unused(), 2);
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #   try_main().unwrap();
+    /// # }
+    /// ```
+    pub fn release(&self, handle: RcHandle<T>) -> Result<(), (PoolError, RcHandle<T>)> {
+        debug!("A RcHandle is being released explicitly to the RcPool.");
+        trace!("Iterating over all the RcHandles...");
+        if !self.pool_slice().iter().any(|obj| obj.ptr_eq(&handle)) {
+            error!("The released RcHandle does not come from this RcPool !");
+            return Err((
+                PoolError::PoolError(String::from("The RcHandle does not come from this RcPool !")),
+                handle,
+            ));
+        }
+
+        if Rc::strong_count(handle.as_ref()) != 2 {
+            error!("The released RcHandle is still shared by other clones !");
+            return Err((
+                PoolError::PoolError(String::from("Other clones of the RcHandle still exist !")),
+                handle,
+            ));
+        }
+
+        trace!("Dropping the released RcHandle, which reinitializes its inner object.");
+        drop(handle);
+        Ok(())
+    }
+
+    /// Releases explicitly a batch of `RcHandle<T>`, like the ones given by `create_many`, reinitializing their objects
+    /// in the order of the vector.
+    ///
+    /// It recycles a wave of objects deterministically, instead of relying on scattered drops.
+    ///
+    /// # Errors
+    /// Every `RcHandle<T>` is released, even after a failure. The `RcHandle<T>` which do not come from this pool,
+    /// or which are not the last clone of their object, see `release`, are returned with their PoolError,
+    /// in the order of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// # use std::error::Error;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monsters = pool.create_many(3).unwrap();
+    /// assert_eq!(pool.nb_unused(), 0);
+    ///
+    /// assert!(pool.release_all(monsters).is_ok());
+    /// assert_eq!(pool.nb_unused(), 3);
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #   try_main().unwrap();
+    /// # }
+    /// ```
+    pub fn release_all(&self, handles: Vec<RcHandle<T>>) -> Result<(), Vec<(PoolError, RcHandle<T>)>> {
+        debug!("{} RcHandle(s) are being released explicitly to the RcPool.", handles.len());
+        let failures: Vec<(PoolError, RcHandle<T>)> = handles
+            .into_iter()
+            .filter_map(|handle| self.release(handle).err())
+            .collect();
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            error!("{} RcHandle(s) could not be released to the RcPool !", failures.len());
+            Err(failures)
+        }
+    }
+
+    /// Reinitializes by force the non-used object at the given index.
+    ///
+    /// It is useful to recover a single object which has been detected as corrupted.
+    ///
+    /// # Errors
+    /// If the index is out of bounds, if the object is used, or if it is currently borrowed, a PoolError is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// # use std::error::Error;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// pool.reinitialize_slot(1)?;
+    /// assert_eq!(pool.pool_slice()[1].borrow().level, 1);
+    /// assert!(pool.reinitialize_slot(2).is_err());
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #   try_main().unwrap();
+    /// # }
+    /// ```
+    pub fn reinitialize_slot(&self, index: usize) -> PoolResult<()> {
+        debug!("Reinitializing by force the object at index {} of the RcPool.", index);
+        if index >= self.handles.len() {
+            error!("The index {} is out of bounds !", index);
+            return Err(PoolError::PoolError(format!(
+                "The index {} is out of bounds of the RcPool !",
+                index
+            )));
+        }
+
+        if !self.is_unused(index) {
+            error!("The object at index {} is used !", index);
+            return Err(PoolError::PoolError(format!(
+                "The object at index {} is used !",
+                index
+            )));
+        }
+
+        match self.handles[index].try_borrow_mut() {
+            Ok(mut object) => {
+                trace!("Reinitializing the object at index {}.", index);
+                (self.recycle)(&mut object);
+                self.handles[index].clear_deferred_reinitialization();
+                Ok(())
+            }
+            Err(_) => {
+                error!("The object at index {} is currently borrowed !", index);
+                Err(PoolError::PoolError(format!(
+                    "The object at index {} is currently borrowed !",
+                    index
+                )))
+            }
+        }
+    }
+
+    /// Calls the given closure on every object of the pool, used or not, in slot order, and stops at the first error.
+    ///
+    /// It can be used to validate or flush all the pooled objects, aborting on the first failure.
+    /// The error returned by the closure is returned, and the next objects are not processed.
+    ///
+    /// The objects currently borrowed elsewhere are skipped instead of panicking.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let mut nb_processed = 0;
+    /// let result = pool.try_for_each(|monster| {
+    ///     nb_processed += 1;
+    ///     monster.level_up();
+    ///     if monster.level > 10 {
+    ///         return Err("This monster is too strong !");
+    ///     }
+    ///     Ok(())
+    /// });
+    /// assert_eq!(result, Err("This monster is too strong !"));
+    /// assert_eq!(nb_processed, 1);
+    /// ```
+    pub fn try_for_each<E, F>(&self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&mut T) -> Result<(), E>,
+    {
+        debug!("Processing all the objects of the RcPool until an error occurs.");
+        for (index, handle) in self.handles.iter().enumerate() {
+            if handle.is_detached() {
+                trace!("The object at index {} has been detached, skipping it.", index);
+                continue;
+            }
+            let mut object = match handle.try_borrow_mut() {
+                Ok(object) => object,
+                Err(_) => {
+                    warn!("The object at index {} is currently borrowed, skipping it.", index);
+                    continue;
+                }
+            };
+            trace!("Processing the object at index {}.", index);
+            if let Err(error) = f(&mut object) {
+                trace!("The processing of the object at index {} failed, stopping.", index);
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Calls the closure on each used object of the pool, with mutable access, without cloning their `RcHandle<T>`.
+    ///
+    /// It helps to update all the active objects at once, like applying the gravity to all the active particles.
+    /// Non-used and detached objects are not processed.
+    ///
+    /// The objects currently borrowed elsewhere are skipped instead of panicking. The number of skipped objects
+    /// is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let first_monster = pool.create().unwrap();
+    /// let second_monster = pool.create().unwrap();
+    ///
+    /// let borrowed = second_monster.borrow();
+    /// let nb_skipped = pool.each_used_mut(|monster| monster.level_up());
+    /// assert_eq!(nb_skipped, 1);
+    /// drop(borrowed);
+    ///
+    /// assert_eq!(first_monster.borrow().level, 11);
+    /// assert_eq!(second_monster.borrow().level, 10);
+    /// ```
+    pub fn each_used_mut<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&mut T),
+    {
+        debug!("Processing all the used objects of the RcPool.");
+        let mut nb_skipped = 0;
+        for (index, handle) in self.handles.iter().enumerate() {
+            if Self::is_free(handle) || handle.is_detached() {
+                continue;
+            }
+            match handle.try_borrow_mut() {
+                Ok(mut object) => {
+                    trace!("Processing the used object at index {}.", index);
+                    f(&mut object);
+                }
+                Err(_) => {
+                    warn!("The used object at index {} is currently borrowed, skipping it.", index);
+                    nb_skipped += 1;
+                }
+            }
+        }
+        nb_skipped
+    }
+
+    /// Replaces the object at the given index with the given value, if it is not used, and returns the old object.
+    ///
+    /// It helps to update a pooled object in place, like a configuration object. The new value is not reinitialized.
+    ///
+    /// # Errors
+    /// A `PoolError` is returned, and the value is dropped, if the index is out of bounds, or if the object is used
+    /// or currently borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// # use std::error::Error;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let old_monster = pool.replace_free(1, Monster { hp: 50, level: 5 })?;
+    /// assert_eq!(old_monster.level, 10);
+    /// assert_eq!(pool.pool_slice()[1].borrow().level, 5);
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// assert!(pool.replace_free(0, Monster::default()).is_err());
+    /// assert!(pool.replace_free(2, Monster::default()).is_err());
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #   try_main().unwrap();
+    /// # }
+    /// ```
+    pub fn replace_free(&self, index: usize, value: T) -> PoolResult<T> {
+        debug!("Replacing the object at index {} of the RcPool.", index);
+        if index >= self.handles.len() {
+            error!("The index {} is out of bounds !", index);
+            return Err(PoolError::PoolError(format!(
+                "The index {} is out of bounds of the RcPool !",
+                index
+            )));
+        }
+
+        if !self.is_unused(index) {
+            error!("The object at index {} is used !", index);
+            return Err(PoolError::PoolError(format!(
+                "The object at index {} is used !",
+                index
+            )));
+        }
+
+        match self.handles[index].try_borrow_mut() {
+            Ok(mut object) => {
+                trace!("Replacing the object at index {}.", index);
+                Ok(mem::replace(&mut *object, value))
+            }
+            Err(_) => {
+                error!("The object at index {} is currently borrowed !", index);
+                Err(PoolError::PoolError(format!(
+                    "The object at index {} is currently borrowed !",
+                    index
+                )))
+            }
+        }
+    }
+
+    /// Return the number of non-used `RcHandle<T>` in the pool.
+    ///
+    /// The number is kept up to date by the acquisitions and the releases: this function runs in `O(1)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.nb_unused(), 2);
+    /// let a_monster = pool.create();
+    /// assert!(a_monster.is_some());
+    /// assert_eq!(pool.nb_unused(), 1);
+    /// ```
+    pub fn nb_unused(&self) -> usize {
+        debug!("Getting the number of unused RcHandles in the RcPool.");
+        self.counters.free.get()
+    }
+
+    /// Returns `true` if the pool has at least one non-used `RcHandle<T>`.
+    ///
+    /// Like `nb_unused`, this function runs in `O(1)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    /// assert!(pool.has_free());
+    /// let a_monster = pool.create();
+    /// assert!(!pool.has_free());
+    /// ```
+    pub fn has_free(&self) -> bool {
+        debug!("Checking if the RcPool has an unused RcHandle.");
+        self.nb_unused() > 0
+    }
+
+    /// Returns `true` if the pool has at least `n` non-used `RcHandle<T>`.
+    ///
+    /// Like `nb_unused`, this function runs in `O(1)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    /// let a_monster = pool.create();
+    /// assert!(pool.has_free_at_least(2));
+    /// assert!(!pool.has_free_at_least(3));
+    /// ```
+    pub fn has_free_at_least(&self, n: usize) -> bool {
+        debug!("Checking if the RcPool has at least {} unused RcHandle(s).", n);
+        self.nb_unused() >= n
+    }
+
+    /// Returns `true` if all the `RcHandle<T>` of the pool are used.
+    ///
+    /// Like `has_free`, this function runs in `O(1)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    /// assert!(!pool.is_saturated());
+    /// let a_monster = pool.create();
+    /// assert!(pool.is_saturated());
+    /// ```
+    pub fn is_saturated(&self) -> bool {
+        debug!("Checking if all the RcHandles of the RcPool are used.");
+        !self.has_free()
+    }
+
+    /// Returns `true` if none of the `RcHandle<T>` of the pool is used.
+    ///
+    /// The detached objects are not considered as used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    /// assert!(pool.is_idle());
+    /// let a_monster = pool.create();
+    /// assert!(!pool.is_idle());
+    /// ```
+    pub fn is_idle(&self) -> bool {
+        debug!("Checking if none of the RcHandles of the RcPool is used.");
+        self.nb_in_use() == 0
+    }
+
+    /// Panics if an object of the pool is still used, giving the number of used objects.
+    ///
+    /// It is a one-liner leak check for the tests, at a point where all the `RcHandle<T>` should have been dropped.
+    /// With the `backtrace` feature, the panic message also gives the call sites where the leaked `RcHandle<T>`
+    /// were acquired. The detached objects are not considered as used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an object of the pool is used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// {
+    ///     let a_monster = pool.create().unwrap();
+    /// }
+    /// pool.assert_all_released();
+    /// ```
+    pub fn assert_all_released(&self) {
+        debug!("Asserting that all the RcHandles of the RcPool are released.");
+        let used: Vec<usize> = (0..self.handles.len())
+            .filter(|&index| !self.is_unused(index) && !self.handles[index].is_detached())
+            .collect();
+        if used.is_empty() {
+            return;
+        }
+
+        #[allow(unused_mut)]
+        let mut message = format!(
+            "{} object(s) of the {} are still used, at the indices {:?} !",
+            used.len(),
+            self.id,
+            used
+        );
+        #[cfg(feature = "backtrace")]
+        for &index in &used {
+            if let Some(backtrace) = self.handles[index].acquisition_backtrace() {
+                message.push_str(&format!("\nThe object at index {} was acquired at:\n{}", index, backtrace));
+            }
+        }
+        error!("{}", message);
+        panic!("{}", message);
+    }
+
+    /// Returns the total number of `RcHandle<T>` the vector can hold without reallocating.
+    ///
+    /// This is the sum of `len()` and `reserved()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.capacity(), 2);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        debug!("Getting the number of RcHandle the RcPool can hold without reallocating.");
+        self.handles.capacity()
+    }
+
+    /// Returns the number of `RcHandle<T>` contained in the pool, used or not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        debug!("Getting the number of RcHandle contained in the RcPool.");
+        self.handles.len()
+    }
+
+    /// Returns `true` if the pool contains no `RcHandle<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(0, || {
+    ///     Monster::default()
+    /// });
+    /// assert!(pool.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        debug!("Checking if the RcPool contains no RcHandle.");
+        self.handles.is_empty()
+    }
+
+    /// Returns the number of extra slots allocated by the vector of `RcHandle<T>`, which do not
+    /// contain any object yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.reserved(), 0);
+    ///
+    /// pool.grow(1, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.len(), 3);
+    /// assert_eq!(pool.reserved(), pool.capacity() - pool.len());
+    /// ```
+    pub fn reserved(&self) -> usize {
+        debug!("Getting the number of spare slots of the RcPool.");
+        self.handles.capacity() - self.handles.len()
+    }
+
+    /// Returns an estimate of the heap memory held by the pool, in bytes.
+    ///
+    /// It accounts for the allocations of the pool's vectors, and for the allocation of each object
+    /// with its reference counts. The memory owned by the objects themselves is not counted,
+    /// see `capacity_bytes_with_heap`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// use std::cell::RefCell;
+    /// use std::mem;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(10, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// assert!(pool.capacity_bytes() > 10 * mem::size_of::<RefCell<Monster>>());
+    /// ```
+    pub fn capacity_bytes(&self) -> usize {
+        debug!("Estimating the memory footprint of the RcPool.");
+        // Each Rc allocation holds the strong and weak counts, followed by the RefCell.
+        let object_bytes = 2 * mem::size_of::<usize>() + mem::size_of::<RefCell<T>>();
+        self.handles.capacity() * mem::size_of::<RcHandle<T>>()
+            + self.stamps.capacity() * mem::size_of::<Cell<u64>>()
+            + self.acquired_at.capacity() * mem::size_of::<Cell<Option<Instant>>>()
+            + self.handles.len() * object_bytes
+    }
+
+    /// Returns an estimate of the heap memory held by the pool, in bytes, including the memory owned
+    /// by the objects as reported by `HeapSize`.
+    ///
+    /// Objects which are currently mutably borrowed are not inspected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{HeapSize, RcPool};
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// impl HeapSize for Monster {
+    ///     fn heap_size(&self) -> usize {
+    ///         64
+    ///     }
+    /// }
+    ///
+    /// let pool = RcPool::with_capacity(10, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// assert_eq!(pool.capacity_bytes_with_heap(), pool.capacity_bytes() + 10 * 64);
+    /// ```
+    pub fn capacity_bytes_with_heap(&self) -> usize
+    where
+        T: HeapSize,
+    {
+        debug!("Estimating the memory footprint of the RcPool, with the heap memory of its objects.");
+        trace!("Iterating over all the RcHandles...");
+        let heap_bytes: usize = self.handles
+            .iter()
+            .filter_map(|handle| handle.try_borrow().ok().map(|object| object.heap_size()))
+            .sum();
+        self.capacity_bytes() + heap_bytes
+    }
+
+    /// Adds `additional` objects to the pool, created with the given closure.
+    ///
+    /// The vector of `RcHandle<T>` may reallocate, and reserve more slots than needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// pool.grow(3, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.len(), 5);
+    /// assert_eq!(pool.nb_unused(), 5);
+    /// ```
+    pub fn grow<F>(&mut self, additional: usize, mut op: F)
+    where
+        F: FnMut() -> T,
+    {
+        debug!("Growing the RcPool by {} RcHandle(s).", additional);
+        self.handles.reserve(additional);
+
+        for _ in 0..additional {
+            let index = self.handles.len();
+            self.handles.push(RcHandle::pooled(
+                op(),
+                self.recycle,
+                self.id,
+                index,
+                self.recycle_enabled.clone(),
+                self.observer.clone(),
+                self.counters.clone(),
+            ));
+            self.counters.free.set(self.counters.free.get() + 1);
+            self.stamps.push(Cell::new(0));
+            self.acquired_at.push(Cell::new(None));
+        }
+        if let Some(observer) = self.observer.get() {
+            observer.on_grow(additional);
+        }
+    }
+
+    /// Ensures that at least `additional` objects of the pool are not used, creating the missing ones with the given closure.
+    ///
+    /// Unlike `grow`, which always adds `additional` objects, only the objects needed to reach `additional`
+    /// non-used objects are created. Nothing is created if the pool already has enough non-used objects.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    /// let a_monster = pool.create().unwrap();
+    ///
+    /// // 2 monsters are not used, only 3 are created.
+    /// pool.reserve(5, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.len(), 6);
+    /// assert_eq!(pool.nb_unused(), 5);
+    ///
+    /// pool.reserve(5, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.len(), 6);
+    /// ```
+    pub fn reserve<F>(&mut self, additional: usize, op: F)
+    where
+        F: FnMut() -> T,
+    {
+        debug!("Reserving {} unused RcHandle(s) in the RcPool.", additional);
+        let nb_unused = self.nb_unused();
+        if nb_unused >= additional {
+            trace!("The RcPool already has {} unused RcHandle(s).", nb_unused);
+            return;
+        }
+
+        self.grow(additional - nb_unused, op);
+    }
+
+    /// Grows or shrinks the pool toward `peak_in_use * (1 + headroom)` objects, creating the missing ones with the
+    /// given closure.
+    ///
+    /// It can be called periodically to right-size the pool from the observed usage. The peak is tracked since the
+    /// creation of the pool, or the last call to `reset_stats`. Shrinking only removes non-used objects, starting
+    /// from the end of the pool, so the pool may stay bigger than the target if too many objects are used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity(10, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// {
+    ///     let monsters = pool.create_many(4).unwrap();
+    /// }
+    ///
+    /// pool.autotune(0.5, || {
+    ///     Monster::default()
+    /// });
+    /// assert_eq!(pool.len(), 6);
+    /// ```
+    pub fn autotune<F>(&mut self, headroom: f32, op: F)
+    where
+        F: FnMut() -> T,
+    {
+        debug!("Auto-tuning the size of the RcPool with a headroom of {}.", headroom);
+        let target = (self.peak_in_use() as f32 * (1.0 + headroom)).ceil() as usize;
+        let len = self.handles.len();
+        if len < target {
+            trace!("Growing the RcPool from {} to {} RcHandle(s).", len, target);
+            self.grow(target - len, op);
+            return;
+        }
+
+        trace!("Shrinking the RcPool from {} toward {} RcHandle(s).", len, target);
+        for index in (0..len).rev() {
+            if self.handles.len() <= target {
+                break;
+            }
+            self.swap_remove_free(index);
+        }
+    }
+
+    /// Grows or shrinks the pool to exactly `new_len` objects, like `Vec::resize`.
+    ///
+    /// When the pool grows, the new objects are created with the given closure. When it shrinks, the slots of the
+    /// detached objects are removed first, then the non-used objects, starting from the end of the pool: the slots of
+    /// the remaining objects can change, like with `swap_remove_free`.
+    ///
+    /// # Errors
+    ///
+    /// A `PoolError` is returned, and the pool is left untouched, if `new_len` is lower than the number of used objects,
+    /// plus the number of detached objects whose `RcHandle<T>` has been cloned from `pool_slice`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// pool.resize(5, Monster::default).unwrap();
+    /// assert_eq!(pool.len(), 5);
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// let another_monster = pool.create().unwrap();
+    /// pool.resize(2, Monster::default).unwrap();
+    /// assert_eq!(pool.len(), 2);
+    ///
+    /// assert!(pool.resize(1, Monster::default).is_err());
+    /// assert_eq!(pool.len(), 2);
+    /// ```
+    pub fn resize<F>(&mut self, new_len: usize, op: F) -> PoolResult<()>
+    where
+        F: FnMut() -> T,
+    {
+        debug!("Resizing the RcPool to {} RcHandle(s).", new_len);
+        let len = self.handles.len();
+        if len < new_len {
+            trace!("Growing the RcPool from {} to {} RcHandle(s).", len, new_len);
+            self.grow(new_len - len, op);
+            return Ok(());
+        }
+
+        let nb_used = self.nb_in_use();
+        if new_len < nb_used {
+            error!("The RcPool cannot be shrunk to {} RcHandle(s), {} are used !", new_len, nb_used);
+            return Err(PoolError::PoolError(format!(
+                "The RcPool cannot be shrunk to {} RcHandle(s), {} are used !",
+                new_len, nb_used
+            )));
+        }
+
+        // The slot of a detached object can be removed, unless a clone of its RcHandle is still alive.
+        let nb_referenced = self.handles
+            .iter()
+            .filter(|handle| handle.is_detached() && Rc::strong_count(handle.as_ref()) > 1)
+            .count();
+        if new_len < nb_used + nb_referenced {
+            error!(
+                "The RcPool cannot be shrunk to {} RcHandle(s), {} are used and {} detached ones are referenced !",
+                new_len, nb_used, nb_referenced
+            );
+            return Err(PoolError::PoolError(format!(
+                "The RcPool cannot be shrunk to {} RcHandle(s), {} are used and {} detached ones are referenced !",
+                new_len, nb_used, nb_referenced
+            )));
+        }
+
+        trace!("Shrinking the RcPool from {} to {} RcHandle(s).", len, new_len);
+        // The slots of the detached objects are never handed out again, they are removed first.
+        for index in (0..len).rev() {
+            if self.handles.len() <= new_len {
+                break;
+            }
+            if self.handles[index].is_detached() && Rc::strong_count(self.handles[index].as_ref()) == 1 {
+                trace!("Removing the slot of the detached object at index {}.", index);
+                self.swap_remove_slot(index);
+                self.counters.detached.set(self.counters.detached.get() - 1);
+            }
+        }
+        for index in (0..self.handles.len()).rev() {
+            if self.handles.len() <= new_len {
+                break;
+            }
+            self.swap_remove_free(index);
+        }
+        Ok(())
+    }
+
+    /// Asks the pool for an `RcHandle<T>`, growing the pool if all `RcHandle<T>` are used.
+    ///
+    /// When the pool grows, its size is doubled, like a `Vec`, and the new objects are created with the given closure.
+    /// Acquiring `n` objects from a full pool grows it only `O(log n)` times, so the amortized cost of an
+    /// acquisition stays constant. The size of the pool never exceeds the maximum set with `set_max_capacity`.
+    ///
+    /// The objects reserved for priority acquisitions are never given: the pool grows when only those are left,
+    /// and the overflow policy is ignored.
+    ///
+    /// `None` is returned if all `RcHandle<T>` are used and the pool reached its maximum size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create_or_grow(Monster::default).unwrap();
+    /// let another_monster = pool.create_or_grow(Monster::default).unwrap();
+    /// assert_eq!(pool.len(), 2);
+    ///
+    /// // The pool is full, its size is doubled.
+    /// let a_third_monster = pool.create_or_grow(Monster::default).unwrap();
+    /// assert_eq!(pool.len(), 4);
+    /// ```
+    pub fn create_or_grow<F>(&mut self, op: F) -> Option<RcHandle<T>>
+    where
+        F: FnMut() -> T,
+    {
+        debug!("The RcPool is being asked a RcHandle, growing if needed.");
+        if self.is_paused() {
+            return None;
+        }
+        if let Some(handle) = self.acquire_first_unused(false) {
+            return Some(handle);
+        }
+
+        // Only the objects reserved for priority acquisitions are left, if any: the pool must grow enough
+        // to give an object while keeping its reserve.
+        let len = self.handles.len();
+        let missing = self.priority_reserve.saturating_sub(self.counters.free.get()) + 1;
+        let mut new_len = len.saturating_mul(2).max(len.saturating_add(missing));
+        if let Some(max) = self.max_capacity {
+            new_len = new_len.min(max);
+        }
+
+        if new_len < len.saturating_add(missing) {
+            trace!("The RcPool cannot grow beyond its maximum size of {} RcHandle(s).", new_len.max(len));
+            return None;
+        }
+
+        trace!("The RcPool is full, growing from {} to {} RcHandle(s).", len, new_len);
+        self.grow(new_len - len, op);
+        self.acquire_first_unused(false)
+    }
+
+    /// Sets the maximum size the pool can reach when growing with `create_or_grow`.
+    ///
+    /// `None`, the default, means the pool can grow without limit. The current size of the pool is not changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// pool.set_max_capacity(Some(3));
+    ///
+    /// let monsters: Vec<_> = (0..3).map(|_| pool.create_or_grow(Monster::default).unwrap()).collect();
+    /// assert_eq!(pool.len(), 3);
+    /// assert!(pool.create_or_grow(Monster::default).is_none());
+    /// ```
+    pub fn set_max_capacity(&mut self, max: Option<usize>) {
+        debug!("Setting the maximum size of the RcPool to {:?}.", max);
+        self.max_capacity = max;
+    }
+
+    /// Sets what `create` does when all the objects of the pool are used.
+    ///
+    /// With `OverflowPolicy::Allocate`, `create` returns a `RcHandle<T>` to an object created outside of the pool,
+    /// so the program degrades gracefully instead of failing. This object is not counted in the pool, and is dropped
+    /// with its last `RcHandle<T>` instead of going back to the pool. The other acquisition functions are not affected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{OverflowPolicy, RcPool};
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    /// pool.set_overflow_policy(OverflowPolicy::Allocate(Monster::default));
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// let another_monster = pool.create().unwrap();
+    /// assert_eq!(pool.len(), 1);
+    /// assert!(!another_monster.is_pooled());
+    /// ```
+    pub fn set_overflow_policy(&mut self, policy: OverflowPolicy<T>) {
+        debug!("Setting the overflow policy of the RcPool.");
+        self.overflow_policy = policy;
+    }
+
+    /// Returns the maximum size the pool can reach when growing with `create_or_grow`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// assert_eq!(pool.max_capacity(), None);
+    /// pool.set_max_capacity(Some(10));
+    /// assert_eq!(pool.max_capacity(), Some(10));
+    /// ```
+    pub fn max_capacity(&self) -> Option<usize> {
+        debug!("Getting the maximum size of the RcPool.");
+        self.max_capacity
+    }
+
+    /// Removes the non-used objects matching the given predicate from the pool, and returns them.
+    ///
+    /// Used objects are never drained. The order of the remaining objects is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity_indexed(4, |index| {
+    ///     Monster {
+    ///         hp: 10,
+    ///         level: index as u32,
+    ///     }
+    /// });
+    /// let a_monster = pool.create().unwrap();
+    ///
+    /// let drained = pool.drain_filter(|monster| monster.level % 2 == 0);
+    /// assert_eq!(drained.len(), 1);
+    /// assert_eq!(drained[0].level, 2);
+    /// assert_eq!(pool.len(), 3);
+    /// ```
+    pub fn drain_filter<F>(&mut self, mut f: F) -> Vec<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        debug!("Draining the unused RcHandles matching a predicate from the RcPool.");
+        let handles = mem::take(&mut self.handles);
+        let stamps = mem::take(&mut self.stamps);
+        let acquired_at = mem::take(&mut self.acquired_at);
+        let mut drained = Vec::new();
+
+        trace!("Iterating over all the RcHandles...");
+        for ((handle, stamp), instant) in handles.into_iter().zip(stamps).zip(acquired_at) {
+            // The objects of a lazy pool may still wait for their first reinitialization.
+            if Self::is_free(&handle) && handle.reinitialize_if_needed() && f(&handle.borrow()) {
+                trace!("Draining an unused RcHandle.");
+                self.counters.free.set(self.counters.free.get() - 1);
+                match handle.try_into_inner() {
+                    Ok(object) => drained.push(object),
+                    Err(_) => unreachable!("an unused RcHandle is the only owner of its object"),
+                }
+            } else {
+                handle.set_slot_index(self.handles.len());
+                self.handles.push(handle);
+                self.stamps.push(stamp);
+                self.acquired_at.push(instant);
+            }
+        }
+
+        drained
+    }
+
+    /// Moves up to `n` non-used objects from this pool into the `other` pool, and returns the number of moved objects.
+    ///
+    /// It helps to rebalance objects across sharded pools. The moved objects keep their state, they are not reinitialized,
+    /// and are appended at the end of the `other` pool. The used objects are never moved. Only the objects whose
+    /// reinitialization is pending, like the ones of a lazy pool, are reinitialized before being moved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity(4, || {
+    ///     Monster::default()
+    /// });
+    /// let mut other_pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    /// let a_monster = pool.create().unwrap();
+    ///
+    /// // Only 3 monsters are not used.
+    /// assert_eq!(pool.drain_into(&mut other_pool, 5), 3);
+    /// assert_eq!(pool.len(), 1);
+    /// assert_eq!(other_pool.len(), 4);
+    /// ```
+    pub fn drain_into(&mut self, other: &mut RcPool<T>, n: usize) -> usize {
+        debug!("Moving up to {} unused RcHandle(s) from the RcPool to another RcPool.", n);
+        let mut remaining = n;
+        let drained = self.drain_filter(|_| {
+            if remaining == 0 {
+                return false;
+            }
+            remaining -= 1;
+            true
+        });
+
+        let moved = drained.len();
+        trace!("Moving {} object(s) to the other RcPool.", moved);
+        let mut objects = drained.into_iter();
+        other.grow(moved, || objects.next().expect("as many objects as the moved ones"));
+        moved
+    }
+
+    /// Removes the non-used object at the given index from the pool in `O(1)`, and returns it.
+    ///
+    /// The last object of the pool takes the place of the removed one: the order of the objects is not preserved.
+    ///
+    /// `None` is returned, and the pool is left untouched, if the index is out of bounds or if the object is used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity_indexed(3, |index| {
+    ///     Monster {
+    ///         hp: 10,
+    ///         level: index as u32,
+    ///     }
+    /// });
+    /// let a_monster = pool.create().unwrap();
+    ///
+    /// // The first monster is used.
+    /// assert!(pool.swap_remove_free(0).is_none());
+    ///
+    /// let removed = pool.swap_remove_free(1).unwrap();
+    /// assert_eq!(removed.level, 1);
+    /// assert_eq!(pool.len(), 2);
+    ///
+    /// // The last monster took the place of the removed one.
+    /// assert_eq!(pool.pool_slice()[1].borrow().level, 2);
+    /// ```
+    pub fn swap_remove_free(&mut self, index: usize) -> Option<T> {
+        debug!("Swap-removing the object at index {} from the RcPool.", index);
+        if index >= self.handles.len() || !self.is_unused(index) {
+            trace!("The object at index {} is used, or does not exist.", index);
+            return None;
+        }
+
+        let removed = self.swap_remove_slot(index);
+        removed.reinitialize_if_needed();
+        self.counters.free.set(self.counters.free.get() - 1);
+        match removed.try_into_inner() {
+            Ok(object) => Some(object),
+            Err(_) => unreachable!("an unused RcHandle is the only owner of its object"),
+        }
+    }
+
+    /// Converts this `RcPool<T>` into an `ArcPool<T>` holding the same objects, in the same order.
+    ///
+    /// An `Rc` cannot be shared between threads: the conversion requires that no `RcHandle<T>` is outstanding.
+    /// If some objects are used, the `RcPool<T>` is given back.
+    ///
+    /// The `ArcPool<T>` has a new `PoolId`. The objects are recycled the same way as in the `RcPool<T>`, and the objects
+    /// whose reinitialization is pending, like the ones of a lazy pool, are reinitialized before the conversion.
+    /// The slots of the detached objects are not converted: the placeholders left in them are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// pool.pool_slice()[1].borrow_mut().level_up();
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// let pool = pool.into_arc_pool().unwrap_err();
+    /// drop(a_monster);
+    ///
+    /// let arc_pool = pool.into_arc_pool().unwrap();
+    /// assert_eq!(arc_pool.pool_slice()[1].read().unwrap().level, 11);
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn into_arc_pool(self) -> Result<ArcPool<T>, RcPool<T>> {
+        debug!("Converting the RcPool into an ArcPool.");
+        let outstanding = self.handles.iter().any(|handle| Rc::strong_count(handle.as_ref()) > 1);
+        if outstanding {
+            trace!("Some RcHandles of the RcPool are outstanding.");
+            return Err(self);
+        }
+
+        let recycle = self.recycle;
+        let objects = self.handles
+            .into_iter()
+            .filter(|handle| !handle.is_detached())
+            .map(|handle| {
+                // The objects of a lazy pool may still wait for their first reinitialization.
+                handle.reinitialize_if_needed();
+                match handle.try_into_inner() {
+                    Ok(object) => object,
+                    Err(_) => unreachable!("an unused RcHandle is the only owner of its object"),
+                }
+            })
+            .collect();
+
+        Ok(ArcPool::from_objects(objects, recycle))
+    }
+
+    /// Returns the instant at which the object at the given index has been acquired, if it is used.
+    ///
+    /// `None` is returned if the index is out of bounds, or if the object is not used. It helps to find
+    /// the objects which are held for too long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// use std::time::Instant;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// assert!(pool.acquired_at(0).unwrap() <= Instant::now());
+    /// assert!(pool.acquired_at(1).is_none());
+    /// ```
+    pub fn acquired_at(&self, index: usize) -> Option<Instant> {
+        debug!("Getting the acquisition instant of the object at index {} of the RcPool.", index);
+        if index >= self.handles.len() || self.is_unused(index) {
+            trace!("The object at index {} is not used.", index);
+            return None;
+        }
+        self.acquired_at[index].get()
+    }
+
+    /// Logs, as warnings, the backtraces of the acquisitions of all the objects still in use, and returns them
+    /// along with the index of their slot.
+    ///
+    /// With the `backtrace` feature, each acquisition records a backtrace of its call site. It pinpoints where the leaked
+    /// `RcHandle<T>` were acquired, when a test expects all the objects to be back in the pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let leaked_monster = pool.create().unwrap();
+    /// let outstanding = pool.dump_outstanding();
+    /// assert_eq!(outstanding.len(), 1);
+    /// assert_eq!(outstanding[0].0, 0);
+    ///
+    /// drop(leaked_monster);
+    /// assert!(pool.dump_outstanding().is_empty());
+    /// ```
+    #[cfg(feature = "backtrace")]
+    pub fn dump_outstanding(&self) -> Vec<(usize, String)> {
+        debug!("Dumping the acquisition backtraces of the used objects of the RcPool.");
+        let outstanding: Vec<_> = (0..self.handles.len())
+            .filter(|&index| !self.is_unused(index))
+            .filter_map(|index| {
+                self.handles[index]
+                    .acquisition_backtrace()
+                    .map(|backtrace| (index, backtrace))
+            })
+            .collect();
+
+        for &(index, ref backtrace) in &outstanding {
+            warn!("The object at index {} of the {} is still in use, it was acquired at:\n{}", index, self.id, backtrace);
+        }
+        outstanding
+    }
+
+    /// Compares two `RcHandle<T>` by the index of their slot in the pool, not by the value of their objects.
+    ///
+    /// It gives a deterministic order to a set of used `RcHandle<T>`, whatever the order they were acquired in.
+    /// The `RcHandle<T>` which do not belong to a slot of this pool, like the ones given by another pool or
+    /// allocated on overflow, are ordered after the others.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let first = pool.create().unwrap();
+    /// let second = pool.create().unwrap();
+    /// let third = pool.create().unwrap();
+    ///
+    /// let mut monsters = vec![third, first, second];
+    /// monsters.sort_by(|a, b| pool.compare_handles(a, b));
+    /// assert!(monsters[0].ptr_eq(&pool.pool_slice()[0]));
+    /// assert!(monsters[2].ptr_eq(&pool.pool_slice()[2]));
+    /// ```
+    pub fn compare_handles(&self, a: &RcHandle<T>, b: &RcHandle<T>) -> Ordering {
+        debug!("Comparing two RcHandle by their slot index in the RcPool.");
+        let position = |handle: &RcHandle<T>| {
+            if handle.pool_id() == Some(self.id) {
+                handle.slot_index()
+            } else {
+                None
+            }
+        };
+
+        match (position(a), position(b)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    /// Returns the highest number of `RcHandle<T>` used at the same time, since the creation of the pool
+    /// or the last call to `reset_stats`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// {
+    ///     let a_monster = pool.create();
+    ///     let another_monster = pool.create();
+    /// }
+    ///
+    /// let a_monster = pool.create();
+    /// assert_eq!(pool.peak_in_use(), 2);
+    /// ```
+    pub fn peak_in_use(&self) -> usize {
+        debug!("Getting the peak number of used RcHandles in the RcPool.");
+        self.peak_in_use.get()
+    }
+
+    /// Returns the number of `RcHandle<T>` given by the pool, since the creation of the pool
+    /// or the last call to `reset_stats`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// {
+    ///     let a_monster = pool.create();
+    ///     let another_monster = pool.create();
+    /// }
+    ///
+    /// let a_monster = pool.create();
+    /// assert_eq!(pool.total_acquisitions(), 3);
+    /// ```
+    pub fn total_acquisitions(&self) -> usize {
+        debug!("Getting the total number of acquisitions of the RcPool.");
+        self.total_acquisitions.get()
+    }
+
+    /// Returns a snapshot of the metrics of the pool.
+    ///
+    /// A `RcPool<T>` tracks its peak usage and its acquisitions. Its objects cannot be poisoned.
+    ///
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// {
+    ///     let a_monster = pool.create();
+    ///     let another_monster = pool.create();
+    /// }
+    ///
+    /// let a_monster = pool.create();
+    /// let stats = pool.stats();
+    /// assert_eq!(stats.in_use, 1);
+    /// assert_eq!(stats.peak_in_use, Some(2));
+    /// assert_eq!(stats.total_acquisitions, Some(3));
+    /// ```
+    pub fn stats(&self) -> PoolStats {
+        debug!("Getting a snapshot of the metrics of the RcPool.");
+        let unused = self.nb_unused();
+        PoolStats {
+            len: self.len(),
+            in_use: self.nb_in_use(),
+            unused,
+            peak_in_use: Some(self.peak_in_use()),
+            total_acquisitions: Some(self.total_acquisitions()),
+            poisoned: 0,
+        }
+    }
+
+    /// Resets the statistics of the pool, `peak_in_use` and `total_acquisitions`, to zero.
+    ///
+    /// The objects of the pool are not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// let pool = RcPool::with_capacity(3, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create();
+    /// assert_eq!(pool.total_acquisitions(), 1);
+    ///
+    /// pool.reset_stats();
+    /// assert_eq!(pool.total_acquisitions(), 0);
+    /// assert_eq!(pool.peak_in_use(), 0);
+    /// assert_eq!(pool.nb_unused(), 2);
+    /// ```
+    pub fn reset_stats(&self) {
+        debug!("Resetting the statistics of the RcPool.");
+        self.peak_in_use.set(0);
+        self.total_acquisitions.set(0);
+    }
+
+    /// Creates a `SerializableHandle`, referencing the slot of the given `RcHandle<T>` in this pool.
+    ///
+    /// Returns `None` if the `RcHandle<T>` does not come from this pool.
+    ///
+    /// This function is only available with the `serde` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// let monster = pool.create().unwrap();
+    ///
+    /// let serializable = pool.serializable_handle(&monster).unwrap();
+    /// assert_eq!(serializable.slot(), 0);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn serializable_handle(&self, handle: &RcHandle<T>) -> Option<SerializableHandle> {
+        debug!("Creating a SerializableHandle from a RcHandle of the RcPool.");
+        trace!("Iterating over all the RcHandles...");
+        self.handles
+            .iter()
+            .position(|obj| obj.ptr_eq(handle))
+            .map(|index| SerializableHandle::new(self.id, index, self.stamps[index].get()))
+    }
+
+    /// Turns a `SerializableHandle` back into a `RcHandle<T>` of this pool.
+    ///
+    /// Returns `None` if the `SerializableHandle` references another pool, if the slot does not exist,
+    /// or if its object has gone back to the pool or has been acquired again since the creation of the
+    /// `SerializableHandle`.
+    ///
+    /// A deserialized pool resolves the `SerializableHandle`s created from the pool it has been serialized from.
+    /// The objects used when the pool was serialized are acquired again by the first `SerializableHandle` resolving
+    /// them, like with `create`: `None` is returned if the pool is paused, or if only the objects reserved for
+    /// priority acquisitions are not used.
+    ///
+    /// This function is only available with the `serde` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    /// let monster = pool.create().unwrap();
+    /// let serializable = pool.serializable_handle(&monster).unwrap();
+    ///
+    /// let same_monster = pool.resolve(&serializable).unwrap();
+    /// assert!(same_monster.ptr_eq(&monster));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn resolve(&self, serialized: &SerializableHandle) -> Option<RcHandle<T>> {
+        debug!("Resolving a SerializableHandle in the RcPool.");
+        if serialized.pool_id() != self.id && Some(serialized.pool_id()) != self.origin {
+            trace!("The SerializableHandle references the pool {}.", serialized.pool_id());
+            return None;
+        }
+
+        let index = serialized.slot();
+        match self.stamps.get(index) {
+            Some(stamp) if stamp.get() == serialized.generation() => {
+                trace!("The slot {} has the expected generation.", index);
+            }
+            _ => {
+                trace!("The slot {} does not exist, or has been acquired again.", index);
+                return None;
+            }
+        }
+
+        let handle = &self.handles[index];
+        if handle.is_detached() {
+            trace!("The object of the slot {} has been detached.", index);
+            return None;
+        }
+        if !self.is_unused(index) {
+            return Some(handle.clone());
+        }
+        if !handle.is_restored() {
+            trace!("The object of the slot {} has gone back to the pool.", index);
+            return None;
+        }
+
+        // The object was used when the pool was serialized: it is acquired again, keeping its generation
+        // so the other SerializableHandles of the object resolve to it.
+        if self.is_paused() || self.nb_available() == 0 {
+            return None;
+        }
+        trace!("Acquiring the object of the slot {}, used when the pool was serialized.", index);
+        let handle = self.acquire(index);
+        self.stamps[index].set(serialized.generation());
+        self.update_peak_in_use();
+        Some(handle)
+    }
+
+    fn is_unused(&self, index: usize) -> bool {
+        Self::is_free(&self.handles[index])
+    }
+
+    // A detached slot is never free again.
+    fn is_free(handle: &RcHandle<T>) -> bool {
+        Rc::strong_count(handle.as_ref()) == 1 && !handle.is_detached()
+    }
+
+    // Returns the indices of the first n unused RcHandles, or None if there are fewer beyond the priority reserve.
+    // Nothing is acquired, so a failed request has no effect on the pool.
+    fn unused_indices(&self, n: usize) -> Option<Vec<usize>> {
+        if n > self.nb_available() {
+            trace!("The RcPool has fewer than {} unused RcHandle(s) beyond its priority reserve.", n);
+            return None;
+        }
+
+        trace!("Iterating over all the RcHandles...");
+        let indices: Vec<usize> = (0..self.handles.len())
+            .filter(|&index| self.is_unused(index))
+            .take(n)
+            .collect();
+
+        if indices.len() < n {
+            trace!("The RcPool does not have {} unused RcHandle(s).", n);
+            return None;
+        }
+        Some(indices)
+    }
+
+    fn first_unused(&self) -> Option<usize> {
+        trace!("Iterating over all the RcHandles...");
+        (0..self.handles.len()).find(|&index| self.is_unused(index))
+    }
+
+    // Returns the first unused RcHandle, if more unused RcHandles than the priority reserve remain.
+    fn first_unused_beyond_reserve(&self) -> Option<usize> {
+        if self.nb_available() == 0 {
+            return None;
+        }
+        self.first_unused()
+    }
+
+    // Removes a slot, moving the last one in its place.
+    fn swap_remove_slot(&mut self, index: usize) -> RcHandle<T> {
+        self.stamps.swap_remove(index);
+        self.acquired_at.swap_remove(index);
+        let removed = self.handles.swap_remove(index);
+        if let Some(moved) = self.handles.get(index) {
+            moved.set_slot_index(index);
+        }
+        removed
+    }
+
+    // The detached objects are neither used nor unused.
+    fn nb_in_use(&self) -> usize {
+        self.handles.len() - self.counters.free.get() - self.counters.detached.get()
+    }
+
+    // Returns the number of unused RcHandles the acquisitions without priority can take.
+    fn nb_available(&self) -> usize {
+        let available = self.counters.free.get().saturating_sub(self.priority_reserve);
+        if available == 0 && self.priority_reserve > 0 {
+            trace!("Only the {} RcHandle(s) reserved for priority acquisitions remain.", self.priority_reserve);
+        }
+        available
+    }
+
+    fn acquire(&self, index: usize) -> RcHandle<T> {
+        trace!("Giving the RcHandle at index {}.", index);
+        self.handles[index].reinitialize_if_needed();
+        #[cfg(feature = "serde")]
+        self.handles[index].set_restored(false);
+        let stamp = self.next_stamp.get();
+        self.next_stamp.set(stamp + 1);
+        self.stamps[index].set(stamp);
+        self.acquired_at[index].set(Some(Instant::now()));
+        #[cfg(feature = "backtrace")]
+        self.handles[index].record_acquisition();
+        self.total_acquisitions.set(self.total_acquisitions.get() + 1);
+        if let Some(observer) = self.observer.get() {
+            observer.on_create(index);
+        }
+        self.handles[index].clone()
+    }
+
+    fn notify_exhausted(&self) {
+        if let Some(observer) = self.observer.get() {
+            observer.on_exhausted();
+        }
+    }
+
+    fn force_recycle(&self, candidates: Vec<usize>) -> Option<RcHandle<T>> {
+        if self.is_paused() {
+            return None;
+        }
+        trace!("Trying to recycle by force one of {} RcHandle(s).", candidates.len());
+        for index in candidates {
+            if self.handles[index].is_detached() {
+                trace!("The object at index {} has been detached, skipping it.", index);
+                continue;
+            }
+            // The non-used objects left after a failed `create` are reserved for priority acquisitions.
+            if self.is_unused(index) {
+                trace!("The object at index {} is reserved for priority acquisitions, skipping it.", index);
+                continue;
+            }
+            if self.handles[index].try_borrow_mut().is_err() {
+                trace!("The object at index {} is currently borrowed.", index);
+                continue;
+            }
+            // The eviction callback is called before borrowing the object, so it can read it.
+            self.evict(index);
+            match self.handles[index].try_borrow_mut() {
+                Ok(mut object) => {
+                    trace!("Reinitializing by force the object at index {}.", index);
+                    (self.recycle)(&mut object);
+                }
+                Err(_) => {
+                    trace!("The object at index {} has been borrowed by the eviction callback.", index);
+                    continue;
+                }
+            }
+            return Some(self.acquire(index));
+        }
+
+        trace!("All the objects of the RcPool are currently borrowed.");
+        None
+    }
+
+    fn evict(&self, index: usize) {
+        // The callback is cloned, so it can replace itself with on_evict.
+        let callback = self.on_evict.borrow().clone();
+        if let Some(EvictionCallback(callback)) = callback {
+            trace!("Calling the eviction callback for the object at index {}.", index);
+            callback(index);
+        }
+    }
+
+    fn update_peak_in_use(&self) {
+        trace!("Updating the peak number of used RcHandles in the RcPool.");
+        let in_use = self.nb_in_use();
+        if in_use > self.peak_in_use.get() {
+            self.peak_in_use.set(in_use);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize)]
+struct RcPoolRef<'a, T: 'a> {
+    // Only used to resolve the SerializableHandles, a deserialized pool gets a new PoolId.
+    origin: PoolId,
+    objects: Vec<&'a RefCell<T>>,
+    generations: Vec<u64>,
+    in_use: Vec<bool>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RcPoolData<T> {
+    origin: PoolId,
+    objects: Vec<T>,
+    generations: Vec<u64>,
+    #[serde(default)]
+    in_use: Vec<bool>,
+}
+
+// The objects are dumped only with the alternate formatter, `{:#?}`, to keep the logs readable.
+impl<T: Recyclable + fmt::Debug> fmt::Debug for RcPool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let in_use = self.nb_in_use();
+        let alternate = f.alternate();
+        let mut debug = f.debug_struct("RcPool");
+        debug
+            .field("len", &self.handles.len())
+            .field("in_use", &in_use)
+            .field("capacity", &self.handles.capacity());
+        if alternate {
+            debug.field("id", &self.id).field("objects", &self.handles);
+        }
+        debug.finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Recyclable + Serialize> Serialize for RcPool<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        debug!("Serializing the RcPool.");
+        RcPoolRef {
+            origin: self.origin.unwrap_or(self.id),
+            objects: self.handles.iter().map(|obj| &*obj.0).collect(),
+            generations: self.stamps.iter().map(Cell::get).collect(),
+            in_use: self.handles.iter().map(|handle| !Self::is_free(handle) && !handle.is_detached()).collect(),
+        }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Recyclable + Deserialize<'de>> Deserialize<'de> for RcPool<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        debug!("Deserializing a RcPool.");
+        let data = RcPoolData::deserialize(deserializer)?;
+        if data.objects.len() != data.generations.len() {
+            error!("The RcPool has {} objects but {} generations !", data.objects.len(), data.generations.len());
+            return Err(D::Error::custom("the number of objects and generations of the RcPool differ"));
+        }
+
+        let next_stamp = data.generations.iter().max().map_or(0, |max| max + 1);
+        let mut objects = data.objects.into_iter();
+        let mut pool = RcPool::with_capacity(0, || unreachable!());
+        // The deserialized pool is a new pool, but its SerializableHandles can still be resolved.
+        pool.origin = Some(data.origin);
+        pool.grow(data.generations.len(), || objects.next().expect("as many objects as generations"));
+        pool.stamps = data.generations.into_iter().map(Cell::new).collect();
+        pool.next_stamp.set(next_stamp);
+        for (handle, _) in pool.handles.iter().zip(data.in_use).filter(|&(_, in_use)| in_use) {
+            handle.set_restored(true);
+        }
+        Ok(pool)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::redundant_closure)]
+mod refcounted_objectpool_tests {
+    use super::*;
+    use acquisition_policy::{FirstFree, Lru, MinOrd, Mru};
+    use std::rc::Rc;
+    use pool_object::Recyclable;
+
+    #[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Clone)]
+    pub struct Monster {
+        name: String,
+        level: u8,
+        hp: u32,
+    }
+
+    impl Default for Monster {
+        fn default() -> Self {
+            Monster {
+                name: String::from("default name"),
+                level: 10,
+                hp: 10,
+            }
+        }
+    }
+
+    impl Monster {
+        pub fn level_up(&mut self) {
+            self.level += 1;
+        }
+
+        pub fn level(&self) -> u8 {
+            self.level
+        }
+
+        pub fn hp(&self) -> u32 {
+            self.hp
+        }
+    }
+
+    impl Recyclable for Monster {
+        fn reinitialize(&mut self) {
+            self.level = 1;
+            self.hp = 1;
+        }
+    }
+
+    impl HeapSize for Monster {
+        fn heap_size(&self) -> usize {
+            self.name.capacity()
+        }
+    }
+
+    #[test]
+    fn test_len() {
+        let simple_pool = RcPool::with_capacity(26, || Monster::default());
+        assert_eq!(simple_pool.capacity(), 26);
+    }
+
+    #[test]
+    fn test_is_used_at_initialization() {
         let monster_pool = RcPool::with_capacity(14, || Monster::default());
         for monster in monster_pool.pool_slice().iter() {
             assert_eq!(Rc::strong_count(monster.as_ref()), 1);
@@ -481,72 +5613,1915 @@ mod refcounted_objectpool_tests {
     }
 
     #[test]
-    fn test_drop_wrapper_around_smart_pointer() {
-        let monster_pool = RcPool::with_capacity(10, || Monster::default());
+    fn test_drop_wrapper_around_smart_pointer() {
+        let monster_pool = RcPool::with_capacity(10, || Monster::default());
+        let monster = monster_pool.create().unwrap();
+        assert_eq!(Rc::strong_count(monster.as_ref()), 2);
+        assert_eq!(monster_pool.nb_unused(), 9);
+        {
+            let monster2 = monster_pool.create().unwrap();
+            assert_eq!(monster2.borrow_mut().level(), 10);
+            assert_eq!(monster2.borrow_mut().hp(), 10);
+            assert_eq!(Rc::strong_count(monster2.as_ref()), 2);
+            assert_eq!(monster_pool.nb_unused(), 8);
+
+            //monster2 will be dropped here, we must check :
+            // - nb_unused() returns 9. It will mean that our drop implementation for the wrapper
+            //around the Rc<RefCell<T>> works.
+
+            // - every strong count should be 1 and each object should have in_use to false.
+            // except for monster.
+        }
+        assert_eq!(monster_pool.nb_unused(), 9);
+        let nb_monster_with_1_ref = monster_pool
+            .pool_slice()
+            .iter()
+            .filter(|obj| Rc::strong_count(obj.as_ref()) == 1)
+            .count();
+
+        assert_eq!(nb_monster_with_1_ref, 9);
+
+        let nb_monster_with_1_hp = monster_pool
+            .pool_slice()
+            .iter()
+            .filter(|obj| obj.borrow_mut().hp() == 1)
+            .count();
+
+        assert_eq!(nb_monster_with_1_hp, 1);
+    }
+
+    #[test]
+    fn test_create_no_more_objects() {
+        let monster_pool = RcPool::with_capacity(3, || Monster::default());
+        let _monster = monster_pool.create().unwrap();
+        let _monster2 = monster_pool.create().unwrap();
+        let _monster3 = monster_pool.create().unwrap();
+
+        assert_eq!(monster_pool.create(), None);
+    }
+
+    #[test]
+    fn test_modify_inner_value() {
+        let monster_pool = RcPool::with_capacity(3, || Monster::default());
+        let monster = monster_pool.create().unwrap();
+        monster.borrow_mut().level_up();
+        assert_eq!(monster.borrow_mut().level(), 11);
+        let nb_monster_lvl_11 = monster_pool
+            .pool_slice()
+            .iter()
+            .filter(|obj| (**obj).borrow_mut().level() > 10)
+            .count();
+
+        assert_eq!(nb_monster_lvl_11, 1);
+    }
+
+    #[test]
+    fn test_create_strict() {
+        let monster_pool = RcPool::with_capacity(1, || Monster::default());
+        let _monster = monster_pool.create_strict().unwrap();
+        assert!(monster_pool.create_strict().is_err());
+    }
+
+    #[test]
+    fn test_handle_ptr_eq() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        let same_monster = monster.clone();
+        let other_monster = monster_pool.create().unwrap();
+
+        assert!(monster.ptr_eq(&same_monster));
+        assert!(same_monster.ptr_eq(&monster));
+        assert!(!monster.ptr_eq(&other_monster));
+    }
+
+    #[test]
+    fn test_handle_comparison_while_borrowed() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        let same_monster = monster.clone();
+        let other_monster = monster_pool.create().unwrap();
+
+        let _borrowed = monster.borrow_mut();
+        assert!(monster == same_monster);
+        assert!(monster != other_monster);
+        assert_ne!(monster.cmp(&other_monster), ::std::cmp::Ordering::Equal);
+        assert_eq!(monster.cmp(&same_monster), ::std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_len_reserved_capacity() {
+        let mut monster_pool = RcPool::with_capacity(4, Monster::default);
+        assert_eq!(monster_pool.len(), 4);
+        assert_eq!(monster_pool.reserved(), 0);
+        assert_eq!(monster_pool.capacity(), 4);
+
+        monster_pool.grow(1, Monster::default);
+        assert_eq!(monster_pool.len(), 5);
+        assert!(monster_pool.capacity() > 4);
+        assert_eq!(monster_pool.reserved(), monster_pool.capacity() - 5);
+        assert_eq!(monster_pool.nb_unused(), 5);
+    }
+    #[test]
+    fn test_reserve_group() {
+        let monster_pool = RcPool::with_capacity(5, Monster::default);
+        let mut token = monster_pool.reserve_group(3).unwrap();
+        assert_eq!(token.remaining(), 3);
+        assert_eq!(monster_pool.nb_unused(), 2);
+        assert!(monster_pool.reserve_group(3).is_none());
+        assert_eq!(monster_pool.nb_unused(), 2);
+
+        let monster = monster_pool.take_from_reservation(&mut token).unwrap();
+        assert!(monster.ptr_eq(&monster_pool.pool_slice()[0]));
+        monster.borrow_mut().level_up();
+        assert_eq!(token.remaining(), 2);
+
+        drop(token);
+        assert_eq!(monster_pool.nb_unused(), 4);
+        assert_eq!(monster.borrow().level(), 11);
+    }
+
+    #[test]
+    fn test_take_from_empty_reservation() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
+        let mut token = monster_pool.reserve_group(1).unwrap();
+        let _monster = monster_pool.take_from_reservation(&mut token).unwrap();
+        assert!(monster_pool.take_from_reservation(&mut token).is_none());
+        assert_eq!(monster_pool.nb_unused(), 0);
+    }
+    #[test]
+    fn test_release() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster.borrow_mut().level_up();
+        assert_eq!(monster_pool.nb_unused(), 1);
+
+        assert!(monster_pool.release(monster).is_ok());
+        assert_eq!(monster_pool.nb_unused(), 2);
+        assert_eq!(monster_pool.pool_slice()[0].borrow().level(), 1);
+    }
+
+    #[test]
+    fn test_release_foreign_handle() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let other_pool = RcPool::with_capacity(2, Monster::default);
+        let monster = other_pool.create().unwrap();
+
+        // The RcHandle is given back, and can be released to its own pool.
+        let (_, monster) = monster_pool.release(monster).unwrap_err();
+        assert_eq!(monster_pool.nb_unused(), 2);
+        assert_eq!(other_pool.nb_unused(), 1);
+        assert!(other_pool.release(monster).is_ok());
+        assert_eq!(other_pool.nb_unused(), 2);
+    }
+
+    #[test]
+    fn test_release_cloned_handle() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        let monster_clone = monster.clone();
+        monster.borrow_mut().level_up();
+
+        let (_, monster) = monster_pool.release(monster).unwrap_err();
+        assert_eq!(monster_pool.nb_unused(), 1);
+        assert_eq!(monster_clone.borrow().level(), 11);
+
+        drop(monster_clone);
+        assert!(monster_pool.release(monster).is_ok());
+        assert_eq!(monster_pool.nb_unused(), 2);
+    }
+    #[test]
+    fn test_reset_stats() {
+        let monster_pool = RcPool::with_capacity(4, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        {
+            let _token = monster_pool.reserve_group(2).unwrap();
+            assert_eq!(monster_pool.peak_in_use(), 3);
+        }
+        monster.borrow_mut().level_up();
+        assert_eq!(monster_pool.total_acquisitions(), 3);
+
+        monster_pool.reset_stats();
+        assert_eq!(monster_pool.peak_in_use(), 0);
+        assert_eq!(monster_pool.total_acquisitions(), 0);
+        assert_eq!(monster_pool.nb_unused(), 3);
+        assert_eq!(monster.borrow().level(), 11);
+
+        let _monster2 = monster_pool.create().unwrap();
+        assert_eq!(monster_pool.peak_in_use(), 2);
+        assert_eq!(monster_pool.total_acquisitions(), 1);
+    }
+    #[test]
+    fn test_force_create_lru() {
+        let monster_pool = RcPool::with_capacity(3, Monster::default);
+        let monster1 = monster_pool.create().unwrap();
+        let monster2 = monster_pool.create().unwrap();
+        let monster3 = monster_pool.create().unwrap();
+        drop(monster1);
+        // The first slot is acquired again, it is now the most recently acquired.
+        let monster1 = monster_pool.create().unwrap();
+        monster2.borrow_mut().level_up();
+
+        let forced = monster_pool.force_create_lru().unwrap();
+        assert!(forced.ptr_eq(&monster2));
+        assert_eq!(monster2.borrow().level(), 1);
+        assert_eq!(monster_pool.total_acquisitions(), 5);
+
+        let forced = monster_pool.force_create_lru().unwrap();
+        assert!(forced.ptr_eq(&monster3));
+        let forced = monster_pool.force_create_lru().unwrap();
+        assert!(forced.ptr_eq(&monster1));
+    }
+
+    #[test]
+    fn test_force_create_lru_skips_borrowed() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let monster1 = monster_pool.create().unwrap();
+        let monster2 = monster_pool.create().unwrap();
+
+        let _borrowed = monster1.borrow_mut();
+        let forced = monster_pool.force_create_lru().unwrap();
+        assert!(forced.ptr_eq(&monster2));
+    }
+    #[test]
+    fn test_force_create_lru_and_mru() {
+        let monster_pool = RcPool::with_capacity(3, Monster::default);
+        let monster1 = monster_pool.create().unwrap();
+        let monster2 = monster_pool.create().unwrap();
+        let monster3 = monster_pool.create().unwrap();
+
+        // Acquisition order: monster1, monster2, monster3.
+        let forced = monster_pool.force_create_mru().unwrap();
+        assert!(forced.ptr_eq(&monster3));
+        // Acquisition order: monster1, monster2, monster3 (again).
+        let forced = monster_pool.force_create_lru().unwrap();
+        assert!(forced.ptr_eq(&monster1));
+        // Acquisition order: monster2, monster3, monster1.
+        let forced = monster_pool.force_create_mru().unwrap();
+        assert!(forced.ptr_eq(&monster1));
+        let forced = monster_pool.force_create_lru().unwrap();
+        assert!(forced.ptr_eq(&monster2));
+    }
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize_on_release() {
+        use zeroize::Zeroize;
+
+        #[derive(Default)]
+        struct Secret {
+            key: [u8; 16],
+            used: bool,
+        }
+
+        impl Zeroize for Secret {
+            fn zeroize(&mut self) {
+                self.key.zeroize();
+            }
+        }
+
+        impl Recyclable for Secret {
+            fn reinitialize(&mut self) {
+                self.used = false;
+            }
+        }
+
+        let secret_pool = RcPool::with_capacity_zeroizing(1, Secret::default);
+        {
+            let secret = secret_pool.create().unwrap();
+            secret.borrow_mut().key = [0xAB; 16];
+            secret.borrow_mut().used = true;
+        }
+
+        let secret = secret_pool.pool_slice()[0].borrow();
+        assert_eq!(secret.key, [0; 16]);
+        assert!(!secret.used);
+    }
+    #[test]
+    fn test_create_and_borrow_mut() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        {
+            let mut monster = monster_pool.create_and_borrow_mut().unwrap();
+            monster.level_up();
+            assert_eq!(monster.level(), 11);
+            assert_eq!(monster_pool.nb_unused(), 1);
+            assert!(monster.handle().try_borrow().is_err());
+        }
+        assert_eq!(monster_pool.nb_unused(), 2);
+        assert_eq!(monster_pool.pool_slice()[0].borrow().level(), 1);
+
+        let monster = monster_pool.create_and_borrow_mut().unwrap().into_handle();
+        monster.borrow_mut().level_up();
+        assert_eq!(monster.borrow().level(), 2);
+    }
+
+    #[test]
+    fn test_create_and_borrow_mut_already_borrowed() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
+        let _borrowed = monster_pool.pool_slice()[0].borrow();
+        assert!(monster_pool.create_and_borrow_mut().is_none());
+        assert_eq!(monster_pool.nb_unused(), 1);
+    }
+    #[test]
+    fn test_option_pool() {
+        let item_pool = RcPool::with_capacity(2, || Some(String::from("sword")));
+        {
+            let item = item_pool.create().unwrap();
+            assert_eq!(item.take(), Some(String::from("sword")));
+            assert_eq!(item.take(), None);
+            assert_eq!(item.fill(String::from("shield")), None);
+            assert_eq!(item.fill(String::from("bow")), Some(String::from("shield")));
+            assert_eq!(*item.borrow(), Some(String::from("bow")));
+        }
+
+        assert_eq!(*item_pool.pool_slice()[0].borrow(), None);
+        assert_eq!(*item_pool.pool_slice()[1].borrow(), Some(String::from("sword")));
+        assert_eq!(item_pool.nb_unused(), 2);
+    }
+    #[test]
+    fn test_has_free() {
+        let monster_pool = RcPool::with_capacity(3, Monster::default);
+        assert!(monster_pool.has_free());
+        assert!(monster_pool.has_free_at_least(0));
+        assert!(monster_pool.has_free_at_least(3));
+        assert!(!monster_pool.has_free_at_least(4));
+
+        let _monster = monster_pool.create().unwrap();
+        let _monster2 = monster_pool.create().unwrap();
+        assert!(monster_pool.has_free());
+        assert!(monster_pool.has_free_at_least(1));
+        assert!(!monster_pool.has_free_at_least(2));
+
+        let _monster3 = monster_pool.create().unwrap();
+        assert!(!monster_pool.has_free());
+        assert!(!monster_pool.has_free_at_least(1));
+        assert!(monster_pool.has_free_at_least(0));
+    }
+
+    #[test]
+    fn test_pool_id() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
+        let mut other_pool = RcPool::with_capacity(1, Monster::default);
+        other_pool.grow(1, Monster::default);
+        assert_ne!(monster_pool.id(), other_pool.id());
+
+        let monster = monster_pool.create().unwrap();
+        let other_monster = other_pool.create().unwrap();
+        let other_monster2 = other_pool.create().unwrap();
+        assert_eq!(monster.pool_id(), Some(monster_pool.id()));
+        assert_eq!(monster.clone().pool_id(), Some(monster_pool.id()));
+        assert_eq!(other_monster.pool_id(), Some(other_pool.id()));
+        assert_eq!(other_monster2.pool_id(), Some(other_pool.id()));
+        assert_eq!(RcHandle::new(Monster::default()).pool_id(), None);
+    }
+    #[test]
+    fn test_with_capacity_indexed() {
+        let monster_pool = RcPool::with_capacity_indexed(5, |index| Monster {
+            level: index as u8,
+            ..Default::default()
+        });
+        assert_eq!(monster_pool.len(), 5);
+        for (index, monster) in monster_pool.pool_slice().iter().enumerate() {
+            assert_eq!(monster.borrow().level() as usize, index);
+        }
+    }
+    #[test]
+    fn test_drain_filter() {
+        let mut monster_pool = RcPool::with_capacity_indexed(6, |index| Monster {
+            level: index as u8,
+            ..Default::default()
+        });
+        let monster0 = monster_pool.create().unwrap();
+        let monster1 = monster_pool.create().unwrap();
+
+        let drained = monster_pool.drain_filter(|monster| monster.level() % 2 == 0);
+        let drained_levels: Vec<u8> = drained.iter().map(Monster::level).collect();
+        assert_eq!(drained_levels, vec![2, 4]);
+
+        let remaining_levels: Vec<u8> = monster_pool
+            .pool_slice()
+            .iter()
+            .map(|monster| monster.borrow().level())
+            .collect();
+        assert_eq!(remaining_levels, vec![0, 1, 3, 5]);
+        assert!(monster0.ptr_eq(&monster_pool.pool_slice()[0]));
+        assert!(monster1.ptr_eq(&monster_pool.pool_slice()[1]));
+        assert_eq!(monster_pool.nb_unused(), 2);
+
+        drop(monster0);
+        assert_eq!(monster_pool.pool_slice()[0].borrow().level(), 1);
+    }
+    #[test]
+    fn test_create_reuses_hot_slots() {
+        let monster_pool = RcPool::with_capacity(16, Monster::default);
+        let mut used_slots = Vec::new();
+
+        for _ in 0..100 {
+            let first = monster_pool.create().unwrap();
+            let second = monster_pool.create().unwrap();
+            for handle in [&first, &second].iter() {
+                let index = monster_pool
+                    .pool_slice()
+                    .iter()
+                    .position(|slot| slot.ptr_eq(handle))
+                    .unwrap();
+                if !used_slots.contains(&index) {
+                    used_slots.push(index);
+                }
+            }
+        }
+
+        assert_eq!(used_slots, vec![0, 1]);
+    }
+    #[test]
+    fn test_slots() {
+        let monster_pool = RcPool::with_capacity(4, Monster::default);
+        let monster0 = monster_pool.create().unwrap();
+        let monster1 = monster_pool.create().unwrap();
+        drop(monster0);
+
+        let slots: Vec<(usize, bool)> = monster_pool
+            .slots()
+            .map(|(index, in_use, _)| (index, in_use))
+            .collect();
+        assert_eq!(slots, vec![(0, false), (1, true), (2, false), (3, false)]);
+
+        let (_, _, handle) = monster_pool.slots().nth(1).unwrap();
+        assert!(handle.ptr_eq(&monster1));
+    }
+    #[test]
+    fn test_into_arc_pool() {
+        let monster_pool = RcPool::with_capacity_indexed(3, |index| Monster {
+            level: index as u8,
+            ..Default::default()
+        });
+
+        let monster = monster_pool.create().unwrap();
+        let monster_pool = monster_pool.into_arc_pool().unwrap_err();
+        drop(monster);
+
+        let monster_pool = monster_pool.into_arc_pool().unwrap();
+        let levels: Vec<u8> = monster_pool
+            .pool_slice()
+            .iter()
+            .map(|monster| monster.read().unwrap().level())
+            .collect();
+        // The first monster has been recycled when its handle was dropped.
+        assert_eq!(levels, vec![1, 1, 2]);
+
+        let monster_pool = monster_pool.into_rc_pool().unwrap();
+        let levels: Vec<u8> = monster_pool
+            .pool_slice()
+            .iter()
+            .map(|monster| monster.borrow().level())
+            .collect();
+        assert_eq!(levels, vec![1, 1, 2]);
+        assert_eq!(monster_pool.nb_unused(), 3);
+    }
+    #[test]
+    fn test_create_many() {
+        let monster_pool = RcPool::with_capacity(4, Monster::default);
+        let monster = monster_pool.create().unwrap();
+
+        let monsters = monster_pool.create_many(3).unwrap();
+        assert_eq!(monsters.len(), 3);
+        assert!(monsters.iter().all(|handle| !handle.ptr_eq(&monster)));
+        assert_eq!(monster_pool.nb_unused(), 0);
+        drop(monsters);
+
+        // An oversized request acquires nothing.
+        assert!(monster_pool.create_many(4).is_none());
+        assert!(monster_pool.create_many(5).is_none());
+        assert_eq!(monster_pool.nb_unused(), 3);
+        assert_eq!(monster_pool.total_acquisitions(), 4);
+        assert_eq!(monster_pool.create_many(0).unwrap().len(), 0);
+    }
+    // Selects the slot at the given index.
+    struct Slot(usize);
+
+    impl AcquisitionPolicy<Monster> for Slot {
+        fn select(&self, _slots: &[SlotInfo<Monster>]) -> Option<usize> {
+            Some(self.0)
+        }
+    }
+
+    // Slot 0 is used. The non-used slots 1, 2 and 3 have been acquired in the order 3, 1, 2,
+    // and hold monsters of level 2, 3 and 5.
+    fn policy_pool() -> (RcPool<Monster>, RcHandle<Monster>) {
+        let monster_pool = RcPool::with_capacity(4, Monster::default);
+        for &index in [3, 1, 2].iter() {
+            drop(monster_pool.create_with_policy(&Slot(index)).unwrap());
+        }
+        let handle0 = monster_pool.create_with_policy(&Slot(0)).unwrap();
+        handle0.borrow_mut().level = 0;
+        for &(index, level) in [(1, 2), (2, 3), (3, 5)].iter() {
+            monster_pool.pool_slice()[index].borrow_mut().level = level;
+        }
+        (monster_pool, handle0)
+    }
+
+    #[test]
+    fn test_acquisition_policies() {
+        fn selected<P: AcquisitionPolicy<Monster>>(policy: &P) -> usize {
+            let (monster_pool, _handle0) = policy_pool();
+            let handle = monster_pool.create_with_policy(policy).unwrap();
+            let index = monster_pool
+                .pool_slice()
+                .iter()
+                .position(|slot| slot.ptr_eq(&handle))
+                .unwrap();
+            index
+        }
+
+        assert_eq!(selected(&FirstFree), 1);
+        assert_eq!(selected(&Lru), 3);
+        assert_eq!(selected(&Mru), 2);
+        assert_eq!(selected(&MinOrd), 1);
+    }
+
+    #[test]
+    fn test_acquisition_policy_selecting_used_slot() {
+        let (monster_pool, _handle0) = policy_pool();
+        assert!(monster_pool.create_with_policy(&Slot(0)).is_none());
+        assert!(monster_pool.create_with_policy(&Slot(4)).is_none());
+        assert_eq!(monster_pool.nb_unused(), 3);
+    }
+    #[test]
+    fn test_capacity_bytes() {
+        use std::mem;
+
+        let monster_pool = RcPool::with_capacity(10, Monster::default);
+        let bytes = monster_pool.capacity_bytes();
+        // At least the objects and the handles.
+        assert!(bytes >= 10 * (mem::size_of::<RefCell<Monster>>() + mem::size_of::<RcHandle<Monster>>()));
+        // At most a few words of overhead per slot.
+        assert!(bytes <= 10 * (mem::size_of::<RefCell<Monster>>() + 12 * mem::size_of::<usize>()));
+
+        let name_bytes = String::from("default name").capacity();
+        assert_eq!(monster_pool.capacity_bytes_with_heap(), bytes + 10 * name_bytes);
+    }
+    #[test]
+    fn test_deferred_reinitialization() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster.borrow_mut().level_up();
+
+        {
+            // The handle is dropped while its object is borrowed: the reinitialization is deferred.
+            let object = monster_pool.pool_slice()[0].borrow();
+            drop(monster);
+            assert_eq!(object.level(), 11);
+        }
+        assert_eq!(monster_pool.pool_slice()[0].borrow().level(), 11);
+
+        let monster = monster_pool.create().unwrap();
+        assert_eq!(monster.borrow().level(), 1);
+        assert_eq!(monster.borrow().hp(), 1);
+        drop(monster);
+
+        monster_pool.pool_slice()[0].borrow_mut().level_up();
+        let monster = monster_pool.create().unwrap();
+        // The reinitialization is not run twice.
+        assert_eq!(monster.borrow().level(), 2);
+    }
+    #[test]
+    fn test_handle_is_unique() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        assert!(monster.is_unique());
+
+        let same_monster = monster.clone();
+        assert!(!monster.is_unique());
+        assert!(!same_monster.is_unique());
+
+        drop(same_monster);
+        assert!(monster.is_unique());
+        assert!(RcHandle::new(Monster::default()).is_unique());
+    }
+    #[test]
+    fn test_with_capacity_consistent() {
+        let monster_pool = RcPool::with_capacity_consistent(3, Monster::default);
+        assert!(monster_pool
+            .pool_slice()
+            .iter()
+            .all(|monster| monster.borrow().level() == 1 && monster.borrow().hp() == 1));
+
+        let fresh_level = monster_pool.create().unwrap().borrow().level();
+        let recycled_level = monster_pool.create().unwrap().borrow().level();
+        assert_eq!(fresh_level, recycled_level);
+    }
+    #[test]
+    fn test_reinitialize_slot() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        monster_pool.pool_slice()[1].borrow_mut().level_up();
+        assert!(monster_pool.reinitialize_slot(1).is_ok());
+        assert_eq!(monster_pool.pool_slice()[1].borrow().level(), 1);
+        assert_eq!(monster_pool.pool_slice()[0].borrow().level(), 10);
+
+        let monster = monster_pool.create().unwrap();
+        monster.borrow_mut().level_up();
+        assert!(monster_pool.reinitialize_slot(0).is_err());
+        assert_eq!(monster.borrow().level(), 11);
+
+        assert!(monster_pool.reinitialize_slot(2).is_err());
+
+        let object = monster_pool.pool_slice()[1].borrow();
+        assert!(monster_pool.reinitialize_slot(1).is_err());
+        drop(object);
+    }
+    #[test]
+    fn test_is_saturated_and_is_idle() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        assert!(!monster_pool.is_saturated());
+        assert!(monster_pool.is_idle());
+
+        let monster = monster_pool.create().unwrap();
+        assert!(!monster_pool.is_saturated());
+        assert!(!monster_pool.is_idle());
+
+        let monster2 = monster_pool.create().unwrap();
+        assert!(monster_pool.is_saturated());
+        assert!(!monster_pool.is_idle());
+
+        drop(monster);
+        drop(monster2);
+        assert!(monster_pool.is_idle());
+
+        // An empty pool has nothing to give, and nothing in use.
+        let empty_pool = RcPool::with_capacity(0, Monster::default);
+        assert!(empty_pool.is_saturated());
+        assert!(empty_pool.is_idle());
+    }
+    #[test]
+    fn test_handle_map() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        {
+            let level = monster.map_ref(|monster| &monster.level);
+            assert_eq!(*level, 10);
+            // Other immutable borrows are still allowed.
+            assert_eq!(monster.borrow().hp(), 10);
+            assert!(monster.try_borrow_mut().is_err());
+        }
+
+        {
+            let mut name = monster.map_mut(|monster| &mut monster.name);
+            name.push_str(" 2");
+            assert!(monster.try_borrow().is_err());
+        }
+        assert_eq!(&*monster.map_ref(|monster| monster.name.as_str()), "default name 2");
+    }
+    #[test]
+    fn test_handle_try_map_ref() {
+        let monster_pool = RcPool::with_capacity(2, || None);
+        let named = monster_pool.create().unwrap();
+        let anonymous = monster_pool.create().unwrap();
+        named.fill(Monster::default());
+
+        {
+            let name = named
+                .try_map_ref(|monster| monster.as_ref().map(|monster| monster.name.as_str()).ok_or("no monster"))
+                .unwrap();
+            assert_eq!(&*name, "default name");
+            assert!(named.try_borrow_mut().is_err());
+        }
+        assert!(named.try_borrow_mut().is_ok());
+
+        let name = anonymous.try_map_ref(|monster| monster.as_ref().map(|monster| &monster.name).ok_or("no monster"));
+        assert_eq!(name.err(), Some("no monster"));
+        // The object is not borrowed anymore after an error.
+        assert!(anonymous.try_borrow_mut().is_ok());
+    }
+    #[test]
+    fn test_on_evict() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let evicted = Rc::new(RefCell::new(Vec::new()));
+        {
+            let evicted = evicted.clone();
+            monster_pool.on_evict(Box::new(move |index| evicted.borrow_mut().push(index)));
+        }
+
+        // Normal acquisitions and releases do not evict anything.
+        let monster = monster_pool.create().unwrap();
+        drop(monster);
+        let monster0 = monster_pool.force_create_lru().unwrap();
+        let monster1 = monster_pool.force_create_lru().unwrap();
+        assert!(monster_pool.release(monster1).is_ok());
+        assert!(evicted.borrow().is_empty());
+
+        let _monster1 = monster_pool.create().unwrap();
+        let _monster = monster_pool.force_create_mru().unwrap();
+        let _other_monster = monster_pool.force_create_lru().unwrap();
+        assert_eq!(*evicted.borrow(), vec![1, 0]);
+        drop(monster0);
+    }
+    #[test]
+    fn test_with_const_capacity() {
+        let monster_pool = RcPool::with_const_capacity::<3>(Monster::default);
+        assert_eq!(monster_pool.len(), 3);
+        assert_eq!(monster_pool.nb_unused(), 3);
+        assert_eq!(monster_pool.pool_slice()[2].borrow().level(), 10);
+    }
+    #[test]
+    fn test_acquired_at() {
+        use std::thread;
+        use std::time::Duration;
+
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        assert!(monster_pool.acquired_at(0).is_none());
+
+        let before = Instant::now();
+        let monster = monster_pool.create().unwrap();
+        thread::sleep(Duration::from_millis(10));
+
+        let acquired_at = monster_pool.acquired_at(0).unwrap();
+        assert!(acquired_at >= before);
+        assert!(acquired_at.elapsed() >= Duration::from_millis(10));
+        assert!(monster_pool.acquired_at(1).is_none());
+        assert!(monster_pool.acquired_at(2).is_none());
+
+        drop(monster);
+        assert!(monster_pool.acquired_at(0).is_none());
+    }
+    #[test]
+    fn test_find() {
+        let monster_pool = RcPool::with_capacity(4, Monster::default);
+        monster_pool.pool_slice()[1].borrow_mut().level_up();
+        monster_pool.pool_slice()[3].borrow_mut().level_up();
+
+        let monster = monster_pool.find(|monster| monster.level() == 11).unwrap();
+        assert!(monster.ptr_eq(&monster_pool.pool_slice()[1]));
+
+        let another_monster = monster_pool.find(|monster| monster.level() == 11).unwrap();
+        assert!(another_monster.ptr_eq(&monster_pool.pool_slice()[3]));
+
+        assert!(monster_pool.find(|monster| monster.level() == 11).is_none());
+        assert_eq!(monster_pool.nb_unused(), 2);
+
+        // Mutably borrowed objects are skipped.
+        let object = monster_pool.pool_slice()[0].borrow_mut();
+        let last_monster = monster_pool.find(|monster| monster.level() == 10).unwrap();
+        assert!(last_monster.ptr_eq(&monster_pool.pool_slice()[2]));
+        drop(object);
+    }
+    #[test]
+    fn test_recycle_disabled() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
+        monster_pool.set_recycle_enabled(false);
+
+        let monster = monster_pool.create().unwrap();
+        monster.borrow_mut().level_up();
+        drop(monster);
+
+        // The slot is reusable, and the object kept its mutated state.
+        assert_eq!(monster_pool.nb_unused(), 1);
+        let monster = monster_pool.create().unwrap();
+        assert_eq!(monster.borrow().level(), 11);
+        assert_eq!(monster.borrow().hp(), 10);
+
+        monster_pool.set_recycle_enabled(true);
+        drop(monster);
+        assert_eq!(monster_pool.pool_slice()[0].borrow().level(), 1);
+    }
+    #[test]
+    fn test_create_or_grow_doubling() {
+        let mut monster_pool = RcPool::with_capacity(1, Monster::default);
+        let mut monsters = Vec::new();
+        let mut nb_grows = 0;
+
+        for _ in 0..1000 {
+            let len = monster_pool.len();
+            monsters.push(monster_pool.create_or_grow(Monster::default).unwrap());
+            if monster_pool.len() != len {
+                nb_grows += 1;
+            }
+        }
+
+        // 1 -> 2 -> 4 -> ... -> 1024.
+        assert_eq!(nb_grows, 10);
+        assert_eq!(monster_pool.len(), 1024);
+        assert_eq!(monster_pool.nb_unused(), 24);
+    }
+    #[test]
+    fn test_create_or_grow_max_capacity() {
+        let mut monster_pool = RcPool::with_capacity(0, Monster::default);
+        monster_pool.set_max_capacity(Some(5));
+
+        let monsters: Vec<_> = (0..5).map(|_| monster_pool.create_or_grow(Monster::default).unwrap()).collect();
+        assert_eq!(monster_pool.len(), 5);
+        assert!(monster_pool.create_or_grow(Monster::default).is_none());
+
+        drop(monsters);
+        assert!(monster_pool.create_or_grow(Monster::default).is_some());
+    }
+    #[test]
+    fn test_create_or_grow_ignores_the_overflow_policy() {
+        let mut monster_pool = RcPool::with_capacity(1, Monster::default);
+        monster_pool.set_overflow_policy(OverflowPolicy::Allocate(Monster::default));
+        let _monster = monster_pool.create_or_grow(Monster::default).unwrap();
+
+        let monster = monster_pool.create_or_grow(Monster::default).unwrap();
+        assert!(monster.is_pooled());
+        assert_eq!(monster_pool.len(), 2);
+    }
+    #[test]
+    fn test_create_or_grow_keeps_the_priority_reserve() {
+        let mut monster_pool = RcPool::with_priority_reserve(2, 1, Monster::default);
+        let _monster = monster_pool.create_or_grow(Monster::default).unwrap();
+        assert_eq!(monster_pool.len(), 2);
+
+        // Only the reserved object is left: the pool grows instead of giving it.
+        let _monster = monster_pool.create_or_grow(Monster::default).unwrap();
+        assert_eq!(monster_pool.len(), 4);
+        assert_eq!(monster_pool.nb_unused(), 2);
+
+        // The pool grows enough to keep a reserve larger than itself.
+        let mut monster_pool = RcPool::with_priority_reserve(1, 3, Monster::default);
+        let monster = monster_pool.create_or_grow(Monster::default).unwrap();
+        assert!(monster.is_pooled());
+        assert_eq!(monster_pool.len(), 4);
+        assert_eq!(monster_pool.nb_unused(), 3);
+
+        let mut monster_pool = RcPool::with_priority_reserve(1, 3, Monster::default);
+        monster_pool.set_max_capacity(Some(3));
+        assert!(monster_pool.create_or_grow(Monster::default).is_none());
+        assert_eq!(monster_pool.len(), 1);
+    }
+    #[test]
+    fn test_swap_remove_free() {
+        let mut monster_pool = RcPool::with_capacity(4, Monster::default);
+        monster_pool.pool_slice()[3].borrow_mut().level_up();
+        let monster = monster_pool.create().unwrap();
+
+        let removed = monster_pool.swap_remove_free(1).unwrap();
+        assert_eq!(removed.level(), 10);
+        assert_eq!(monster_pool.len(), 3);
+        assert_eq!(monster_pool.nb_unused(), 2);
+        assert_eq!(monster_pool.pool_slice()[1].borrow().level(), 11);
+
+        // The handle given before the removal still points to its slot.
+        assert!(monster.ptr_eq(&monster_pool.pool_slice()[0]));
+        assert!(monster_pool.acquired_at(0).is_some());
+        assert!(monster_pool.acquired_at(1).is_none());
+    }
+    #[test]
+    fn test_swap_remove_free_rejects_used_slot() {
+        let mut monster_pool = RcPool::with_capacity(2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+
+        assert!(monster_pool.swap_remove_free(0).is_none());
+        assert!(monster_pool.swap_remove_free(2).is_none());
+        assert_eq!(monster_pool.len(), 2);
+
+        drop(monster);
+        assert!(monster_pool.swap_remove_free(0).is_some());
+        assert_eq!(monster_pool.len(), 1);
+    }
+    #[test]
+    fn test_filter_handles() {
+        let monster_pool = RcPool::with_capacity(4, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster.borrow_mut().level_up();
+        monster_pool.pool_slice()[2].borrow_mut().level_up();
+
+        let strong_monsters = monster_pool.filter_handles(|monster| monster.level() > 10);
+        assert_eq!(strong_monsters.len(), 2);
+        assert!(strong_monsters[0].ptr_eq(&monster));
+        assert!(strong_monsters[1].ptr_eq(&monster_pool.pool_slice()[2]));
+
+        // Nothing is acquired.
+        assert_eq!(monster_pool.nb_unused(), 3);
+
+        // Mutably borrowed objects are skipped.
+        let object = monster.borrow_mut();
+        assert_eq!(monster_pool.filter_handles(|monster| monster.level() > 10).len(), 1);
+        drop(object);
+    }
+    #[test]
+    fn test_slot_indices_follow_removals() {
+        let mut monster_pool = RcPool::with_capacity(4, Monster::default);
+        monster_pool.pool_slice()[1].borrow_mut().level_up();
+        monster_pool.swap_remove_free(0);
+        monster_pool.drain_filter(|monster| monster.level() == 11);
+
+        for (index, handle) in monster_pool.pool_slice().iter().enumerate() {
+            assert_eq!(handle.slot_index(), Some(index));
+        }
+        assert_eq!(RcHandle::new(Monster::default()).slot_index(), None);
+    }
+    #[cfg(feature = "debug-borrows")]
+    #[test]
+    #[should_panic(expected = "The object of the slot 1 of the Pool")]
+    fn test_debug_borrows_double_borrow() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let _first = monster_pool.create().unwrap();
+        let monster = monster_pool.create().unwrap();
+
+        let _object = monster.borrow();
+        monster.borrow_mut().level_up();
+    }
+    #[test]
+    fn test_clone_inner() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster.borrow_mut().level_up();
+
+        let snapshot = monster.clone_inner();
+        monster.borrow_mut().level_up();
+        monster.borrow_mut().name.push_str(" 2");
+
+        assert_eq!(snapshot.level(), 11);
+        assert_eq!(snapshot.name, "default name");
+        assert_eq!(monster.borrow().level(), 12);
+
+        // The snapshot is not recycled with the object.
+        drop(monster);
+        assert_eq!(snapshot.level(), 11);
+        assert_eq!(monster_pool.pool_slice()[0].borrow().level(), 1);
+    }
+    #[test]
+    fn test_reserve() {
+        let mut monster_pool = RcPool::with_capacity(4, Monster::default);
+        let _monster = monster_pool.create().unwrap();
+        let _another_monster = monster_pool.create().unwrap();
+
+        let mut nb_created = 0;
+        monster_pool.reserve(5, || {
+            nb_created += 1;
+            Monster::default()
+        });
+        assert_eq!(nb_created, 3);
+        assert_eq!(monster_pool.len(), 7);
+        assert_eq!(monster_pool.nb_unused(), 5);
+
+        monster_pool.reserve(2, || panic!("The RcPool has enough unused objects."));
+        assert_eq!(monster_pool.len(), 7);
+    }
+    #[test]
+    fn test_with_capacity_lazy() {
+        let nb_initializations = Cell::new(0);
+        let monster_pool = RcPool::with_capacity_lazy(100, || {
+            nb_initializations.set(nb_initializations.get() + 1);
+            Monster::default()
+        });
+        assert_eq!(nb_initializations.get(), 1);
+        assert_eq!(monster_pool.nb_unused(), 100);
+
+        // Objects are reinitialized before their first acquisition only.
+        let monster = monster_pool.create().unwrap();
+        assert_eq!(monster.borrow().level(), 1);
+        monster.borrow_mut().level_up();
+        drop(monster);
+        assert_eq!(monster_pool.pool_slice()[0].borrow().level(), 1);
+        assert_eq!(monster_pool.pool_slice()[1].borrow().level(), 10);
+        assert_eq!(monster_pool.create().unwrap().borrow().level(), 1);
+    }
+    #[test]
+    fn test_stats() {
+        let monster_pool = RcPool::with_capacity(4, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        let _another_monster = monster_pool.create().unwrap();
+        drop(monster);
+        let _monster = monster_pool.create().unwrap();
+
+        assert_eq!(
+            monster_pool.stats(),
+            PoolStats {
+                len: 4,
+                in_use: 2,
+                unused: 2,
+                peak_in_use: Some(2),
+                total_acquisitions: Some(3),
+                poisoned: 0,
+            }
+        );
+    }
+    #[test]
+    fn test_create_with() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let monster = monster_pool.create_with(|monster| monster.level = 5).unwrap();
+        assert_eq!(monster.borrow().level(), 5);
+        assert_eq!(monster_pool.nb_unused(), 1);
+
+        // The closure is not called if no object can be acquired.
+        let _another_monster = monster_pool.create().unwrap();
+        assert!(monster_pool.create_with(|_| panic!("No object can be acquired.")).is_none());
+
+        drop(monster);
+        assert_eq!(monster_pool.pool_slice()[0].borrow().level(), 1);
+    }
+    #[test]
+    fn test_overflow_policy_allocate() {
+        let mut monster_pool = RcPool::with_capacity(2, Monster::default);
+        monster_pool.set_overflow_policy(OverflowPolicy::Allocate(Monster::default));
+        let _monster = monster_pool.create().unwrap();
+        let _another_monster = monster_pool.create().unwrap();
+
+        let overflow_monster = monster_pool.create().unwrap();
+        assert!(!overflow_monster.is_pooled());
+        assert_eq!(overflow_monster.pool_id(), Some(monster_pool.id()));
+        assert!(overflow_monster.is_unique());
+        overflow_monster.borrow_mut().level_up();
+        assert_eq!(overflow_monster.borrow().level(), 11);
+        assert_eq!(monster_pool.len(), 2);
+        assert_eq!(monster_pool.nb_unused(), 0);
+
+        drop(overflow_monster);
+        assert_eq!(monster_pool.nb_unused(), 0);
+        assert!(monster_pool.create_strict().is_err());
+    }
+    #[test]
+    fn test_force_create_ignores_the_overflow_policy() {
+        let mut monster_pool = RcPool::with_capacity(2, Monster::default);
+        monster_pool.set_overflow_policy(OverflowPolicy::Allocate(Monster::default));
+        let oldest_monster = monster_pool.create().unwrap();
+        oldest_monster.borrow_mut().level_up();
+        let newest_monster = monster_pool.create().unwrap();
+        newest_monster.borrow_mut().level_up();
+
+        let monster = monster_pool.force_create_lru().unwrap();
+        assert!(monster.ptr_eq(&oldest_monster));
+        assert_eq!(oldest_monster.borrow().level(), 1);
+
+        let monster = monster_pool.force_create_mru().unwrap();
+        assert!(monster.ptr_eq(&oldest_monster));
+        assert_eq!(newest_monster.borrow().level(), 11);
+    }
+    #[test]
+    fn test_overflow_policy_fail() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
+        let _monster = monster_pool.create().unwrap();
+        assert!(monster_pool.create().is_none());
+    }
+    #[test]
+    fn test_with_capacity_checked() {
+        let monster_pool = RcPool::with_capacity_checked(5, Monster::default).unwrap();
+        assert_eq!(monster_pool.nb_unused(), 5);
+
+        for size in &[usize::MAX, usize::MAX / 2, isize::MAX as usize / 8] {
+            match RcPool::with_capacity_checked(*size, || -> Monster { panic!("no object should be created") }) {
+                Err(PoolError::CapacityOverflow) => {}
+                _ => panic!("The capacity of the RcPool should overflow."),
+            }
+        }
+    }
+    #[test]
+    fn test_detach() {
+        let monster_pool = RcPool::with_capacity(3, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster.borrow_mut().level_up();
+
+        let monster = monster.detach().unwrap();
+        assert_eq!(monster.level(), 11);
+        assert_eq!(monster_pool.nb_unused(), 2);
+
+        // The slot is never handed out again, even by force.
+        let _monsters: Vec<_> = (0..2).map(|_| monster_pool.create().unwrap()).collect();
+        assert!(monster_pool.create().is_none());
+        assert!(!monster_pool.pool_slice()[0].ptr_eq(&monster_pool.force_create_lru().unwrap()));
+        assert_eq!(monster_pool.nb_unused(), 0);
+        assert!(monster_pool.slots().all(|(_, in_use, _)| in_use));
+    }
+    #[test]
+    fn test_detach_shared_object() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
         let monster = monster_pool.create().unwrap();
-        assert_eq!(Rc::strong_count(monster.as_ref()), 2);
-        assert_eq!(monster_pool.nb_unused(), 9);
+        let same_monster = monster.clone();
+
+        assert!(monster.detach().is_none());
+        assert!(same_monster.detach().is_some());
+        assert_eq!(monster_pool.nb_unused(), 0);
+    }
+    #[test]
+    fn test_compare_handles() {
+        let pool = RcPool::with_capacity(4, Monster::default);
+        let other_pool = RcPool::with_capacity(1, Monster::default);
+
+        let handles: Vec<_> = (0..4).map(|_| pool.create().unwrap()).collect();
+        let foreign = other_pool.create().unwrap();
+
+        let mut sorted = [
+            handles[2].clone(),
+            foreign.clone(),
+            handles[0].clone(),
+            handles[3].clone(),
+            handles[1].clone(),
+        ];
+        sorted.sort_by(|a, b| pool.compare_handles(a, b));
+
+        let indices: Vec<_> = sorted.iter().map(|handle| handle.slot_index()).collect();
+        assert_eq!(indices, vec![Some(0), Some(1), Some(2), Some(3), Some(0)]);
+        assert!(sorted[4].ptr_eq(&foreign));
+        assert_eq!(pool.compare_handles(&handles[1], &handles[1]), Ordering::Equal);
+    }
+    #[test]
+    fn test_try_for_each() {
+        let pool = RcPool::with_capacity(5, Monster::default);
+        let _monster = pool.create().unwrap();
+
+        let mut nb_calls = 0;
+        let result = pool.try_for_each(|monster| {
+            nb_calls += 1;
+            if nb_calls == 3 {
+                return Err(format!("The monster {} is invalid.", nb_calls));
+            }
+            monster.level_up();
+            Ok(())
+        });
+
+        assert_eq!(result, Err(String::from("The monster 3 is invalid.")));
+        assert_eq!(nb_calls, 3);
+        let levels: Vec<_> = pool.pool_slice().iter().map(|handle| handle.borrow().level()).collect();
+        assert_eq!(levels, vec![11, 11, 10, 10, 10]);
+
+        assert_eq!(pool.try_for_each(|_| Ok::<(), ()>(())), Ok(()));
+
+        // A borrowed object is skipped.
+        let _borrowed = pool.pool_slice()[1].borrow();
+        let mut nb_calls = 0;
+        let result = pool.try_for_each(|_| {
+            nb_calls += 1;
+            Ok::<(), ()>(())
+        });
+        assert_eq!(result, Ok(()));
+        assert_eq!(nb_calls, 4);
+    }
+    #[test]
+    fn test_mark_clean() {
+        struct Counted {
+            reinitializations: Rc<Cell<usize>>,
+        }
+
+        impl Recyclable for Counted {
+            fn reinitialize(&mut self) {
+                self.reinitializations.set(self.reinitializations.get() + 1);
+            }
+        }
+
+        let reinitializations = Rc::new(Cell::new(0));
+        let pool = RcPool::with_capacity(1, || Counted {
+            reinitializations: reinitializations.clone(),
+        });
+
+        let object = pool.create().unwrap();
+        object.mark_clean();
+        let clone = object.clone();
+        drop(object);
+        drop(clone);
+        assert_eq!(reinitializations.get(), 0);
+        assert!(pool.has_free());
+
+        // The object is dirty again once back in the pool.
+        drop(pool.create().unwrap());
+        assert_eq!(reinitializations.get(), 1);
+    }
+    #[test]
+    fn test_autotune() {
+        let mut monster_pool = RcPool::with_capacity(20, Monster::default);
         {
-            let monster2 = monster_pool.create().unwrap();
-            assert_eq!(monster2.borrow_mut().level(), 10);
-            assert_eq!(monster2.borrow_mut().hp(), 10);
-            assert_eq!(Rc::strong_count(monster2.as_ref()), 2);
-            assert_eq!(monster_pool.nb_unused(), 8);
+            let _monsters = monster_pool.create_many(6).unwrap();
+        }
+        monster_pool.autotune(0.5, Monster::default);
+        assert_eq!(monster_pool.len(), 9);
 
-            //monster2 will be dropped here, we must check :
-            // - nb_unused() returns 9. It will mean that our drop implementation for the wrapper
-            //around the Rc<RefCell<T>> works.
+        // The pool grows when the peak reaches its size.
+        let monsters = monster_pool.create_many(9).unwrap();
+        monster_pool.autotune(0.5, Monster::default);
+        assert_eq!(monster_pool.len(), 14);
 
-            // - every strong count should be 1 and each object should have in_use to false.
-            // except for monster.
+        // The used objects are never removed.
+        monster_pool.reset_stats();
+        monster_pool.autotune(0.0, Monster::default);
+        assert_eq!(monster_pool.len(), 9);
+        for (index, handle) in monster_pool.pool_slice().iter().enumerate() {
+            assert_eq!(handle.slot_index(), Some(index));
         }
+        drop(monsters);
         assert_eq!(monster_pool.nb_unused(), 9);
-        let nb_monster_with_1_ref = monster_pool
-            .pool_slice()
-            .iter()
-            .filter(|obj| Rc::strong_count(obj.as_ref()) == 1)
-            .count();
+    }
+    #[test]
+    fn test_drain_into() {
+        let mut monster_pool = RcPool::with_capacity_indexed(5, |index| Monster {
+            level: index as u8,
+            ..Default::default()
+        });
+        let mut other_pool = RcPool::with_capacity(1, Monster::default);
+        let used = monster_pool.create().unwrap();
 
-        assert_eq!(nb_monster_with_1_ref, 9);
+        assert_eq!(monster_pool.drain_into(&mut other_pool, 2), 2);
+        assert_eq!(monster_pool.len(), 3);
+        assert_eq!(other_pool.len(), 3);
 
-        let nb_monster_with_1_hp = monster_pool
-            .pool_slice()
-            .iter()
-            .filter(|obj| obj.borrow_mut().hp() == 1)
-            .count();
+        // The moved objects keep their state, and belong to the other pool.
+        let levels: Vec<_> = other_pool.pool_slice().iter().map(|handle| handle.borrow().level()).collect();
+        assert_eq!(levels, vec![10, 1, 2]);
+        for (index, handle) in other_pool.pool_slice().iter().enumerate() {
+            assert_eq!(handle.slot_index(), Some(index));
+            assert_eq!(handle.pool_id(), Some(other_pool.id()));
+        }
 
-        assert_eq!(nb_monster_with_1_hp, 1);
+        // The used object is never moved.
+        assert_eq!(monster_pool.drain_into(&mut other_pool, 10), 2);
+        assert_eq!(monster_pool.len(), 1);
+        assert!(monster_pool.pool_slice()[0].ptr_eq(&used));
+        assert_eq!(other_pool.len(), 5);
+        assert_eq!(other_pool.nb_unused(), 5);
     }
+    #[test]
+    fn test_assert_all_released() {
+        let monster_pool = RcPool::with_capacity(3, Monster::default);
+        {
+            let _monsters = monster_pool.create_many(3).unwrap();
+        }
+        monster_pool.assert_all_released();
+    }
+    #[test]
+    #[should_panic(expected = "1 object(s) of the Pool")]
+    fn test_assert_all_released_with_a_leak() {
+        let monster_pool = RcPool::with_capacity(3, Monster::default);
+        let _first = monster_pool.create().unwrap();
+        drop(monster_pool.create().unwrap());
 
+        monster_pool.assert_all_released();
+    }
     #[test]
-    fn test_create_no_more_objects() {
-        let monster_pool = RcPool::with_capacity(3, || Monster::default());
+    fn test_create_valid() {
+        struct Connection {
+            alive: bool,
+            generation: u32,
+        }
+
+        impl Recyclable for Connection {
+            fn reinitialize(&mut self) {}
+        }
+
+        impl Validate for Connection {
+            fn is_valid(&self) -> bool {
+                self.alive
+            }
+        }
+
+        let pool = RcPool::with_capacity_indexed(4, |index| Connection {
+            alive: index % 2 == 1,
+            generation: 0,
+        });
+
+        // Only the valid connections are given.
+        let first = pool.create_valid().unwrap();
+        let second = pool.create_valid().unwrap();
+        assert!(first.ptr_eq(&pool.pool_slice()[1]));
+        assert!(second.ptr_eq(&pool.pool_slice()[3]));
+        assert!(pool.create_valid().is_none());
+        assert_eq!(pool.nb_unused(), 2);
+
+        // The broken connections are rebuilt.
+        let mut nb_renewed = 0;
+        let renewed: Vec<_> = (0..2)
+            .map(|_| {
+                pool.create_valid_or_renew(|| {
+                    nb_renewed += 1;
+                    Connection {
+                        alive: true,
+                        generation: 1,
+                    }
+                })
+                .unwrap()
+            })
+            .collect();
+        assert_eq!(nb_renewed, 2);
+        assert!(renewed.iter().all(|connection| connection.borrow().is_valid()));
+        assert!(renewed.iter().all(|connection| connection.borrow().generation == 1));
+        assert!(pool.create_valid_or_renew(|| unreachable!()).is_none());
+
+        // A renewed object which is not valid is skipped.
+        drop(renewed);
+        pool.pool_slice()[0].borrow_mut().alive = false;
+        let connection = pool
+            .create_valid_or_renew(|| Connection {
+                alive: false,
+                generation: 2,
+            })
+            .unwrap();
+        assert!(connection.ptr_eq(&pool.pool_slice()[2]));
+    }
+    #[test]
+    fn test_clone_all_handles() {
+        let monster_pool = RcPool::with_capacity(4, Monster::default);
+        let used = monster_pool.create().unwrap();
+        used.borrow_mut().level_up();
+
+        let snapshot = monster_pool.clone_all_handles();
+        assert_eq!(snapshot.len(), 4);
+        assert_eq!(monster_pool.nb_unused(), 0);
+        assert!(monster_pool.create().is_none());
+        for (index, handle) in snapshot.iter().enumerate() {
+            assert!(handle.ptr_eq(&monster_pool.pool_slice()[index]));
+        }
+
+        drop(snapshot);
+        assert_eq!(monster_pool.nb_unused(), 3);
+        // The used object is not reinitialized.
+        assert_eq!(used.borrow().level(), 11);
+        drop(used);
+        assert_eq!(monster_pool.nb_unused(), 4);
+    }
+    #[test]
+    fn test_create_detailed() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
+        let monster = monster_pool.create_detailed().unwrap();
+        match monster_pool.create_detailed() {
+            Err(PoolError::Exhausted) => {}
+            _ => panic!("All the monsters are used."),
+        }
+        drop(monster);
+        assert!(monster_pool.create_detailed().is_ok());
+    }
+    #[test]
+    fn test_create_valid_detailed() {
+        struct Connection {
+            alive: bool,
+        }
+
+        impl Recyclable for Connection {
+            fn reinitialize(&mut self) {}
+        }
+
+        impl Validate for Connection {
+            fn is_valid(&self) -> bool {
+                self.alive
+            }
+        }
+
+        let pool = RcPool::with_capacity_indexed(2, |index| Connection { alive: index == 0 });
+        let connection = pool.create_valid_detailed().unwrap();
+        match pool.create_valid_detailed() {
+            Err(PoolError::AllInvalid) => {}
+            _ => panic!("The non-used connection is not valid."),
+        }
+
+        let _other = pool.create().unwrap();
+        match pool.create_valid_detailed() {
+            Err(PoolError::Exhausted) => {}
+            _ => panic!("All the connections are used."),
+        }
+        drop(connection);
+        assert!(pool.create_valid_detailed().is_ok());
+    }
+    #[test]
+    fn test_collect_values() {
+        let monster_pool = RcPool::with_capacity_indexed(4, |index| Monster {
+            level: index as u8,
+            ..Default::default()
+        });
+        let used = monster_pool.create().unwrap();
+        used.borrow_mut().name = String::from("used");
+
+        let values = monster_pool.collect_values();
+        assert_eq!(values.len(), monster_pool.len());
+        for (value, handle) in values.iter().zip(monster_pool.pool_slice()) {
+            assert_eq!(*value, *handle.borrow());
+        }
+        assert_eq!(values[0].name, "used");
+
+        // The values are not the objects of the pool.
+        let mut values = values;
+        values[1].level_up();
+        assert_eq!(monster_pool.pool_slice()[1].borrow().level(), 1);
+    }
+    #[test]
+    fn test_debug() {
+        let monster_pool = RcPool::with_capacity(3, Monster::default);
         let _monster = monster_pool.create().unwrap();
-        let _monster2 = monster_pool.create().unwrap();
-        let _monster3 = monster_pool.create().unwrap();
 
-        assert_eq!(monster_pool.create(), None);
+        assert_eq!(
+            format!("{:?}", monster_pool),
+            format!("RcPool {{ len: 3, in_use: 1, capacity: {} }}", monster_pool.capacity())
+        );
+
+        let alternate = format!("{:#?}", monster_pool);
+        assert!(alternate.starts_with("RcPool {\n    len: 3,\n    in_use: 1,\n"));
+        assert!(alternate.contains("objects: ["));
+        assert_eq!(alternate.matches("default name").count(), 3);
     }
+    #[test]
+    fn test_replace_free() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let replacement = Monster {
+            name: String::from("replacement"),
+            level: 42,
+            hp: 42,
+        };
+
+        let old_monster = monster_pool.replace_free(1, replacement).unwrap();
+        assert_eq!(old_monster.name, "default name");
+        assert_eq!(monster_pool.pool_slice()[1].borrow().name, "replacement");
+        assert_eq!(monster_pool.nb_unused(), 2);
+
+        // A used object cannot be replaced.
+        let used = monster_pool.create().unwrap();
+        assert!(monster_pool.replace_free(0, Monster::default()).is_err());
+        assert!(used.ptr_eq(&monster_pool.pool_slice()[0]));
+        assert_eq!(used.borrow().name, "default name");
 
+        // Out of bounds.
+        assert!(monster_pool.replace_free(2, Monster::default()).is_err());
+        assert_eq!(monster_pool.len(), 2);
+
+        // A borrowed object cannot be replaced.
+        let _borrowed = monster_pool.pool_slice()[1].borrow();
+        assert!(monster_pool.replace_free(1, Monster::default()).is_err());
+    }
     #[test]
-    fn test_modify_inner_value() {
-        let monster_pool = RcPool::with_capacity(3, || Monster::default());
-        let monster = monster_pool.create().unwrap();
-        monster.borrow_mut().level_up();
-        assert_eq!(monster.borrow_mut().level(), 11);
-        let nb_monster_lvl_11 = monster_pool
+    fn test_release_all() {
+        let monster_pool = RcPool::with_capacity(4, Monster::default);
+        let monsters = monster_pool.create_many(4).unwrap();
+        for monster in &monsters {
+            monster.borrow_mut().level_up();
+        }
+        assert_eq!(monster_pool.nb_unused(), 0);
+
+        monster_pool.release_all(monsters).unwrap();
+        assert_eq!(monster_pool.nb_unused(), 4);
+        assert!(monster_pool
             .pool_slice()
             .iter()
-            .filter(|obj| (**obj).borrow_mut().level() > 10)
-            .count();
+            .all(|monster| monster.borrow().level() == 1));
 
-        assert_eq!(nb_monster_lvl_11, 1);
+        // A shared RcHandle cannot be released, the following ones are.
+        let monsters = monster_pool.create_many(3).unwrap();
+        let clone = monsters[0].clone();
+        let failures = monster_pool.release_all(monsters).unwrap_err();
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].1.ptr_eq(&clone));
+        assert_eq!(monster_pool.nb_unused(), 3);
+        drop(failures);
+        drop(clone);
+        assert_eq!(monster_pool.nb_unused(), 4);
+
+        let other_pool = RcPool::with_capacity(1, Monster::default);
+        let failures = monster_pool.release_all(vec![other_pool.create().unwrap()]).unwrap_err();
+        assert_eq!(failures[0].1.pool_id(), Some(other_pool.id()));
+    }
+    #[test]
+    fn test_pause() {
+        let monster_pool = RcPool::with_capacity(3, Monster::default);
+        let monster = monster_pool.create().unwrap();
+
+        monster_pool.pause();
+        assert!(monster_pool.create().is_none());
+        match monster_pool.create_strict() {
+            Err(PoolError::Paused) => {}
+            _ => panic!("The RcPool is paused."),
+        }
+        match monster_pool.create_detailed() {
+            Err(PoolError::Paused) => {}
+            _ => panic!("The RcPool is paused."),
+        }
+        assert!(monster_pool.find(|_| true).is_none());
+        assert!(monster_pool.create_many(1).is_none());
+        assert!(monster_pool.create_and_borrow_mut().is_none());
+        assert!(monster_pool.force_create_lru().is_none());
+        assert!(monster_pool.create_with_policy(&FirstFree).is_none());
+        assert!(monster_pool.clone().create().is_none());
+
+        // The used objects can still be released.
+        drop(monster);
+        assert_eq!(monster_pool.nb_unused(), 3);
+
+        monster_pool.resume();
+        assert!(monster_pool.create().is_some());
+        assert!(monster_pool.create_strict().is_ok());
     }
+    #[test]
+    fn test_handle_replace() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster.borrow_mut().level_up();
+
+        let replacement = Monster {
+            name: String::from("replacement"),
+            level: 42,
+            hp: 7,
+        };
+        let old_monster = monster.replace(replacement);
+        assert_eq!(old_monster.level(), 11);
+        assert_eq!(old_monster.name, "default name");
+        assert_eq!(monster.borrow().level(), 42);
+        assert_eq!(monster.borrow().name, "replacement");
 
+        // The new value is recycled with the slot.
+        drop(monster);
+        assert_eq!(monster_pool.pool_slice()[0].borrow().level(), 1);
+        assert_eq!(monster_pool.pool_slice()[0].borrow().name, "replacement");
+    }
     #[test]
-    fn test_create_strict() {
-        let monster_pool = RcPool::with_capacity(1, || Monster::default());
-        let _monster = monster_pool.create_strict().unwrap();
+    fn test_with_concurrency() {
+        assert_eq!(recommended_capacity(10, 1.5), 15);
+        assert_eq!(recommended_capacity(10, 1.1), 11);
+        assert_eq!(recommended_capacity(3, 1.5), 5);
+        assert_eq!(recommended_capacity(0, 1.5), 0);
+        assert_eq!(recommended_capacity(10, 0.5), 5);
+        assert_eq!(recommended_capacity(10, -1.0), 0);
+
+        let monster_pool = RcPool::with_concurrency(10, 1.5, Monster::default);
+        assert_eq!(monster_pool.nb_unused(), 15);
+        let monster_pool = RcPool::with_concurrency(4, 1.0, Monster::default);
+        assert_eq!(monster_pool.nb_unused(), 4);
+    }
+    #[test]
+    fn test_each_used_mut() {
+        let monster_pool = RcPool::with_capacity(4, Monster::default);
+        let first_monster = monster_pool.create().unwrap();
+        let second_monster = monster_pool.create().unwrap();
+        let third_monster = monster_pool.create().unwrap();
+
+        {
+            let _borrowed = second_monster.borrow_mut();
+            assert_eq!(monster_pool.each_used_mut(|monster| monster.level_up()), 1);
+        }
+        assert_eq!(first_monster.borrow().level(), 11);
+        assert_eq!(second_monster.borrow().level(), 10);
+        assert_eq!(third_monster.borrow().level(), 11);
+        // The non-used object is not processed.
+        assert_eq!(monster_pool.pool_slice()[3].borrow().level(), 10);
+
+        assert_eq!(monster_pool.each_used_mut(|monster| monster.level_up()), 0);
+        assert_eq!(second_monster.borrow().level(), 11);
+        assert_eq!(third_monster.borrow().level(), 12);
+    }
+    #[test]
+    fn test_create_indexed() {
+        let monster_pool = RcPool::with_capacity(3, Monster::default);
+        let first_monster = monster_pool.create().unwrap();
+        let (index, second_monster) = monster_pool.create_indexed().unwrap();
+        assert_eq!(index, 1);
+        assert!(second_monster.ptr_eq(&monster_pool.pool_slice()[index]));
+        assert_eq!(second_monster.slot_index(), Some(index));
+
+        drop(first_monster);
+        let (index, third_monster) = monster_pool.create_indexed().unwrap();
+        assert_eq!(index, 0);
+        assert!(third_monster.ptr_eq(&monster_pool.pool_slice()[index]));
+
+        let (index, _fourth_monster) = monster_pool.create_indexed().unwrap();
+        assert_eq!(index, 2);
+        assert!(monster_pool.create_indexed().is_none());
+    }
+    #[test]
+    fn test_resize() {
+        let mut monster_pool = RcPool::with_capacity(2, Monster::default);
+        monster_pool.resize(4, Monster::default).unwrap();
+        assert_eq!(monster_pool.len(), 4);
+        assert_eq!(monster_pool.nb_unused(), 4);
+
+        let first_monster = monster_pool.create().unwrap();
+        let second_monster = monster_pool.create().unwrap();
+        monster_pool.resize(4, Monster::default).unwrap();
+        assert_eq!(monster_pool.len(), 4);
+
+        assert!(monster_pool.resize(1, Monster::default).is_err());
+        assert_eq!(monster_pool.len(), 4);
+        assert_eq!(monster_pool.nb_unused(), 2);
+
+        monster_pool.resize(2, Monster::default).unwrap();
+        assert_eq!(monster_pool.len(), 2);
+        assert_eq!(monster_pool.nb_unused(), 0);
+        assert!(first_monster.ptr_eq(&monster_pool.pool_slice()[0]));
+        assert!(second_monster.ptr_eq(&monster_pool.pool_slice()[1]));
+
+        drop(first_monster);
+        drop(second_monster);
+        monster_pool.resize(0, Monster::default).unwrap();
+        assert!(monster_pool.is_empty());
+    }
+    #[test]
+    fn test_observer() {
+        #[derive(Debug, PartialEq)]
+        enum Event {
+            Create(usize),
+            Release(usize),
+            Exhausted,
+            Grow(usize),
+        }
+
+        struct Recorder(Rc<RefCell<Vec<Event>>>);
+
+        impl PoolObserver for Recorder {
+            fn on_create(&self, index: usize) {
+                self.0.borrow_mut().push(Event::Create(index));
+            }
+            fn on_release(&self, index: usize) {
+                self.0.borrow_mut().push(Event::Release(index));
+            }
+            fn on_exhausted(&self) {
+                self.0.borrow_mut().push(Event::Exhausted);
+            }
+            fn on_grow(&self, additional: usize) {
+                self.0.borrow_mut().push(Event::Grow(additional));
+            }
+        }
+
+        let mut monster_pool = RcPool::with_capacity(1, Monster::default);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        monster_pool.set_observer(Box::new(Recorder(events.clone())));
+
+        let monster = monster_pool.create().unwrap();
+        let same_monster = monster.clone();
+        assert!(monster_pool.create().is_none());
+        drop(monster);
+        drop(same_monster);
+        assert_eq!(*events.borrow(), vec![Event::Create(0), Event::Exhausted, Event::Release(0)]);
+
+        events.borrow_mut().clear();
+        monster_pool.grow(2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        let another_monster = monster_pool.create().unwrap();
+        drop(another_monster);
+        drop(monster);
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                Event::Grow(2),
+                Event::Create(0),
+                Event::Create(1),
+                Event::Release(1),
+                Event::Release(0),
+            ]
+        );
+    }
+    #[test]
+    fn test_from_seeds() {
+        struct Config {
+            name: &'static str,
+            level: u8,
+        }
+
+        let configs = [
+            Config { name: "goblin", level: 2 },
+            Config { name: "orc", level: 5 },
+            Config { name: "troll", level: 9 },
+        ];
+        let monster_pool = RcPool::from_seeds(&configs, |config| Monster {
+            name: String::from(config.name),
+            level: config.level,
+            hp: 10,
+        });
+
+        assert_eq!(monster_pool.len(), 3);
+        assert_eq!(monster_pool.nb_unused(), 3);
+        for (handle, config) in monster_pool.pool_slice().iter().zip(configs.iter()) {
+            assert_eq!(handle.borrow().name, config.name);
+            assert_eq!(handle.borrow().level(), config.level);
+        }
+
+        let no_configs: [Config; 0] = [];
+        assert!(RcPool::from_seeds(&no_configs, |_| Monster::default()).is_empty());
+    }
+    #[test]
+    fn test_priority_reserve() {
+        let monster_pool = RcPool::with_priority_reserve(3, 2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        assert!(monster_pool.create().is_none());
         assert!(monster_pool.create_strict().is_err());
+        assert!(monster_pool.create_indexed().is_none());
+        assert!(monster_pool.create_and_borrow_mut().is_none());
+        assert_eq!(monster_pool.nb_unused(), 2);
+
+        let first_critical = monster_pool.create_priority().unwrap();
+        let second_critical = monster_pool.create_priority().unwrap();
+        assert!(monster_pool.create_priority().is_none());
+        assert_eq!(monster_pool.nb_unused(), 0);
+
+        // Low-priority acquisitions succeed again once more objects than the reserve are free.
+        drop(first_critical);
+        drop(second_critical);
+        assert!(monster_pool.create().is_none());
+        drop(monster);
+        assert!(monster_pool.create().is_some());
+    }
+    #[test]
+    fn test_with_capacity_no_reset() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static NB_REINITIALIZATIONS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Clone, Copy, Default)]
+        struct Point {
+            x: i32,
+        }
+
+        impl Recyclable for Point {
+            fn reinitialize(&mut self) {
+                NB_REINITIALIZATIONS.fetch_add(1, Ordering::SeqCst);
+                self.x = 0;
+            }
+        }
+
+        let point_pool = RcPool::with_capacity_no_reset(2, Point::default);
+        for x in 0..5 {
+            let point = point_pool.create().unwrap();
+            point.borrow_mut().x = x;
+            let clone = point.clone();
+            drop(point);
+            drop(clone);
+        }
+        {
+            // A deferred reinitialization does not call `reinitialize` either.
+            let point = point_pool.create().unwrap();
+            let _borrowed = point_pool.pool_slice()[0].borrow();
+            drop(point);
+        }
+        assert!(point_pool.create_many(2).is_some());
+
+        assert_eq!(NB_REINITIALIZATIONS.load(Ordering::SeqCst), 0);
+        assert_eq!(point_pool.pool_slice()[0].borrow().x, 4);
+    }
+    #[test]
+    fn test_contains_value() {
+        let monster_pool = RcPool::with_capacity(3, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster.borrow_mut().level_up();
+        let leveled_up = monster.borrow().clone();
+
+        assert!(monster_pool.contains_value(&leveled_up, false));
+        assert!(monster_pool.contains_value(&leveled_up, true));
+        // The non-used objects are only compared when asked to.
+        assert!(!monster_pool.contains_value(&Monster::default(), false));
+        assert!(monster_pool.contains_value(&Monster::default(), true));
+
+        // Once released, the object is reset: it only matches its recycled state.
+        let mut recycled = leveled_up.clone();
+        recycled.reinitialize();
+        drop(monster);
+        assert!(!monster_pool.contains_value(&leveled_up, false));
+        assert!(!monster_pool.contains_value(&leveled_up, true));
+        assert!(!monster_pool.contains_value(&recycled, false));
+        assert!(monster_pool.contains_value(&recycled, true));
+    }    #[test]
+    fn test_into_arc_pool_lazy() {
+        let monster_pool = RcPool::with_capacity_lazy(2, Monster::default);
+
+        // The placeholders are reinitialized before being moved to the ArcPool.
+        let monster_pool = monster_pool.into_arc_pool().unwrap();
+        assert_eq!(monster_pool.create().unwrap().read().unwrap().level(), 1);
+        assert_eq!(monster_pool.pool_slice()[1].read().unwrap().level(), 1);
+    }
+    #[test]
+    fn test_drain_into_lazy() {
+        let mut monster_pool = RcPool::with_capacity_lazy(3, Monster::default);
+        let mut other_pool = RcPool::with_capacity(0, Monster::default);
+
+        // The placeholders are reinitialized before being moved to the other pool.
+        assert_eq!(monster_pool.drain_into(&mut other_pool, 2), 2);
+        assert_eq!(other_pool.create().unwrap().borrow().level(), 1);
+        assert_eq!(other_pool.pool_slice()[1].borrow().level(), 1);
+
+        let removed = monster_pool.swap_remove_free(0).unwrap();
+        assert_eq!(removed.level(), 1);
+    }    #[test]
+    fn test_on_evict_reads_the_evicted_object() {
+        let monster_pool = RcPool::with_capacity(1, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster.borrow_mut().level_up();
+
+        let evicted_levels = Rc::new(RefCell::new(Vec::new()));
+        {
+            let evicted_levels = evicted_levels.clone();
+            let evicted = monster.clone();
+            monster_pool.on_evict(Box::new(move |_| evicted_levels.borrow_mut().push(evicted.borrow().level())));
+        }
+
+        let _other_monster = monster_pool.force_create_lru().unwrap();
+        assert_eq!(*evicted_levels.borrow(), vec![11]);
+        drop(monster);
+    }    #[test]
+    fn test_free_slots_counter() {
+        let mut monster_pool = RcPool::with_capacity(4, Monster::default);
+        let scanned = |pool: &RcPool<Monster>| (0..pool.len()).filter(|&index| pool.is_unused(index)).count();
+        assert_eq!(monster_pool.counters.free.get(), 4);
+
+        let monster = monster_pool.create().unwrap();
+        let same_monster = monster.clone();
+        let monsters = monster_pool.create_many(2).unwrap();
+        let outside = monster_pool.pool_slice()[3].clone();
+        assert_eq!(monster_pool.counters.free.get(), 0);
+        assert_eq!(monster_pool.counters.free.get(), scanned(&monster_pool));
+
+        drop(monster);
+        drop(monsters);
+        drop(outside);
+        assert_eq!(monster_pool.counters.free.get(), 3);
+        assert!(same_monster.detach().is_some());
+        assert_eq!(monster_pool.counters.free.get(), 3);
+
+        monster_pool.grow(2, Monster::default);
+        assert_eq!(monster_pool.swap_remove_free(1).map(|_| ()), Some(()));
+        let mut other_pool = RcPool::with_capacity(0, Monster::default);
+        assert_eq!(monster_pool.drain_into(&mut other_pool, 2), 2);
+        assert_eq!(monster_pool.counters.free.get(), 2);
+        assert_eq!(monster_pool.counters.free.get(), scanned(&monster_pool));
+        assert_eq!(other_pool.counters.free.get(), 2);
+
+        let cloned_pool = monster_pool.clone();
+        assert_eq!(monster_pool.counters.free.get(), scanned(&monster_pool));
+        drop(cloned_pool);
+        assert_eq!(monster_pool.counters.free.get(), 2);
+        assert_eq!(monster_pool.counters.free.get(), scanned(&monster_pool));
+    }    #[test]
+    fn test_priority_reserve_batches() {
+        let monster_pool = RcPool::with_priority_reserve(3, 2, Monster::default);
+        let _monster = monster_pool.create().unwrap();
+
+        assert!(monster_pool.create().is_none());
+        assert!(monster_pool.create_many(1).is_none());
+        assert!(monster_pool.reserve_group(1).is_none());
+        assert!(monster_pool.find(|_| true).is_none());
+        assert!(monster_pool.create_with_policy(&FirstFree).is_none());
+        assert_eq!(monster_pool.nb_unused(), 2);
+
+        // The forced acquisitions recycle a used object, not a reserved one.
+        let forced = monster_pool.force_create_lru().unwrap();
+        assert_eq!(forced.slot_index(), Some(0));
+        assert_eq!(monster_pool.nb_unused(), 2);
+        assert!(monster_pool.create_priority().is_some());
+    }    #[test]
+    fn test_detached_slots_are_not_used() {
+        let monster_pool = RcPool::with_capacity(2, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        assert!(monster.detach().is_some());
+
+        assert!(monster_pool.is_idle());
+        assert_eq!(monster_pool.stats().in_use, 0);
+        assert_eq!(monster_pool.stats().unused, 1);
+        assert_eq!(
+            format!("{:?}", monster_pool),
+            format!("RcPool {{ len: 2, in_use: 0, capacity: {} }}", monster_pool.capacity())
+        );
+
+        let _monster = monster_pool.create().unwrap();
+        assert!(!monster_pool.is_idle());
+        assert_eq!(monster_pool.stats().in_use, 1);
+    }    #[test]
+    fn test_resize_removes_detached_slots() {
+        let mut monster_pool = RcPool::with_capacity(3, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        let another_monster = monster_pool.create().unwrap();
+        assert!(monster.detach().is_some());
+
+        // The error counts the used objects only.
+        match monster_pool.resize(0, Monster::default) {
+            Err(PoolError::PoolError(message)) => assert!(message.contains(", 1 are used")),
+            _ => panic!("A monster is used."),
+        }
+
+        monster_pool.resize(1, Monster::default).unwrap();
+        assert_eq!(monster_pool.len(), 1);
+        assert!(monster_pool.pool_slice()[0].ptr_eq(&another_monster));
+        assert_eq!(monster_pool.nb_unused(), 0);
+        drop(another_monster);
+        assert_eq!(monster_pool.nb_unused(), 1);
+
+        // A detached slot still referenced is kept.
+        let monster = monster_pool.create().unwrap();
+        assert!(monster.detach().is_some());
+        let referenced = monster_pool.pool_slice()[0].clone();
+        assert!(monster_pool.resize(0, Monster::default).is_err());
+        drop(referenced);
+        monster_pool.resize(0, Monster::default).unwrap();
+        assert!(monster_pool.is_empty());
+    }    #[test]
+    fn test_into_arc_pool_drops_detached_slots() {
+        let monster_pool = RcPool::with_capacity(3, Monster::default);
+        let monster = monster_pool.create().unwrap();
+        monster_pool.pool_slice()[2].borrow_mut().level_up();
+        assert_eq!(monster.detach().unwrap().level(), 10);
+
+        let referenced = monster_pool.pool_slice()[0].clone();
+        let monster_pool = monster_pool.into_arc_pool().unwrap_err();
+        drop(referenced);
+
+        let arc_pool = monster_pool.into_arc_pool().unwrap();
+        assert_eq!(arc_pool.len(), 2);
+        assert_eq!(arc_pool.nb_unused(), 2);
+        assert_eq!(arc_pool.pool_slice()[1].read().unwrap().level(), 11);
+    }    #[test]
+    fn test_reinitialize_slot_clears_the_deferred_reinitialization() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static NB_REINITIALIZATIONS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Clone, Default)]
+        struct Counted;
+
+        impl Recyclable for Counted {
+            fn reinitialize(&mut self) {
+                NB_REINITIALIZATIONS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let counted_pool = RcPool::with_capacity_lazy(1, Counted::default);
+        assert!(counted_pool.reinitialize_slot(0).is_ok());
+        assert_eq!(NB_REINITIALIZATIONS.load(Ordering::SeqCst), 1);
+
+        let _counted = counted_pool.create().unwrap();
+        assert_eq!(NB_REINITIALIZATIONS.load(Ordering::SeqCst), 1);
     }
 }
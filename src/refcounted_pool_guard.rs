@@ -0,0 +1,60 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::cell::RefMut;
+use std::ops::{Deref, DerefMut};
+use refcounted_pool_handler::RcHandle;
+use pool_object::Recyclable;
+
+/// A `RcHandle<T>` returned with a mutable borrow of its object, by `RcPool::create_and_borrow_mut`.
+///
+/// It dereferences to the pooled object. The borrow is always released before the `RcHandle<T>` is dropped,
+/// so the object can be recycled without panicking.
+///
+/// Use `into_handle` to release the borrow and keep the `RcHandle<T>`.
+#[derive(Debug)]
+pub struct RcHandleMut<'a, T: Recyclable + 'a> {
+    // Fields are dropped in declaration order: the borrow must be released first.
+    object: RefMut<'a, T>,
+    handle: RcHandle<T>,
+}
+
+impl<'a, T: Recyclable> RcHandleMut<'a, T> {
+    #[doc(hidden)]
+    pub fn new(handle: RcHandle<T>, object: RefMut<'a, T>) -> Self {
+        debug!("Creating a RcHandleMut.");
+        RcHandleMut { object, handle }
+    }
+
+    /// Returns a reference to the `RcHandle<T>` of the borrowed object.
+    pub fn handle(&self) -> &RcHandle<T> {
+        debug!("Getting the RcHandle of the RcHandleMut.");
+        &self.handle
+    }
+
+    /// Releases the mutable borrow, and returns the `RcHandle<T>`.
+    pub fn into_handle(self) -> RcHandle<T> {
+        debug!("Releasing the mutable borrow of the RcHandleMut.");
+        let RcHandleMut { object, handle } = self;
+        drop(object);
+        handle
+    }
+}
+
+impl<'a, T: Recyclable> Deref for RcHandleMut<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.object
+    }
+}
+
+impl<'a, T: Recyclable> DerefMut for RcHandleMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.object
+    }
+}
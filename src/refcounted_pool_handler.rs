@@ -6,8 +6,14 @@
 // copied, modified, or distributed except according to those terms.
 
 use std::rc::Rc;
-use std::cell::{BorrowError, BorrowMutError, Ref, RefCell, RefMut};
-use pool_object::Recyclable;
+use std::cell::{BorrowError, BorrowMutError, Cell, Ref, RefCell, RefMut};
+use std::cmp::Ordering;
+use std::mem;
+#[cfg(feature = "backtrace")]
+use std::backtrace::Backtrace;
+use pool_object::{skip_recycle, Recyclable};
+use pool_id::PoolId;
+use pool_observer::SharedObserver;
 
 /// A wrapper around a `Rc` pointer to a `Poolable` object with interior mutability.
 ///
@@ -19,8 +25,63 @@ use pool_object::Recyclable;
 /// This wrapper allows a custom `Drop` implementation: when a `RcHandle` is dropped, the contained `Poolable` object is reinitialized
 /// if its strong reference count is equal to two. If it is the case, the object is reinitialized, the inner `Rc` is dropped and the strong
 /// reference count decrease to 1, meaning that the only structure holding a reference is the `RcPool` itself.
-#[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
-pub struct RcHandle<T: Recyclable>(pub Rc<RefCell<T>>);
+///
+/// If the object is borrowed when the last `RcHandle` is dropped, it cannot be reinitialized: its slot is marked,
+/// and the object is reinitialized by the pool right before being handed out again.
+///
+/// If the recycling of its pool is disabled, with `RcPool::set_recycle_enabled`, the object is not reinitialized
+/// when the last `RcHandle` is dropped.
+///
+/// The `PartialEq`, `Eq`, `PartialOrd` and `Ord` implementations compare the *identity* of the inner objects, not their values:
+/// two handles are equal if they point to the same object. The inner objects are never borrowed during a comparison,
+/// so comparing handles cannot panic, even if one of them is mutably borrowed.
+#[derive(Debug)]
+pub struct RcHandle<T: Recyclable>(
+    pub Rc<RefCell<T>>,
+    fn(&mut T),
+    Option<PoolId>,
+    Rc<SlotState>,
+    Rc<Cell<bool>>,
+    SharedObserver,
+);
+
+/// The number of non-used and detached slots of a pool, shared by the pool and its `RcHandle`s.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct SlotCounters {
+    pub free: Cell<usize>,
+    pub detached: Cell<usize>,
+}
+
+impl SlotCounters {
+    #[doc(hidden)]
+    pub fn new(free: usize) -> Self {
+        SlotCounters {
+            free: Cell::new(free),
+            detached: Cell::new(0),
+        }
+    }
+}
+
+// The state of a slot, shared by the RcHandles of its object.
+#[derive(Debug, Default)]
+struct SlotState {
+    // The reinitialization of the object has been deferred, because it was borrowed.
+    needs_reinit: Cell<bool>,
+    // The index of the slot in its pool, if the object belongs to a pool.
+    index: Cell<Option<usize>>,
+    // The object has been detached from the pool, its slot is never handed out again.
+    detached: Cell<bool>,
+    // The object has not been mutated since its acquisition, it is not reinitialized when it goes back to the pool.
+    clean: Cell<bool>,
+    // The object was used when its pool was serialized, and has not been acquired since its deserialization.
+    restored: Cell<bool>,
+    // The counters of the pool, updated when the object is acquired, goes back to the pool or is detached.
+    counters: Option<Rc<SlotCounters>>,
+    // The call site of the last acquisition of the object, recorded with the `backtrace` feature.
+    #[cfg(feature = "backtrace")]
+    acquired_from: RefCell<Option<Backtrace>>,
+}
 
 impl<T: Recyclable> AsRef<Rc<RefCell<T>>> for RcHandle<T> {
     fn as_ref(&self) -> &Rc<RefCell<T>> {
@@ -33,7 +94,221 @@ impl<T: Recyclable> RcHandle<T> {
     #[doc(hidden)]
     pub fn new(item: T) -> Self {
         debug!("Creating a RcHandle.");
-        RcHandle(Rc::new(RefCell::new(item)))
+        RcHandle(
+            Rc::new(RefCell::new(item)),
+            T::reinitialize,
+            None,
+            Rc::new(SlotState::default()),
+            Rc::new(Cell::new(true)),
+            SharedObserver::default(),
+        )
+    }
+
+    /// Creates a new `RcHandle` from a `Recyclable` object, belonging to the given pool and
+    /// recycled with the given function, if the recycling of the pool is enabled.
+    ///
+    /// The observer of the pool is notified when the object goes back to the pool, and the counters
+    /// of the pool are updated when the object is acquired, goes back to the pool or is detached.
+    #[doc(hidden)]
+    pub fn pooled(
+        item: T,
+        recycle: fn(&mut T),
+        pool_id: PoolId,
+        index: usize,
+        recycle_enabled: Rc<Cell<bool>>,
+        observer: SharedObserver,
+        counters: Rc<SlotCounters>,
+    ) -> Self {
+        debug!("Creating a RcHandle for the slot {} of the pool {}.", index, pool_id);
+        let state = SlotState {
+            needs_reinit: Cell::new(false),
+            index: Cell::new(Some(index)),
+            counters: Some(counters),
+            ..SlotState::default()
+        };
+        RcHandle(Rc::new(RefCell::new(item)), recycle, Some(pool_id), Rc::new(state), recycle_enabled, observer)
+    }
+
+    /// Creates a new `RcHandle` from an object created by the given pool when it overflowed.
+    ///
+    /// The object does not belong to a slot of the pool, and is never recycled.
+    #[doc(hidden)]
+    pub fn overflow(item: T, pool_id: PoolId) -> Self {
+        debug!("Creating an overflow RcHandle for the pool {}.", pool_id);
+        RcHandle(
+            Rc::new(RefCell::new(item)),
+            skip_recycle,
+            Some(pool_id),
+            Rc::new(SlotState::default()),
+            Rc::new(Cell::new(false)),
+            SharedObserver::default(),
+        )
+    }
+
+    /// Marks the object, so it is reinitialized by the pool right before being handed out.
+    #[doc(hidden)]
+    pub fn defer_reinitialization(&self) {
+        self.3.needs_reinit.set(true);
+    }
+
+    /// Forgets a deferred reinitialization, when the pool has just reinitialized the object.
+    #[doc(hidden)]
+    pub fn clear_deferred_reinitialization(&self) {
+        self.3.needs_reinit.set(false);
+    }
+
+    /// Returns the index of the slot of the object in its pool, if it belongs to a pool.
+    #[doc(hidden)]
+    pub fn slot_index(&self) -> Option<usize> {
+        self.3.index.get()
+    }
+
+    /// Returns `true` if the object has been detached from its pool with `detach`.
+    #[doc(hidden)]
+    pub fn is_detached(&self) -> bool {
+        self.3.detached.get()
+    }
+
+    /// Marks the object as used when its pool was serialized, so a `SerializableHandle` can acquire it again.
+    #[doc(hidden)]
+    pub fn set_restored(&self, restored: bool) {
+        self.3.restored.set(restored);
+    }
+
+    /// Returns `true` if the object was used when its pool was serialized, and has not been acquired since.
+    #[doc(hidden)]
+    pub fn is_restored(&self) -> bool {
+        self.3.restored.get()
+    }
+
+    /// Sets the index of the slot of the object, when the pool moves it.
+    #[doc(hidden)]
+    pub fn set_slot_index(&self, index: usize) {
+        self.3.index.set(Some(index));
+    }
+
+    /// Records the call site acquiring the object, for the diagnostics of the `backtrace` feature.
+    #[cfg(feature = "backtrace")]
+    #[doc(hidden)]
+    pub fn record_acquisition(&self) {
+        *self.3.acquired_from.borrow_mut() = Some(Backtrace::force_capture());
+    }
+
+    /// Returns the backtrace of the last acquisition of the object, recorded with the `backtrace` feature.
+    #[cfg(feature = "backtrace")]
+    #[doc(hidden)]
+    pub fn acquisition_backtrace(&self) -> Option<String> {
+        self.3.acquired_from.borrow().as_ref().map(|backtrace| backtrace.to_string())
+    }
+
+    // Describes the slot of the object, for the diagnostics of the `debug-borrows` feature.
+    #[cfg(feature = "debug-borrows")]
+    fn describe_slot(&self) -> String {
+        match (self.3.index.get(), self.2) {
+            (Some(index), Some(pool_id)) => format!("The object of the slot {} of the {}", index, pool_id),
+            _ => String::from("The object, which does not belong to a pool,"),
+        }
+    }
+
+    /// Reinitializes the object if its reinitialization has been deferred, when the last `RcHandle` was dropped.
+    ///
+    /// Returns `false` if the object still needs to be reinitialized, because it is currently borrowed.
+    #[doc(hidden)]
+    pub fn reinitialize_if_needed(&self) -> bool {
+        if !self.3.needs_reinit.get() {
+            return true;
+        }
+
+        match self.0.try_borrow_mut() {
+            Ok(mut object) => {
+                debug!("Reinitializing an object whose reinitialization has been deferred.");
+                (self.1)(&mut object);
+                self.3.needs_reinit.set(false);
+                true
+            }
+            Err(_) => {
+                warn!("The object needs to be reinitialized, but it is currently borrowed.");
+                false
+            }
+        }
+    }
+
+    /// Returns the inner object if this `RcHandle` is the only one pointing to it, or gives the `RcHandle` back.
+    ///
+    /// The object is not reinitialized.
+    #[doc(hidden)]
+    pub fn try_into_inner(mut self) -> Result<T, RcHandle<T>> {
+        trace!("Trying to take the inner object out of the RcHandle.");
+        if Rc::strong_count(&self.0) != 1 {
+            trace!("Other RcHandles point to the inner object.");
+            return Err(self);
+        }
+
+        // Keep the object alive, and drop the handle without recycling the object.
+        // The slot leaves its pool: the handle is marked as detached, so it does not count as a non-used slot.
+        let inner = self.0.clone();
+        self.1 = skip_recycle::<T>;
+        self.3.detached.set(true);
+        drop(self);
+        match Rc::try_unwrap(inner) {
+            Ok(object) => Ok(object.into_inner()),
+            Err(_) => unreachable!("the RcHandle was the only owner of its object"),
+        }
+    }
+
+    /// Returns the `PoolId` of the pool which created this `RcHandle`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// # use std::error::Error;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let pool = RcPool::with_capacity(10, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create_strict()?;
+    /// assert_eq!(monster.pool_id(), Some(pool.id()));
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #   try_main().unwrap();
+    /// # }
+    /// ```
+    pub fn pool_id(&self) -> Option<PoolId> {
+        debug!("Getting the PoolId of the RcHandle.");
+        self.2
     }
 
     /// Immutably borrows the wrapped value.
@@ -43,7 +318,8 @@ impl<T: Recyclable> RcHandle<T> {
     ///
     /// # Panics
     ///
-    /// Panics if the value is currently mutably borrowed.
+    /// Panics if the value is currently mutably borrowed. With the `debug-borrows` feature, the panic message
+    /// gives the slot of the object and the identifier of its pool.
     ///
     ///
     /// # Example
@@ -94,9 +370,23 @@ impl<T: Recyclable> RcHandle<T> {
     /// #   try_main().unwrap();
     /// # }
     /// ```
-    pub fn borrow(&self) -> Ref<T> {
+    pub fn borrow(&self) -> Ref<'_, T> {
         debug!("Borrowing an immutable reference to the inner object.");
-        self.0.borrow()
+        #[cfg(feature = "debug-borrows")]
+        {
+            match self.0.try_borrow() {
+                Ok(object) => object,
+                Err(_) => {
+                    let message = format!("{} is already mutably borrowed", self.describe_slot());
+                    error!("{}", message);
+                    panic!("{}", message);
+                }
+            }
+        }
+        #[cfg(not(feature = "debug-borrows"))]
+        {
+            self.0.borrow()
+        }
     }
 
     /// Immutably borrows the wrapped value, returning an error if the value is currently mutably borrowed.
@@ -154,7 +444,7 @@ impl<T: Recyclable> RcHandle<T> {
     /// #   try_main().unwrap();
     /// # }
     /// ```
-    pub fn try_borrow(&self) -> Result<Ref<T>, BorrowError> {
+    pub fn try_borrow(&self) -> Result<Ref<'_, T>, BorrowError> {
         debug!("Trying to borrow an immutable reference to the inner object.");
         self.0.try_borrow()
     }
@@ -166,7 +456,8 @@ impl<T: Recyclable> RcHandle<T> {
     ///
     /// # Panics
     ///
-    /// Panics if the value is currently borrowed.
+    /// Panics if the value is currently borrowed. With the `debug-borrows` feature, the panic message
+    /// gives the slot of the object and the identifier of its pool.
     ///
     ///
     /// # Example
@@ -218,9 +509,23 @@ impl<T: Recyclable> RcHandle<T> {
     /// #   try_main().unwrap();
     /// # }
     /// ```
-    pub fn borrow_mut(&self) -> RefMut<T> {
+    pub fn borrow_mut(&self) -> RefMut<'_, T> {
         debug!("Borrowing a mutable reference to the inner object.");
-        self.0.borrow_mut()
+        #[cfg(feature = "debug-borrows")]
+        {
+            match self.0.try_borrow_mut() {
+                Ok(object) => object,
+                Err(_) => {
+                    let message = format!("{} is already borrowed", self.describe_slot());
+                    error!("{}", message);
+                    panic!("{}", message);
+                }
+            }
+        }
+        #[cfg(not(feature = "debug-borrows"))]
+        {
+            self.0.borrow_mut()
+        }
     }
 
     /// Mutably borrows the wrapped value, returning an error if the value is currently borrowed.
@@ -278,7 +583,7 @@ impl<T: Recyclable> RcHandle<T> {
     /// #   try_main().unwrap();
     /// # }
     /// ```
-    pub fn try_borrow_mut(&self) -> Result<RefMut<T>, BorrowMutError> {
+    pub fn try_borrow_mut(&self) -> Result<RefMut<'_, T>, BorrowMutError> {
         debug!("Trying to borrow a mutable reference to the inner object.");
         self.0.try_borrow_mut()
     }
@@ -340,28 +645,746 @@ impl<T: Recyclable> RcHandle<T> {
         debug!("Returning a raw pointer to the inner object.");
         self.0.as_ptr()
     }
-}
 
-impl<T: Recyclable> Drop for RcHandle<T> {
-    /// This `Drop` implementation allow us to reinitialize the `Poolable` object
-    /// if the strong reference count of the inner `Rc` is equal to 2.
+    /// Immutably borrows a part of the wrapped object, selected by the given closure.
     ///
-    /// If it is the case, `T` is reinitialized, the inner `Rc` is dropped and the strong
-    /// reference count is decreased to 1, meaning that the only structure holding a reference is the `RcPool` itself.
-    fn drop(&mut self) {
-        trace!("The RcHandle is being dropped.");
-        // Outer(Inner) -> Outer is dropped, then Inner is dropped.
-        // That's why we check if the refcount is equal to 2 :
-        // PoolObjectHandler is dropped (refcount == 2), then Rc<RefCell<T>> is dropped (refcount == 1 -> only the pool has a ref to the data).
-        if Rc::strong_count(&self.0) == 2 {
-            trace!("The reference count of the RcHandle is equal to 2. Reinitializing the inner object.");
-            self.0.borrow_mut().reinitialize();
-        }
+    /// Refer to the [Ref::map](https://doc.rust-lang.org/std/cell/struct.Ref.html#method.map)
+    /// function for more information.
+    ///
+    /// # Panics
+    /// The value is currently mutably borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create().unwrap();
+    /// let level = monster.map_ref(|monster| &monster.level);
+    /// assert_eq!(*level, 10);
+    /// ```
+    pub fn map_ref<U: ?Sized, F>(&self, f: F) -> Ref<'_, U>
+    where
+        F: FnOnce(&T) -> &U,
+    {
+        debug!("Borrowing a part of the object of the RcHandle.");
+        Ref::map(self.borrow(), f)
     }
-}
 
-impl<T: Recyclable> Clone for RcHandle<T> {
-    fn clone(&self) -> Self {
-        RcHandle(self.0.clone())
+    /// Immutably borrows a part of the wrapped object, selected by the given closure, if this part exists.
+    ///
+    /// It helps to borrow an optional part of the object, like the field of an enum variant. The error returned by
+    /// the closure is returned, and the object is not borrowed anymore.
+    ///
+    /// Refer to the [Ref::filter_map](https://doc.rust-lang.org/std/cell/struct.Ref.html#method.filter_map)
+    /// function for more information.
+    ///
+    /// # Panics
+    ///
+    /// The value is currently mutably borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create().unwrap();
+    /// let level = monster.try_map_ref(|monster| {
+    ///     if monster.level >= 10 {
+    ///         Ok(&monster.level)
+    ///     } else {
+    ///         Err("This monster is too weak !")
+    ///     }
+    /// });
+    /// assert_eq!(*level.unwrap(), 10);
+    ///
+    /// let hp = monster.try_map_ref(|monster| {
+    ///     if monster.hp >= 20 {
+    ///         Ok(&monster.hp)
+    ///     } else {
+    ///         Err("This monster is too weak !")
+    ///     }
+    /// });
+    /// assert_eq!(hp.err(), Some("This monster is too weak !"));
+    /// ```
+    pub fn try_map_ref<U: ?Sized, E, F>(&self, f: F) -> Result<Ref<'_, U>, E>
+    where
+        F: FnOnce(&T) -> Result<&U, E>,
+    {
+        debug!("Trying to borrow a part of the object of the RcHandle.");
+        let mut error = None;
+        Ref::filter_map(self.borrow(), |object| match f(object) {
+            Ok(part) => Some(part),
+            Err(err) => {
+                error = Some(err);
+                None
+            }
+        }).map_err(|_| {
+            trace!("The part of the object of the RcHandle does not exist.");
+            error.expect("the closure returned an error")
+        })
+    }
+
+    /// Mutably borrows a part of the wrapped object, selected by the given closure.
+    ///
+    /// Refer to the [RefMut::map](https://doc.rust-lang.org/std/cell/struct.RefMut.html#method.map)
+    /// function for more information.
+    ///
+    /// # Panics
+    /// The value is currently borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create().unwrap();
+    /// *monster.map_mut(|monster| &mut monster.level) += 5;
+    /// assert_eq!(monster.borrow().level, 15);
+    /// ```
+    pub fn map_mut<U: ?Sized, F>(&self, f: F) -> RefMut<'_, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        debug!("Mutably borrowing a part of the object of the RcHandle.");
+        RefMut::map(self.borrow_mut(), f)
+    }
+
+    /// Returns a clone of the wrapped object, which does not belong to the pool.
+    ///
+    /// The clone is a snapshot of the current state of the object: mutating one of them does not affect the other.
+    ///
+    /// # Panics
+    ///
+    /// The value is currently mutably borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # #[derive(Clone)]
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create().unwrap();
+    /// let snapshot = monster.clone_inner();
+    ///
+    /// monster.borrow_mut().level_up();
+    /// assert_eq!(monster.borrow().level, 11);
+    /// assert_eq!(snapshot.level, 10);
+    /// ```
+    pub fn clone_inner(&self) -> T
+    where
+        T: Clone,
+    {
+        debug!("Cloning the object of the RcHandle.");
+        self.borrow().clone()
+    }
+
+    /// Returns `true` if this `RcHandle` is the only one pointing to its object, besides the one held by the pool.
+    ///
+    /// Nobody else can access the object through a clone of this `RcHandle`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create().unwrap();
+    /// assert!(monster.is_unique());
+    ///
+    /// let same_monster = monster.clone();
+    /// assert!(!monster.is_unique());
+    /// ```
+    pub fn is_unique(&self) -> bool {
+        debug!("Checking if the RcHandle is the only one pointing to its object.");
+        // A handle created outside of a slot has no pool holding a reference to its object.
+        let owners = if self.is_pooled() { 2 } else { 1 };
+        Rc::strong_count(&self.0) == owners
+    }
+
+    /// Returns `true` if the object belongs to a slot of a pool, and goes back to this pool when it is not used anymore.
+    ///
+    /// The `RcHandle`s created by a pool with the `OverflowPolicy::Allocate` policy, when all its objects were used,
+    /// are not pooled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{OverflowPolicy, RcPool};
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let mut pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    /// pool.set_overflow_policy(OverflowPolicy::Allocate(Monster::default));
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// let another_monster = pool.create().unwrap();
+    /// assert!(a_monster.is_pooled());
+    /// assert!(!another_monster.is_pooled());
+    /// ```
+    pub fn is_pooled(&self) -> bool {
+        debug!("Checking if the RcHandle belongs to a slot of a pool.");
+        self.3.index.get().is_some()
+    }
+
+    /// Marks the object as clean: it is not reinitialized when its last `RcHandle` is dropped.
+    ///
+    /// Objects are dirty by default. A caller which only read from the object can mark it clean, to skip a
+    /// reinitialization which would be wasted work. The object becomes dirty again once back in the pool.
+    ///
+    /// The object must not be mutated after being marked clean, or the next user of the object will see the mutation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// assert_eq!(a_monster.borrow().level, 10);
+    /// a_monster.mark_clean();
+    /// drop(a_monster);
+    ///
+    /// // The monster has not been reinitialized.
+    /// assert_eq!(pool.pool_slice()[0].borrow().level, 10);
+    /// ```
+    pub fn mark_clean(&self) {
+        debug!("Marking the object of the RcHandle as clean.");
+        self.3.clean.set(true);
+    }
+
+    /// Detaches the object from its pool, and returns it, if this `RcHandle` is the only one pointing to it.
+    ///
+    /// The object is moved out of its slot, and replaced by its `Default` value. The slot is never handed out again:
+    /// the pool has one less non-used object, permanently. The object is not reinitialized.
+    ///
+    /// `None` is returned if other `RcHandle`s point to the object, or if the object is currently borrowed.
+    /// The `RcHandle` is then dropped as usual.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(2, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create().unwrap();
+    /// monster.borrow_mut().level_up();
+    ///
+    /// let monster = monster.detach().unwrap();
+    /// assert_eq!(monster.level, 11);
+    /// assert_eq!(pool.nb_unused(), 1);
+    /// ```
+    pub fn detach(mut self) -> Option<T>
+    where
+        T: Default,
+    {
+        debug!("Detaching the object of the RcHandle from its pool.");
+        let owners = if self.is_pooled() { 2 } else { 1 };
+        if Rc::strong_count(&self.0) != owners {
+            trace!("Other RcHandles point to the inner object.");
+            return None;
+        }
+
+        let object = match self.0.try_borrow_mut() {
+            Ok(mut object) => mem::take(&mut *object),
+            Err(_) => {
+                trace!("The object of the RcHandle is borrowed, it cannot be detached.");
+                return None;
+            }
+        };
+
+        // The placeholder left in the slot is not recycled, and the slot is never handed out again.
+        self.3.detached.set(true);
+        if let Some(ref counters) = self.3.counters {
+            counters.detached.set(counters.detached.get() + 1);
+        }
+        self.1 = skip_recycle::<T>;
+        Some(object)
+    }
+
+    /// Returns `true` if the two `RcHandle`s point to the same object, in the same slot of the pool.
+    ///
+    /// This is equivalent to `==`.
+    ///
+    /// Refer to the [Rc::ptr_eq](https://doc.rust-lang.org/std/rc/struct.Rc.html#method.ptr_eq)
+    /// method for more information.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// # use std::error::Error;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let pool = RcPool::with_capacity(10, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let monster = pool.create_strict()?;
+    /// let same_monster = monster.clone();
+    /// let other_monster = pool.create_strict()?;
+    ///
+    /// assert!(monster.ptr_eq(&same_monster));
+    /// assert!(!monster.ptr_eq(&other_monster));
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #   try_main().unwrap();
+    /// # }
+    /// ```
+    pub fn ptr_eq(&self, other: &RcHandle<T>) -> bool {
+        debug!("Checking if two RcHandles point to the same object.");
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Replaces the object with the given value, and returns the old object, like `std::mem::replace`.
+    ///
+    /// It helps to reset an object to an explicit state while it is used. The old object is not reinitialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    /// # use maskerad_object_pool::Recyclable;
+    /// #
+    /// # struct Monster {
+    /// # hp :u32,
+    /// # pub level: u32,
+    /// # }
+    /// #
+    /// # impl Default for Monster {
+    /// #    fn default() -> Self {
+    /// #        Monster {
+    /// #            hp: 10,
+    /// #            level: 10,
+    /// #        }
+    /// #    }
+    /// # }
+    /// #
+    /// # impl Recyclable for Monster {
+    /// #   fn reinitialize(&mut self) {
+    /// #       self.level = 1;
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Monster {
+    /// #    pub fn level_up(&mut self) {
+    /// #        self.level += 1;
+    /// #    }
+    /// # }
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Monster::default()
+    /// });
+    ///
+    /// let a_monster = pool.create().unwrap();
+    /// let old_monster = a_monster.replace(Monster { hp: 50, level: 5 });
+    /// assert_eq!(old_monster.level, 10);
+    /// assert_eq!(a_monster.borrow().level, 5);
+    /// ```
+    pub fn replace(&self, value: T) -> T {
+        debug!("Replacing the object of the RcHandle.");
+        mem::replace(&mut *self.borrow_mut(), value)
+    }
+}
+
+impl<T> RcHandle<Option<T>> {
+    /// Takes the value out of the slot, leaving it empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     Some(String::from("sword"))
+    /// });
+    ///
+    /// let slot = pool.create().unwrap();
+    /// assert_eq!(slot.take(), Some(String::from("sword")));
+    /// assert_eq!(slot.take(), None);
+    /// ```
+    pub fn take(&self) -> Option<T> {
+        debug!("Taking the value out of the RcHandle.");
+        self.borrow_mut().take()
+    }
+
+    /// Puts a value in the slot, returning the previous one if the slot was not empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::RcPool;
+    ///
+    /// let pool = RcPool::with_capacity(1, || {
+    ///     None
+    /// });
+    ///
+    /// {
+    ///     let slot = pool.create().unwrap();
+    ///     assert_eq!(slot.fill(String::from("sword")), None);
+    ///     assert_eq!(slot.fill(String::from("shield")), Some(String::from("sword")));
+    /// }
+    ///
+    /// // The slot is emptied when it goes back to the pool.
+    /// assert_eq!(*pool.pool_slice()[0].borrow(), None);
+    /// ```
+    pub fn fill(&self, value: T) -> Option<T> {
+        debug!("Putting a value in the RcHandle.");
+        self.borrow_mut().replace(value)
+    }
+}
+
+impl<T: Recyclable> Drop for RcHandle<T> {
+    /// This `Drop` implementation allow us to reinitialize the `Poolable` object
+    /// if the strong reference count of the inner `Rc` is equal to 2.
+    ///
+    /// If it is the case, `T` is reinitialized, the inner `Rc` is dropped and the strong
+    /// reference count is decreased to 1, meaning that the only structure holding a reference is the `RcPool` itself.
+    fn drop(&mut self) {
+        trace!("The RcHandle is being dropped.");
+        // Outer(Inner) -> Outer is dropped, then Inner is dropped.
+        // That's why we check if the refcount is equal to 2 :
+        // PoolObjectHandler is dropped (refcount == 2), then Rc<RefCell<T>> is dropped (refcount == 1 -> only the pool has a ref to the data).
+        if Rc::strong_count(&self.0) == 2 {
+            if let (Some(index), false) = (self.3.index.get(), self.3.detached.get()) {
+                if let Some(ref counters) = self.3.counters {
+                    counters.free.set(counters.free.get() + 1);
+                }
+                if let Some(observer) = self.5.get() {
+                    observer.on_release(index);
+                }
+            }
+            if !self.4.get() {
+                trace!("The reference count of the RcHandle is equal to 2, but recycling is disabled.");
+                return;
+            }
+            if self.3.clean.replace(false) {
+                trace!("The reference count of the RcHandle is equal to 2, but the object is clean.");
+                return;
+            }
+            trace!("The reference count of the RcHandle is equal to 2. Reinitializing the inner object.");
+            match self.0.try_borrow_mut() {
+                Ok(mut object) => (self.1)(&mut object),
+                Err(_) => {
+                    warn!("The object of the RcHandle is borrowed, its reinitialization is deferred.");
+                    self.3.needs_reinit.set(true);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Recyclable> Clone for RcHandle<T> {
+    fn clone(&self) -> Self {
+        // Only the pool holds the RcHandle of a non-used slot: cloning it acquires the slot.
+        if Rc::strong_count(&self.0) == 1 && !self.3.detached.get() {
+            if let Some(ref counters) = self.3.counters {
+                counters.free.set(counters.free.get() - 1);
+            }
+        }
+        RcHandle(self.0.clone(), self.1, self.2, self.3.clone(), self.4.clone(), self.5.clone())
+    }
+}
+
+impl<T: Recyclable> PartialEq for RcHandle<T> {
+    fn eq(&self, other: &RcHandle<T>) -> bool {
+        self.ptr_eq(other)
+    }
+}
+
+impl<T: Recyclable> Eq for RcHandle<T> {}
+
+impl<T: Recyclable> PartialOrd for RcHandle<T> {
+    fn partial_cmp(&self, other: &RcHandle<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Recyclable> Ord for RcHandle<T> {
+    fn cmp(&self, other: &RcHandle<T>) -> Ordering {
+        Rc::as_ptr(&self.0).cmp(&Rc::as_ptr(&other.0))
     }
 }
@@ -0,0 +1,38 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use refcounted_pool_handler::RcHandle;
+use pool_object::Recyclable;
+
+/// A group of `RcHandle<T>` reserved in advance by `RcPool::reserve_group`.
+///
+/// The reserved objects are considered used by the pool, so nobody else can get them.
+/// They can be taken one by one with `RcPool::take_from_reservation`.
+///
+/// When the token is dropped, the objects which have not been taken go back to the pool.
+#[derive(Debug)]
+pub struct ReservationToken<T: Recyclable>(Vec<RcHandle<T>>);
+
+impl<T: Recyclable> ReservationToken<T> {
+    #[doc(hidden)]
+    pub fn new(handles: Vec<RcHandle<T>>) -> Self {
+        debug!("Creating a ReservationToken of {} RcHandle(s).", handles.len());
+        ReservationToken(handles)
+    }
+
+    /// Returns the number of reserved objects which have not been taken yet.
+    pub fn remaining(&self) -> usize {
+        debug!("Getting the number of RcHandles remaining in the ReservationToken.");
+        self.0.len()
+    }
+
+    #[doc(hidden)]
+    pub fn take(&mut self) -> Option<RcHandle<T>> {
+        trace!("Taking a RcHandle from the ReservationToken.");
+        self.0.pop()
+    }
+}
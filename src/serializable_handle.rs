@@ -0,0 +1,51 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use pool_id::PoolId;
+
+/// A serializable reference to a slot of a `RcPool`, created by `RcPool::serializable_handle`.
+///
+/// A `RcHandle<T>` serializes its object by value: two handles sharing one object would be
+/// deserialized as two different objects. A `SerializableHandle` serializes the position of the object
+/// in its pool instead, and can be turned back into a `RcHandle<T>` with `RcPool::resolve`.
+///
+/// The generation is the acquisition stamp of the slot: if the slot has been acquired again since the creation
+/// of the `SerializableHandle`, the reference is stale and cannot be resolved.
+///
+/// This structure is only available with the `serde` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SerializableHandle {
+    pool_id: PoolId,
+    slot: usize,
+    generation: u64,
+}
+
+impl SerializableHandle {
+    #[doc(hidden)]
+    pub fn new(pool_id: PoolId, slot: usize, generation: u64) -> Self {
+        debug!("Creating a SerializableHandle for the slot {} of the pool {}, generation {}.", slot, pool_id, generation);
+        SerializableHandle { pool_id, slot, generation }
+    }
+
+    /// Returns the `PoolId` of the referenced pool.
+    pub fn pool_id(&self) -> PoolId {
+        debug!("Getting the PoolId of the SerializableHandle.");
+        self.pool_id
+    }
+
+    /// Returns the index of the referenced slot in its pool.
+    pub fn slot(&self) -> usize {
+        debug!("Getting the slot of the SerializableHandle.");
+        self.slot
+    }
+
+    /// Returns the generation of the referenced slot.
+    pub fn generation(&self) -> u64 {
+        debug!("Getting the generation of the SerializableHandle.");
+        self.generation
+    }
+}
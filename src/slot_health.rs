@@ -0,0 +1,44 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+/// A snapshot of the state of one slot of an `ArcPool`, returned by `ArcPool::health`.
+///
+/// The lock states are read without blocking, and can be outdated as soon as they are returned.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::ArcPool;
+///
+/// let pool = ArcPool::with_capacity(2, || {
+///     Some(String::from("sword"))
+/// });
+///
+/// let a_sword = pool.create().unwrap();
+/// let _reading = a_sword.read().unwrap();
+///
+/// let health = pool.health();
+/// assert!(health[0].in_use);
+/// assert_eq!(health[0].read_lockers_estimate, 1);
+/// assert!(!health[1].in_use);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SlotHealth {
+    /// The index of the slot in the pool.
+    pub index: usize,
+    /// `true` if the object is used.
+    pub in_use: bool,
+    /// `true` if the lock of the object is poisoned.
+    pub poisoned: bool,
+    /// `true` if the object is currently locked with write access.
+    pub write_locked: bool,
+    /// 1 if the object is currently locked with read access by at least one reader, 0 otherwise.
+    ///
+    /// The exact number of readers cannot be known without blocking.
+    pub read_lockers_estimate: usize,
+}
@@ -0,0 +1,138 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+use refcounted_pool_allocator::RcPool;
+use pool_object::Recyclable;
+
+/// An object of a `TemplatePool`, recycled by copying the template of its pool over it.
+///
+/// It dereferences to the object.
+#[derive(Debug)]
+pub struct Templated<T: Clone> {
+    object: T,
+    template: Rc<T>,
+}
+
+impl<T: Clone> Templated<T> {
+    /// Returns the template of the pool, which the object is reset to when it is recycled.
+    pub fn template(&self) -> &T {
+        &self.template
+    }
+}
+
+impl<T: Clone> Recyclable for Templated<T> {
+    fn reinitialize(&mut self) {
+        self.object.clone_from(&self.template);
+    }
+}
+
+impl<T: Clone> Deref for Templated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.object
+    }
+}
+
+impl<T: Clone> DerefMut for Templated<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.object
+    }
+}
+
+/// A `RcPool` whose objects are clones of a template, and are reset to this template when they are recycled.
+///
+/// The objects do not need to implement `Default` or `Recyclable`, only `Clone`.
+/// The pool dereferences to a `RcPool<Templated<T>>`, giving access to all its functions.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::TemplatePool;
+///
+/// #[derive(Clone)]
+/// struct Monster {
+///     hp: u32,
+///     level: u32,
+/// }
+///
+/// let pool = TemplatePool::with_capacity(2, Monster { hp: 50, level: 5 });
+///
+/// {
+///     let monster = pool.create().unwrap();
+///     monster.borrow_mut().level += 1;
+///     assert_eq!(monster.borrow().level, 6);
+/// }
+///
+/// assert_eq!(pool.pool_slice()[0].borrow().level, 5);
+/// assert_eq!(pool.pool_slice()[0].borrow().hp, 50);
+/// ```
+#[derive(Debug)]
+pub struct TemplatePool<T: Clone> {
+    pool: RcPool<Templated<T>>,
+    template: Rc<T>,
+}
+
+impl<T: Clone> TemplatePool<T> {
+    /// Create an object pool with the given capacity, and instantiate the given number of clones of the template.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::TemplatePool;
+    ///
+    /// let pool = TemplatePool::with_capacity(10, String::from("template"));
+    /// assert_eq!(pool.nb_unused(), 10);
+    /// assert_eq!(*pool.template(), "template");
+    /// ```
+    pub fn with_capacity(size: usize, template: T) -> Self {
+        debug!("Creating a TemplatePool with a size of {} RcHandle(s)", size);
+        let template = Rc::new(template);
+        let pool = RcPool::with_capacity(size, || Templated {
+            object: (*template).clone(),
+            template: template.clone(),
+        });
+        TemplatePool { pool, template }
+    }
+
+    /// Returns the template of the pool.
+    pub fn template(&self) -> &T {
+        debug!("Getting the template of the TemplatePool.");
+        &self.template
+    }
+
+    /// Increase the number of objects of the pool by `additional`, with clones of the template.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::TemplatePool;
+    ///
+    /// let mut pool = TemplatePool::with_capacity(1, 42);
+    /// pool.grow(2);
+    /// assert_eq!(pool.len(), 3);
+    /// assert_eq!(**pool.pool_slice()[2].borrow(), 42);
+    /// ```
+    pub fn grow(&mut self, additional: usize) {
+        debug!("Growing the TemplatePool by {} RcHandle(s).", additional);
+        let template = &self.template;
+        self.pool.grow(additional, || Templated {
+            object: (**template).clone(),
+            template: template.clone(),
+        });
+    }
+}
+
+impl<T: Clone> Deref for TemplatePool<T> {
+    type Target = RcPool<Templated<T>>;
+
+    fn deref(&self) -> &RcPool<Templated<T>> {
+        &self.pool
+    }
+}
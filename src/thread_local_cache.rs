@@ -0,0 +1,170 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use concurrent_pool_allocator::ArcPool;
+use concurrent_pool_handler::ArcHandle;
+use pool_object::Recyclable;
+
+use std::sync::Arc;
+
+/// A per-thread buffer of `ArcHandle<T>`s, acquired in batches from a shared `ArcPool<T>`.
+///
+/// On hot paths, many threads acquiring objects from the same `ArcPool<T>` contend on its slots. Each thread can own
+/// a `ThreadLocalCache<T>`: its `create` serves the buffered `ArcHandle<T>`s without touching the shared pool, and
+/// refills the buffer with a batch of `ArcHandle<T>`s, claimed in a single pass over the pool, when it is empty.
+///
+/// The buffered `ArcHandle<T>`s are used, from the point of view of the pool: the other threads cannot acquire them.
+/// An `ArcHandle<T>` given by the cache goes back to the shared pool when it is dropped, and the buffered ones go back
+/// to the pool with `flush`, or when the cache is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use maskerad_object_pool::{ArcPool, ThreadLocalCache};
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let pool = Arc::new(ArcPool::with_capacity(8, || Some(0u32)));
+///
+/// let workers: Vec<_> = (0..2).map(|_| {
+///     let mut cache = ThreadLocalCache::new(Arc::clone(&pool), 2);
+///     thread::spawn(move || {
+///         for _ in 0..10 {
+///             let counter = cache.create().unwrap();
+///             *counter.write().unwrap() = Some(1);
+///         }
+///     })
+/// }).collect();
+///
+/// for worker in workers {
+///     worker.join().unwrap();
+/// }
+/// assert_eq!(pool.nb_unused(), 8);
+/// ```
+#[derive(Debug)]
+pub struct ThreadLocalCache<T: Recyclable> {
+    pool: Arc<ArcPool<T>>,
+    buffer: Vec<ArcHandle<T>>,
+    batch_size: usize,
+    nb_refills: usize,
+}
+
+impl<T: Recyclable> ThreadLocalCache<T> {
+    /// Create an empty cache over the given pool, refilled with `batch_size` `ArcHandle<T>`s at most.
+    ///
+    /// The pool is shared with an `Arc`, like any `ArcPool<T>` used by several threads.
+    /// No `ArcHandle<T>` is acquired before the first `create`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{ArcPool, ThreadLocalCache};
+    /// use std::sync::Arc;
+    ///
+    /// let pool = Arc::new(ArcPool::with_capacity(8, || Some(0u32)));
+    /// let cache = ThreadLocalCache::new(Arc::clone(&pool), 4);
+    /// assert_eq!(cache.nb_cached(), 0);
+    /// assert_eq!(pool.nb_unused(), 8);
+    /// ```
+    pub fn new(pool: Arc<ArcPool<T>>, batch_size: usize) -> Self {
+        debug!("Creating a ThreadLocalCache with batches of {} ArcHandle(s).", batch_size);
+        assert!(batch_size > 0, "the batches of a ThreadLocalCache cannot be empty");
+        ThreadLocalCache {
+            pool,
+            buffer: Vec::with_capacity(batch_size),
+            batch_size,
+            nb_refills: 0,
+        }
+    }
+
+    /// Returns a buffered `ArcHandle<T>`, refilling the buffer from the shared pool if it is empty.
+    ///
+    /// `None` is returned if the buffer is empty and the shared pool has no non-used `ArcHandle<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{ArcPool, ThreadLocalCache};
+    /// use std::sync::Arc;
+    ///
+    /// let pool = Arc::new(ArcPool::with_capacity(8, || Some(0u32)));
+    /// let mut cache = ThreadLocalCache::new(Arc::clone(&pool), 4);
+    ///
+    /// let a_counter = cache.create().unwrap();
+    /// assert_eq!(cache.nb_cached(), 3);
+    /// assert_eq!(pool.nb_unused(), 4);
+    /// ```
+    pub fn create(&mut self) -> Option<ArcHandle<T>> {
+        debug!("The ThreadLocalCache is being asked an ArcHandle.");
+        if self.buffer.is_empty() {
+            trace!("The buffer of the ThreadLocalCache is empty, refilling it from the ArcPool.");
+            self.nb_refills += 1;
+            self.buffer = self.pool.claim_batch(self.batch_size);
+            // The handles are given in slot order.
+            self.buffer.reverse();
+        }
+        self.buffer.pop()
+    }
+
+    /// Gives all the buffered `ArcHandle<T>`s back to the shared pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{ArcPool, ThreadLocalCache};
+    /// use std::sync::Arc;
+    ///
+    /// let pool = Arc::new(ArcPool::with_capacity(8, || Some(0u32)));
+    /// let mut cache = ThreadLocalCache::new(Arc::clone(&pool), 4);
+    ///
+    /// let a_counter = cache.create().unwrap();
+    /// cache.flush();
+    /// assert_eq!(cache.nb_cached(), 0);
+    /// assert_eq!(pool.nb_unused(), 7);
+    /// ```
+    pub fn flush(&mut self) {
+        debug!("Giving {} buffered ArcHandle(s) back to the ArcPool.", self.buffer.len());
+        self.buffer.clear();
+    }
+
+    /// Returns the number of `ArcHandle<T>`s in the buffer.
+    pub fn nb_cached(&self) -> usize {
+        debug!("Getting the number of buffered ArcHandles of the ThreadLocalCache.");
+        self.buffer.len()
+    }
+
+    /// Returns the number of times the buffer has been refilled from the shared pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use maskerad_object_pool::{ArcPool, ThreadLocalCache};
+    /// use std::sync::Arc;
+    ///
+    /// let pool = Arc::new(ArcPool::with_capacity(8, || Some(0u32)));
+    /// let mut cache = ThreadLocalCache::new(Arc::clone(&pool), 4);
+    ///
+    /// for _ in 0..8 {
+    ///     let a_counter = cache.create().unwrap();
+    /// }
+    /// assert_eq!(cache.nb_refills(), 2);
+    /// ```
+    pub fn nb_refills(&self) -> usize {
+        debug!("Getting the number of refills of the ThreadLocalCache.");
+        self.nb_refills
+    }
+
+    /// Returns a reference to the shared pool.
+    pub fn pool(&self) -> &ArcPool<T> {
+        debug!("Getting the ArcPool of the ThreadLocalCache.");
+        &self.pool
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{AnyPool, Recyclable};
+
+#[derive(Debug, Default, PartialEq)]
+struct Monster {
+    level: u8,
+}
+
+impl Recyclable for Monster {
+    fn reinitialize(&mut self) {
+        self.level = 1;
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Script {
+    source: String,
+}
+
+impl Recyclable for Script {
+    fn reinitialize(&mut self) {
+        self.source.clear();
+    }
+}
+
+fn pool() -> AnyPool {
+    let pool = AnyPool::new();
+    pool.populate(2, Monster::default);
+    pool.populate(1, || Script {
+        source: String::from("print('hello')"),
+    });
+    pool
+}
+
+#[test]
+fn different_types_are_downcast_from_one_pool() {
+    let pool = pool();
+    let mut monster = pool.create::<Monster>().unwrap();
+    let script = pool.create::<Script>().unwrap();
+
+    monster.level += 1;
+    assert_eq!(*monster, Monster { level: 1 });
+    assert_eq!(script.source, "print('hello')");
+
+    assert!(pool.create::<Script>().is_none());
+    assert_eq!(pool.nb_unused::<Monster>(), 1);
+    assert!(pool.create::<Option<u32>>().is_none());
+}
+
+#[test]
+fn objects_are_recycled_into_the_free_list_of_their_type() {
+    let pool = pool();
+    {
+        let mut script = pool.create::<Script>().unwrap();
+        script.source.push_str(" -- edited");
+        let mut monster = pool.create::<Monster>().unwrap();
+        monster.level = 42;
+    }
+
+    assert_eq!(pool.nb_unused::<Script>(), 1);
+    assert_eq!(pool.nb_unused::<Monster>(), 2);
+
+    let script = pool.create::<Script>().unwrap();
+    assert!(script.source.is_empty());
+    let monsters = [pool.create::<Monster>().unwrap(), pool.create::<Monster>().unwrap()];
+    assert!(monsters.iter().any(|monster| monster.level == 1));
+    assert!(monsters.iter().all(|monster| monster.level != 42));
+}
@@ -0,0 +1,107 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{ArcPool, Recyclable};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const REINITIALIZATION: Duration = Duration::from_millis(200);
+
+#[derive(Default)]
+struct Buffer {
+    data: Vec<u8>,
+}
+
+impl Recyclable for Buffer {
+    // Flushing the buffer is slow.
+    fn reinitialize(&mut self) {
+        thread::sleep(REINITIALIZATION);
+        self.data.clear();
+    }
+}
+
+#[test]
+fn release_does_not_wait_for_the_reinitialization() {
+    let pool = ArcPool::with_background_recycler(1, Buffer::default);
+    let buffer = pool.create().unwrap();
+    buffer.write().unwrap().data.extend_from_slice(b"payload");
+
+    let start = Instant::now();
+    drop(buffer);
+    assert!(start.elapsed() < REINITIALIZATION / 2);
+
+    // The buffer is not given before its reinitialization is complete.
+    assert!(pool.create().is_none());
+
+    let buffer = loop {
+        if let Some(buffer) = pool.create() {
+            break buffer;
+        }
+        assert!(start.elapsed() < REINITIALIZATION * 10, "The buffer was never recycled.");
+        thread::sleep(Duration::from_millis(10));
+    };
+    assert!(start.elapsed() >= REINITIALIZATION);
+    assert!(buffer.read().unwrap().data.is_empty());
+}
+
+#[test]
+fn dropping_the_pool_waits_for_the_pending_reinitializations() {
+    let pool = ArcPool::with_background_recycler(2, Buffer::default);
+    let object = {
+        let buffer = pool.create().unwrap();
+        buffer.write().unwrap().data.push(1);
+        buffer.as_ref().clone()
+    };
+
+    drop(pool);
+    assert!(object.read().unwrap().data.is_empty());
+}
+
+#[test]
+fn grown_objects_are_recycled_in_the_background() {
+    let mut pool = ArcPool::with_background_recycler(0, Buffer::default);
+    pool.grow(1, Buffer::default);
+
+    let buffer = pool.create().unwrap();
+    let start = Instant::now();
+    drop(buffer);
+    assert!(start.elapsed() < REINITIALIZATION / 2);
+}
+
+#[derive(Default)]
+struct Counter {
+    value: usize,
+}
+
+impl Recyclable for Counter {
+    fn reinitialize(&mut self) {
+        self.value = 0;
+    }
+}
+
+#[test]
+fn handles_dropped_while_the_recycler_stops_are_reinitialized() {
+    let pool = ArcPool::with_background_recycler(64, Counter::default);
+    let handles: Vec<_> = (0..64).map(|_| pool.create().unwrap()).collect();
+    let objects: Vec<_> = handles.iter().map(|handle| handle.as_ref().clone()).collect();
+    for handle in &handles {
+        handle.write().unwrap().value = 1;
+    }
+
+    // Some handles are dropped before the recycler stops, some while it stops, some after.
+    let dropper = thread::spawn(move || {
+        for handle in handles {
+            drop(handle);
+        }
+    });
+    drop(pool);
+    dropper.join().unwrap();
+
+    assert!(objects.iter().all(|object| object.read().unwrap().value == 0));
+}
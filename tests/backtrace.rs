@@ -0,0 +1,54 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "backtrace")]
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{RcPool, Recyclable};
+
+#[derive(Default)]
+struct Particle {
+    lifetime: u32,
+}
+
+impl Recyclable for Particle {
+    fn reinitialize(&mut self) {
+        self.lifetime = 0;
+    }
+}
+
+#[test]
+fn leaked_handle_is_reported_with_its_call_site() {
+    let pool = RcPool::with_capacity(3, Particle::default);
+    let released = pool.create().unwrap();
+    let leaked = pool.create().unwrap();
+    drop(released);
+
+    let outstanding = pool.dump_outstanding();
+    assert_eq!(outstanding.len(), 1);
+    assert_eq!(outstanding[0].0, leaked.slot_index().unwrap());
+    assert!(outstanding[0].1.contains("leaked_handle_is_reported_with_its_call_site"));
+}
+
+#[test]
+fn released_handles_are_not_reported() {
+    let pool = RcPool::with_capacity(2, Particle::default);
+    {
+        let _particles = pool.create_many(2).unwrap();
+        assert_eq!(pool.dump_outstanding().len(), 2);
+    }
+    assert!(pool.dump_outstanding().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "leaked_handle_call_site_is_in_the_panic_message")]
+fn leaked_handle_call_site_is_in_the_panic_message() {
+    let pool = RcPool::with_capacity(1, Particle::default);
+    let _leaked = pool.create().unwrap();
+    pool.assert_all_released();
+}
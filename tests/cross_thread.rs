@@ -0,0 +1,159 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Acquires `ArcHandle`s on some threads and releases them on others, to check that their objects
+//! are always reinitialized once, and that the pool never gives an object to two threads.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{ArcPool, Recyclable};
+use std::sync::{Arc, Barrier};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+const THREADS: usize = 8;
+const ITERATIONS: usize = 2_000;
+
+#[derive(Default)]
+struct Monster {
+    level: u32,
+    owner: Option<usize>,
+}
+
+impl Recyclable for Monster {
+    fn reinitialize(&mut self) {
+        self.level = 0;
+        self.owner = None;
+    }
+}
+
+#[test]
+fn handle_released_on_another_thread() {
+    let pool = ArcPool::with_capacity(1, Monster::default);
+    let (sender, receiver) = mpsc::channel();
+
+    let monster = pool.create().unwrap();
+    monster.write().unwrap().level = 42;
+    assert_eq!(pool.nb_unused(), 0);
+
+    let releaser = thread::spawn(move || {
+        let monster = receiver.recv().unwrap();
+        drop(monster);
+    });
+    sender.send(monster).unwrap();
+    releaser.join().unwrap();
+
+    assert_eq!(pool.nb_unused(), 1);
+    assert_eq!(pool.pool_slice()[0].read().unwrap().level, 0);
+}
+
+#[test]
+fn clones_dropped_concurrently_recycle_once() {
+    let pool = ArcPool::with_capacity(1, Monster::default);
+
+    for _ in 0..ITERATIONS / 10 {
+        let monster = pool.create().unwrap();
+        monster.write().unwrap().level = 42;
+        let barrier = Arc::new(Barrier::new(THREADS));
+
+        let workers: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let (monster, barrier) = (monster.clone(), barrier.clone());
+                thread::spawn(move || {
+                    barrier.wait();
+                    drop(monster);
+                })
+            })
+            .collect();
+        drop(monster);
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        assert_eq!(pool.nb_unused(), 1);
+        assert_eq!(pool.pool_slice()[0].read().unwrap().level, 0);
+    }
+}
+
+#[test]
+fn concurrent_acquisitions_never_share_an_object() {
+    let pool = Arc::new(ArcPool::with_capacity(THREADS / 2, Monster::default));
+    let (sender, receiver) = mpsc::channel();
+
+    // The acquirers send their handles to a single releaser thread.
+    let releaser = thread::spawn(move || {
+        for monster in receiver {
+            drop(monster);
+        }
+    });
+
+    let acquirers: Vec<_> = (0..THREADS)
+        .map(|thread_index| {
+            let (pool, sender) = (pool.clone(), sender.clone());
+            thread::spawn(move || {
+                for _ in 0..ITERATIONS {
+                    if let Some(monster) = pool.create() {
+                        {
+                            let mut object = monster.write().unwrap();
+                            assert_eq!(object.owner, None, "an object was given to two threads");
+                            object.owner = Some(thread_index);
+                        }
+                        sender.send(monster).unwrap();
+                    }
+                }
+            })
+        })
+        .collect();
+    drop(sender);
+
+    for acquirer in acquirers {
+        acquirer.join().unwrap();
+    }
+    releaser.join().unwrap();
+
+    assert_eq!(pool.nb_unused(), THREADS / 2);
+    assert!(pool
+        .pool_slice()
+        .iter()
+        .all(|monster| monster.read().unwrap().owner.is_none()));
+}
+
+#[test]
+fn handles_dropped_during_a_health_probe() {
+    let pool = Arc::new(ArcPool::with_capacity(THREADS, Monster::default));
+    let probing = Arc::new(AtomicBool::new(true));
+
+    let probers: Vec<_> = (0..2)
+        .map(|_| {
+            let pool = pool.clone();
+            let probing = probing.clone();
+            thread::spawn(move || {
+                while probing.load(Ordering::Relaxed) {
+                    assert_eq!(pool.health().len(), THREADS);
+                }
+            })
+        })
+        .collect();
+
+    for _ in 0..ITERATIONS * 10 {
+        // An object whose reinitialization was deferred by a probe is not handed out while it is probed.
+        let monsters: Vec<_> = (0..THREADS).filter_map(|_| pool.create()).collect();
+        for monster in &monsters {
+            monster.write().unwrap().level = 42;
+        }
+        drop(monsters);
+    }
+    probing.store(false, Ordering::Relaxed);
+    for prober in probers {
+        prober.join().unwrap();
+    }
+
+    // Once the probes are over, every object can be handed out, reinitialized.
+    let monsters: Vec<_> = (0..THREADS).map(|_| pool.create().unwrap()).collect();
+    assert!(monsters.iter().all(|monster| monster.read().unwrap().level == 0));
+}
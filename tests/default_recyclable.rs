@@ -0,0 +1,45 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{ArcPool, DefaultRecyclable, RcPool};
+
+// Default, but not Recyclable.
+#[derive(Default, Debug, PartialEq)]
+struct Monster {
+    name: String,
+    level: u8,
+    hp: u32,
+}
+
+#[test]
+fn default_objects_are_reset_when_recycled() {
+    let pool = RcPool::with_capacity(2, DefaultRecyclable::<Monster>::default);
+    {
+        let monster = pool.create().unwrap();
+        let mut object = monster.borrow_mut();
+        object.name.push_str("goblin");
+        object.level = 30;
+        object.hp = 5;
+    }
+    assert!(pool
+        .pool_slice()
+        .iter()
+        .all(|monster| **monster.borrow() == Monster::default()));
+}
+
+#[test]
+fn default_objects_can_be_shared_between_threads() {
+    let pool = ArcPool::with_capacity(1, || DefaultRecyclable(vec![1, 2, 3]));
+    {
+        let numbers = pool.create().unwrap();
+        numbers.write().unwrap().push(4);
+    }
+    assert!(pool.pool_slice()[0].read().unwrap().is_empty());
+    assert_eq!(DefaultRecyclable(7).into_inner(), 7);
+}
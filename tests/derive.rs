@@ -0,0 +1,78 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "derive")]
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{RcPool, Recyclable};
+
+#[derive(Recyclable, Debug, PartialEq)]
+struct Monster {
+    name: String,
+    level: u8,
+    #[recycle(with = "100")]
+    hp: u32,
+    inventory: Vec<u32>,
+}
+
+#[derive(Recyclable, Debug, PartialEq)]
+struct Position(f32, #[recycle(with = "-1.0")] f32);
+
+#[derive(Recyclable)]
+struct Marker;
+
+fn monster() -> Monster {
+    Monster {
+        name: String::from("goblin"),
+        level: 7,
+        hp: 35,
+        inventory: vec![1, 2, 3],
+    }
+}
+
+#[test]
+fn fields_are_reset_to_their_default() {
+    let mut monster = monster();
+    monster.reinitialize();
+
+    assert_eq!(monster.name, "");
+    assert_eq!(monster.level, 0);
+    assert!(monster.inventory.is_empty());
+}
+
+#[test]
+fn custom_reset_fields_use_their_expression() {
+    let mut monster = monster();
+    monster.reinitialize();
+    assert_eq!(monster.hp, 100);
+
+    let mut position = Position(3.0, 4.0);
+    position.reinitialize();
+    assert_eq!(position, Position(0.0, -1.0));
+
+    Marker.reinitialize();
+}
+
+#[test]
+fn derived_objects_are_recycled_by_the_pool() {
+    let pool = RcPool::with_capacity(1, monster);
+    {
+        let monster = pool.create().unwrap();
+        monster.borrow_mut().level += 1;
+    }
+
+    assert_eq!(
+        *pool.pool_slice()[0].borrow(),
+        Monster {
+            name: String::new(),
+            level: 0,
+            hp: 100,
+            inventory: Vec::new(),
+        }
+    );
+}
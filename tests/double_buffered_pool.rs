@@ -0,0 +1,72 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{DoubleBufferedPool, Recyclable};
+
+#[derive(Debug, Default, PartialEq)]
+struct Particle {
+    position: (i32, i32),
+    velocity: (i32, i32),
+}
+
+impl Recyclable for Particle {
+    fn reinitialize(&mut self) {
+        self.position = (0, 0);
+        self.velocity = (0, 0);
+    }
+}
+
+#[test]
+fn previous_frame_is_readable_after_a_flip() {
+    let mut pool = DoubleBufferedPool::with_capacity(3, Particle::default);
+    let current_id = pool.current().id();
+    {
+        let particle = pool.current().create().unwrap();
+        particle.borrow_mut().position = (4, 2);
+        particle.borrow_mut().velocity = (1, 1);
+        // Keep the data of the frame in the pool.
+        particle.mark_clean();
+    }
+
+    pool.flip();
+    assert_eq!(pool.previous().id(), current_id);
+    assert_eq!(pool.previous().pool_slice()[0].borrow().position, (4, 2));
+    assert!(pool
+        .current()
+        .pool_slice()
+        .iter()
+        .all(|particle| *particle.borrow() == Particle::default()));
+}
+
+#[test]
+fn new_current_buffer_is_reset() {
+    let mut pool = DoubleBufferedPool::with_capacity(2, Particle::default);
+    pool.current().pool_slice()[1].borrow_mut().position = (7, 7);
+    pool.flip();
+    pool.current().pool_slice()[1].borrow_mut().position = (8, 8);
+    pool.flip();
+
+    assert_eq!(pool.current().pool_slice()[1].borrow().position, (0, 0));
+    assert_eq!(pool.previous().pool_slice()[1].borrow().position, (8, 8));
+    assert_eq!(pool.current().nb_unused(), 2);
+}
+
+#[test]
+fn used_objects_survive_a_flip() {
+    let mut pool = DoubleBufferedPool::with_capacity(2, Particle::default);
+    let held = pool.current().create().unwrap();
+    held.borrow_mut().position = (3, 3);
+
+    pool.flip();
+    pool.flip();
+
+    // The object is still used, it cannot be reinitialized.
+    assert_eq!(held.borrow().position, (3, 3));
+    assert_eq!(pool.current().nb_unused(), 1);
+}
@@ -0,0 +1,138 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Randomly acquires, clones and drops handles, while maintaining an external model
+//! of the slots in use, to check that the pools never hand out a slot which is still in use.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{ArcPool, RcPool, Recyclable};
+use std::rc::Rc;
+use std::sync::Arc;
+
+const SEED: u64 = 0x5EED_1833_CAFE_F00D;
+const ITERATIONS: usize = 20_000;
+const POOL_SIZE: usize = 16;
+
+// A small xorshift generator, so the test is reproducible without any external dependency.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+#[derive(Default)]
+struct Monster {
+    level: u32,
+}
+
+impl Recyclable for Monster {
+    fn reinitialize(&mut self) {
+        self.level = 0;
+    }
+}
+
+#[test]
+fn rc_pool_never_double_issues() {
+    let pool = RcPool::with_capacity(POOL_SIZE, Monster::default);
+    let mut rng = XorShift(SEED);
+    // (slot index, handle) pairs held by the test.
+    let mut held = Vec::new();
+    // Number of handles held by the test, for each slot.
+    let mut model = [0usize; POOL_SIZE];
+
+    for _ in 0..ITERATIONS {
+        match rng.below(3) {
+            0 => match pool.create() {
+                Some(handle) => {
+                    let index = pool.pool_slice()
+                        .iter()
+                        .position(|slot| Rc::ptr_eq(slot.as_ref(), handle.as_ref()))
+                        .unwrap();
+                    assert_eq!(model[index], 0, "slot {} was handed out while in use", index);
+                    assert_eq!(handle.borrow().level, 0, "slot {} was not recycled", index);
+                    handle.borrow_mut().level = 1;
+                    model[index] += 1;
+                    held.push((index, handle));
+                }
+                None => assert!(model.iter().all(|&count| count > 0)),
+            },
+            1 if !held.is_empty() => {
+                let pick = rng.below(held.len());
+                let (index, clone) = (held[pick].0, held[pick].1.clone());
+                model[index] += 1;
+                held.push((index, clone));
+            }
+            _ if !held.is_empty() => {
+                let pick = rng.below(held.len());
+                let (index, _handle) = held.swap_remove(pick);
+                model[index] -= 1;
+            }
+            _ => {}
+        }
+
+        let nb_free = model.iter().filter(|&&count| count == 0).count();
+        assert_eq!(pool.nb_unused(), nb_free);
+    }
+
+    held.clear();
+    assert_eq!(pool.nb_unused(), POOL_SIZE);
+}
+
+#[test]
+fn arc_pool_never_double_issues() {
+    let pool = ArcPool::with_capacity(POOL_SIZE, Monster::default);
+    let mut rng = XorShift(SEED);
+    let mut held = Vec::new();
+    let mut model = [0usize; POOL_SIZE];
+
+    for _ in 0..ITERATIONS {
+        match rng.below(3) {
+            0 => match pool.create() {
+                Some(handle) => {
+                    let index = pool.pool_slice()
+                        .iter()
+                        .position(|slot| Arc::ptr_eq(slot.as_ref(), handle.as_ref()))
+                        .unwrap();
+                    assert_eq!(model[index], 0, "slot {} was handed out while in use", index);
+                    assert_eq!(handle.read().unwrap().level, 0, "slot {} was not recycled", index);
+                    handle.write().unwrap().level = 1;
+                    model[index] += 1;
+                    held.push((index, handle));
+                }
+                None => assert!(model.iter().all(|&count| count > 0)),
+            },
+            1 if !held.is_empty() => {
+                let pick = rng.below(held.len());
+                let (index, clone) = (held[pick].0, held[pick].1.clone());
+                model[index] += 1;
+                held.push((index, clone));
+            }
+            _ if !held.is_empty() => {
+                let pick = rng.below(held.len());
+                let (index, _handle) = held.swap_remove(pick);
+                model[index] -= 1;
+            }
+            _ => {}
+        }
+
+        let nb_free = model.iter().filter(|&&count| count == 0).count();
+        assert_eq!(pool.nb_unused(), nb_free);
+    }
+
+    held.clear();
+    assert_eq!(pool.nb_unused(), POOL_SIZE);
+}
@@ -0,0 +1,70 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{ArcPool, ErasedHandle, RcPool, Recyclable};
+
+#[derive(Debug, Default)]
+struct Monster {
+    level: u8,
+}
+
+impl Recyclable for Monster {
+    fn reinitialize(&mut self) {
+        self.level = 1;
+    }
+}
+
+#[derive(Debug, Default)]
+struct Item {
+    name: String,
+}
+
+impl Recyclable for Item {
+    fn reinitialize(&mut self) {
+        self.name.clear();
+    }
+}
+
+#[test]
+fn handles_of_different_types_are_stored_together() {
+    let monsters = RcPool::with_capacity(1, Monster::default);
+    let items = RcPool::with_capacity(2, Item::default);
+    let monster = monsters.create().unwrap();
+    monster.borrow_mut().level = 5;
+    let item = items.create().unwrap();
+
+    let mut registry: Vec<Box<dyn ErasedHandle>> = Vec::new();
+    registry.push(Box::new(monster));
+    registry.push(item.into_erased());
+
+    assert!(registry.iter().all(|handle| handle.is_in_use()));
+    assert_eq!(registry[0].pool_id(), Some(monsters.id()));
+    assert_eq!(registry[1].pool_id(), Some(items.id()));
+    assert_eq!(monsters.nb_unused(), 0);
+    assert_eq!(items.nb_unused(), 1);
+
+    registry.clear();
+    assert_eq!(monsters.nb_unused(), 1);
+    assert_eq!(monsters.pool_slice()[0].borrow().level, 1);
+    assert_eq!(items.nb_unused(), 2);
+}
+
+#[test]
+fn pooled_handles_are_not_in_use() {
+    let monsters = RcPool::with_capacity(1, Monster::default);
+    assert!(!ErasedHandle::is_in_use(&monsters.pool_slice()[0]));
+    let monster = monsters.create().unwrap();
+    assert!(ErasedHandle::is_in_use(&monster));
+
+    let items = ArcPool::with_capacity(1, Item::default);
+    assert!(!ErasedHandle::is_in_use(&items.pool_slice()[0]));
+    let item = items.create().unwrap().into_erased();
+    assert!(item.is_in_use());
+    assert!(ErasedHandle::is_in_use(&items.pool_slice()[0]));
+}
@@ -0,0 +1,72 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{InterningPool, Recyclable};
+
+#[derive(Debug, Default, PartialEq, Eq, Hash)]
+struct Mesh {
+    vertices: Vec<(i32, i32)>,
+}
+
+impl Recyclable for Mesh {
+    fn reinitialize(&mut self) {
+        self.vertices.clear();
+    }
+}
+
+fn triangle() -> Mesh {
+    Mesh {
+        vertices: vec![(0, 0), (1, 0), (0, 1)],
+    }
+}
+
+fn square() -> Mesh {
+    Mesh {
+        vertices: vec![(0, 0), (1, 0), (1, 1), (0, 1)],
+    }
+}
+
+#[test]
+fn equal_values_share_one_slot() {
+    let pool = InterningPool::with_capacity(3, Mesh::default);
+    let a_triangle = pool.intern(triangle()).unwrap();
+    let another_triangle = pool.intern(triangle()).unwrap();
+
+    assert!(a_triangle.ptr_eq(&another_triangle));
+    assert_eq!(a_triangle.slot_index(), Some(0));
+    assert_eq!(pool.nb_unused(), 2);
+}
+
+#[test]
+fn distinct_values_get_separate_slots() {
+    let pool = InterningPool::with_capacity(2, Mesh::default);
+    let a_triangle = pool.intern(triangle()).unwrap();
+    let a_square = pool.intern(square()).unwrap();
+
+    assert!(!a_triangle.ptr_eq(&a_square));
+    assert_eq!(*a_square.borrow(), square());
+    assert_eq!(pool.nb_unused(), 0);
+    assert!(pool.intern(Mesh::default()).is_none());
+    assert!(pool.intern(square()).unwrap().ptr_eq(&a_square));
+}
+
+#[test]
+fn released_values_are_not_shared_anymore() {
+    let pool = InterningPool::with_capacity(2, Mesh::default);
+    let a_triangle = pool.intern(triangle()).unwrap();
+    drop(a_triangle);
+    assert_eq!(pool.nb_unused(), 2);
+    assert!(pool.pool_slice()[0].borrow().vertices.is_empty());
+
+    let a_square = pool.intern(square()).unwrap();
+    let a_triangle = pool.intern(triangle()).unwrap();
+    assert_eq!(a_square.slot_index(), Some(0));
+    assert_eq!(a_triangle.slot_index(), Some(1));
+    assert_eq!(*a_triangle.borrow(), triangle());
+}
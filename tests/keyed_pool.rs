@@ -0,0 +1,77 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{KeyedPool, Recyclable};
+
+#[derive(Debug)]
+struct Connection {
+    host: String,
+    nb_configurations: u32,
+    nb_requests: u32,
+}
+
+impl Recyclable for Connection {
+    fn reinitialize(&mut self) {
+        self.nb_requests = 0;
+    }
+}
+
+fn connection() -> Connection {
+    Connection {
+        host: String::new(),
+        nb_configurations: 0,
+        nb_requests: 0,
+    }
+}
+
+fn configure(connection: &mut Connection, host: &&str) {
+    connection.host = host.to_string();
+    connection.nb_configurations += 1;
+}
+
+#[test]
+fn free_objects_configured_for_the_key_are_reused() {
+    let pool = KeyedPool::with_capacity(3, connection, configure);
+    let first = "first.example.com";
+    let second = "second.example.com";
+
+    let a_connection = pool.acquire(&first).unwrap();
+    let another_connection = pool.acquire(&second).unwrap();
+    drop(a_connection);
+    drop(another_connection);
+
+    // The second slot is reused, even if the first one is free.
+    let connection = pool.acquire(&second).unwrap();
+    assert!(connection.ptr_eq(&pool.pool_slice()[1]));
+    assert_eq!(connection.borrow().host, second);
+    assert_eq!(connection.borrow().nb_configurations, 1);
+}
+
+#[test]
+fn free_objects_are_reconfigured_for_a_new_key() {
+    let pool = KeyedPool::with_capacity(2, connection, configure);
+    let first = "first.example.com";
+    let second = "second.example.com";
+    let third = "third.example.com";
+
+    drop(pool.acquire(&first).unwrap());
+    let in_use = pool.acquire(&first).unwrap();
+
+    // No free object is configured for the third key: the other free object is reconfigured.
+    let connection = pool.acquire(&second).unwrap();
+    drop(connection);
+    let connection = pool.acquire(&third).unwrap();
+    assert!(connection.ptr_eq(&pool.pool_slice()[1]));
+    assert_eq!(connection.borrow().host, third);
+    assert_eq!(connection.borrow().nb_configurations, 2);
+    assert_eq!(pool.key_of(1), Some(third));
+
+    assert!(pool.acquire(&first).is_none());
+    drop(in_use);
+}
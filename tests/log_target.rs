@@ -0,0 +1,64 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Captures the log records of the crate, to check that they all have the same target.
+
+extern crate log;
+extern crate maskerad_object_pool;
+
+use log::{LevelFilter, Log, Metadata, Record};
+use maskerad_object_pool::{ArcPool, RcPool, Recyclable, LOG_TARGET};
+use std::sync::Mutex;
+
+struct CapturingLogger;
+
+static LOGGER: CapturingLogger = CapturingLogger;
+static TARGETS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        TARGETS.lock().unwrap().push(record.target().to_owned());
+    }
+
+    fn flush(&self) {}
+}
+
+#[derive(Default)]
+struct Monster {
+    level: u32,
+}
+
+impl Recyclable for Monster {
+    fn reinitialize(&mut self) {
+        self.level = 1;
+    }
+}
+
+#[test]
+fn all_records_use_the_crate_target() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Trace);
+
+    let rc_pool = RcPool::with_capacity(1, Monster::default);
+    let monster = rc_pool.create().unwrap();
+    assert!(rc_pool.create_strict().is_err());
+    drop(monster);
+
+    let arc_pool = ArcPool::with_capacity(1, Monster::default);
+    let monster = arc_pool.create().unwrap();
+    assert!(arc_pool.create_strict().is_err());
+    drop(monster);
+
+    let targets = TARGETS.lock().unwrap();
+    assert!(!targets.is_empty());
+    assert!(targets.iter().all(|target| target == LOG_TARGET));
+    assert_eq!(LOG_TARGET, "maskerad_object_pool");
+}
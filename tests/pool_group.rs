@@ -0,0 +1,60 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{PoolGroup, RcPool, Recyclable};
+
+#[derive(Debug, Default)]
+struct Particle {
+    x: f32,
+}
+
+impl Recyclable for Particle {
+    fn reinitialize(&mut self) {
+        self.x = 0.0;
+    }
+}
+
+#[test]
+fn growing_a_pool_fails_when_another_pool_exhausted_the_budget() {
+    let mut group = PoolGroup::new(8);
+    let sparks = group.register(RcPool::with_capacity(2, Particle::default)).unwrap();
+    let smoke = group.register(RcPool::with_capacity(2, Particle::default)).unwrap();
+
+    group.grow(smoke, 4, Particle::default).unwrap();
+    assert_eq!(group.total_capacity(), 8);
+    assert_eq!(group.remaining(), 0);
+
+    assert!(group.grow(sparks, 1, Particle::default).is_err());
+    assert_eq!(group.pool(sparks).unwrap().len(), 2);
+    assert_eq!(group.total_capacity(), 8);
+}
+
+#[test]
+fn registering_a_pool_over_the_budget_fails() {
+    let mut group = PoolGroup::new(4);
+    group.register(RcPool::with_capacity(3, Particle::default)).unwrap();
+    assert!(group.register(RcPool::with_capacity(2, Particle::default)).is_err());
+    assert_eq!(group.len(), 1);
+
+    group.register(RcPool::with_capacity(1, Particle::default)).unwrap();
+    assert_eq!(group.len(), 2);
+    assert!(group.grow(2, 0, Particle::default).is_err());
+}
+
+#[test]
+fn objects_are_acquired_from_the_registered_pools() {
+    let mut group = PoolGroup::new(4);
+    let sparks = group.register(RcPool::with_capacity(1, Particle::default)).unwrap();
+
+    let spark = group.pool(sparks).unwrap().create().unwrap();
+    spark.borrow_mut().x = 2.0;
+    assert!(group.pool(sparks).unwrap().create().is_none());
+    drop(spark);
+    assert_eq!(group.pool(sparks).unwrap().pool_slice()[0].borrow().x, 0.0);
+}
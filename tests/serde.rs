@@ -0,0 +1,204 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "serde")]
+
+extern crate maskerad_object_pool;
+#[macro_use]
+extern crate serde;
+extern crate serde_json;
+
+use maskerad_object_pool::{ArcPool, RcPool, Recyclable, SerializableHandle};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Monster {
+    level: u32,
+}
+
+impl Recyclable for Monster {
+    fn reinitialize(&mut self) {
+        self.level = 1;
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Position {
+    x: u32,
+}
+
+impl Recyclable for Position {
+    fn reinitialize(&mut self) {
+        self.x = 1;
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entity {
+    name: String,
+    target: SerializableHandle,
+}
+
+#[derive(Serialize, Deserialize)]
+struct World {
+    monsters: RcPool<Monster>,
+    entities: Vec<Entity>,
+}
+
+#[test]
+fn shared_handle_round_trip() {
+    let monsters = RcPool::with_capacity(3, Monster::default);
+    let _first = monsters.create().unwrap();
+    let shared = monsters.create().unwrap();
+    shared.borrow_mut().level = 42;
+
+    let entities = vec![
+        Entity {
+            name: String::from("knight"),
+            target: monsters.serializable_handle(&shared).unwrap(),
+        },
+        Entity {
+            name: String::from("archer"),
+            target: monsters.serializable_handle(&shared.clone()).unwrap(),
+        },
+    ];
+    let json = serde_json::to_string(&World { monsters, entities }).unwrap();
+
+    let world: World = serde_json::from_str(&json).unwrap();
+    assert_eq!(world.entities[0].name, "knight");
+    let knight_target = world.monsters.resolve(&world.entities[0].target).unwrap();
+    let archer_target = world.monsters.resolve(&world.entities[1].target).unwrap();
+    assert!(knight_target.ptr_eq(&archer_target));
+    assert!(knight_target.ptr_eq(&world.monsters.pool_slice()[1]));
+    assert_eq!(knight_target.borrow().level, 42);
+    assert_eq!(world.monsters.nb_unused(), 2);
+}
+
+#[test]
+fn stale_handle_is_not_resolved() {
+    let monsters = RcPool::with_capacity(1, Monster::default);
+    let serialized = {
+        let monster = monsters.create().unwrap();
+        monsters.serializable_handle(&monster).unwrap()
+    };
+    let _monster = monsters.create().unwrap();
+
+    assert!(monsters.resolve(&serialized).is_none());
+    assert!(monsters.resolve(&SerializableHandle::new(monsters.id(), 3, 0)).is_none());
+}
+
+#[test]
+fn released_object_is_not_resolved() {
+    let monsters = RcPool::with_capacity(2, Monster::default);
+    let serialized = {
+        let monster = monsters.create().unwrap();
+        monsters.serializable_handle(&monster).unwrap()
+    };
+
+    assert!(monsters.resolve(&serialized).is_none());
+    assert_eq!(monsters.nb_unused(), 2);
+    assert_eq!(monsters.total_acquisitions(), 1);
+
+    let json = serde_json::to_string(&monsters).unwrap();
+    let monsters: RcPool<Monster> = serde_json::from_str(&json).unwrap();
+    assert!(monsters.resolve(&serialized).is_none());
+    assert_eq!(monsters.nb_unused(), 2);
+}
+
+#[test]
+fn restored_object_is_acquired_again() {
+    let monsters = RcPool::with_capacity(2, Monster::default);
+    let monster = monsters.create().unwrap();
+    let serialized = monsters.serializable_handle(&monster).unwrap();
+    let json = serde_json::to_string(&monsters).unwrap();
+
+    let monsters: RcPool<Monster> = serde_json::from_str(&json).unwrap();
+    let monster = monsters.resolve(&serialized).unwrap();
+    assert_eq!(monsters.nb_unused(), 1);
+    assert_eq!(monsters.total_acquisitions(), 1);
+    assert_eq!(monsters.peak_in_use(), 1);
+
+    // Once released, the restored object is not resolved again.
+    drop(monster);
+    assert!(monsters.resolve(&serialized).is_none());
+
+    let monsters: RcPool<Monster> = serde_json::from_str(&json).unwrap();
+    monsters.pause();
+    assert!(monsters.resolve(&serialized).is_none());
+}
+
+#[test]
+fn foreign_handle_is_not_resolved() {
+    let monsters = RcPool::with_capacity(1, Monster::default);
+    let other_monsters = RcPool::with_capacity(1, Monster::default);
+    let monster = other_monsters.create().unwrap();
+    let serialized = other_monsters.serializable_handle(&monster).unwrap();
+
+    assert_eq!(serialized.pool_id(), other_monsters.id());
+    assert!(monsters.serializable_handle(&monster).is_none());
+    assert!(monsters.resolve(&serialized).is_none());
+}
+
+#[test]
+fn deserialized_pool_gets_a_new_id() {
+    let monsters = RcPool::with_capacity(1, Monster::default);
+    let monster = monsters.create().unwrap();
+    let serialized = monsters.serializable_handle(&monster).unwrap();
+    let json = serde_json::to_string(&monsters).unwrap();
+
+    let deserialized: RcPool<Monster> = serde_json::from_str(&json).unwrap();
+    let deserialized_again: RcPool<Monster> = serde_json::from_str(&json).unwrap();
+    assert_ne!(deserialized.id(), monsters.id());
+    assert_ne!(deserialized.id(), deserialized_again.id());
+
+    // Both copies still resolve the handles of the serialized pool, but their handles are told apart.
+    let resolved = deserialized.resolve(&serialized).unwrap();
+    let resolved_again = deserialized_again.resolve(&serialized).unwrap();
+    assert!(deserialized.serializable_handle(&resolved_again).is_none());
+    assert_ne!(resolved.pool_id(), resolved_again.pool_id());
+}
+
+#[test]
+fn recycle_function_is_not_serialized() {
+    let monsters = RcPool::with_capacity_no_reset(1, || Position { x: 0 });
+    let json = serde_json::to_string(&monsters).unwrap();
+
+    // The deserialized pool reinitializes its objects with `Recyclable::reinitialize`.
+    let monsters: RcPool<Position> = serde_json::from_str(&json).unwrap();
+    monsters.create().unwrap().borrow_mut().x = 42;
+    assert_eq!(monsters.create().unwrap().borrow().x, 1);
+}
+
+#[test]
+fn arc_pool_round_trip() {
+    let monsters = ArcPool::with_capacity(2, Monster::default);
+    monsters.create().unwrap().write().unwrap().level = 42;
+
+    let json = serde_json::to_string(&monsters).unwrap();
+    assert_eq!(json, r#"[{"level":1},{"level":0}]"#);
+
+    let monsters: ArcPool<Monster> = serde_json::from_str(&json).unwrap();
+    assert_eq!(monsters.len(), 2);
+    assert_eq!(monsters.pool_slice()[0].read().unwrap().level, 1);
+}
+
+#[test]
+fn stats_are_serialized() {
+    let monsters = RcPool::with_capacity(3, Monster::default);
+    let _monster = monsters.create().unwrap();
+
+    let json: serde_json::Value = serde_json::to_value(monsters.stats()).unwrap();
+    assert_eq!(json["len"], 3);
+    assert_eq!(json["in_use"], 1);
+    assert_eq!(json["unused"], 2);
+    assert_eq!(json["peak_in_use"], 1);
+    assert_eq!(json["total_acquisitions"], 1);
+    assert_eq!(json["poisoned"], 0);
+
+    let monsters = ArcPool::with_capacity(1, Monster::default);
+    let json: serde_json::Value = serde_json::to_value(monsters.stats()).unwrap();
+    assert!(json["peak_in_use"].is_null());
+}
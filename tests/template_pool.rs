@@ -0,0 +1,56 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::TemplatePool;
+
+// Neither Default nor Recyclable.
+#[derive(Clone, Debug, PartialEq)]
+struct Monster {
+    name: String,
+    level: u8,
+    hp: u32,
+}
+
+fn template() -> Monster {
+    Monster {
+        name: String::from("goblin"),
+        level: 7,
+        hp: 35,
+    }
+}
+
+#[test]
+fn objects_start_as_the_template() {
+    let pool = TemplatePool::with_capacity(3, template());
+    assert_eq!(*pool.template(), template());
+    assert!(pool
+        .pool_slice()
+        .iter()
+        .all(|monster| **monster.borrow() == template()));
+}
+
+#[test]
+fn recycled_objects_match_the_template() {
+    let mut pool = TemplatePool::with_capacity(2, template());
+    {
+        let monster = pool.create().unwrap();
+        let mut object = monster.borrow_mut();
+        object.name.push_str(" king");
+        object.level = 30;
+        object.hp = 0;
+    }
+    assert!(pool
+        .pool_slice()
+        .iter()
+        .all(|monster| **monster.borrow() == template()));
+
+    pool.grow(1);
+    assert_eq!(pool.len(), 3);
+    assert_eq!(**pool.pool_slice()[2].borrow(), template());
+}
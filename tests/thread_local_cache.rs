@@ -0,0 +1,90 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{ArcPool, Recyclable, ThreadLocalCache};
+use std::sync::Arc;
+use std::thread;
+
+#[derive(Debug, Default)]
+struct Packet {
+    len: usize,
+}
+
+impl Recyclable for Packet {
+    fn reinitialize(&mut self) {
+        self.len = 0;
+    }
+}
+
+#[test]
+fn batched_refills_reduce_the_interactions_with_the_shared_pool() {
+    let pool = Arc::new(ArcPool::with_capacity(16, Packet::default));
+    let mut cache = ThreadLocalCache::new(Arc::clone(&pool), 4);
+
+    let mut packets = Vec::new();
+    for len in 0..10 {
+        let packet = cache.create().unwrap();
+        packet.write().unwrap().len = len;
+        packets.push(packet);
+    }
+    // 10 acquisitions, but only 3 interactions with the shared pool.
+    assert_eq!(cache.nb_refills(), 3);
+    assert_eq!(cache.nb_cached(), 2);
+    assert_eq!(pool.nb_unused(), 4);
+
+    // The handles given by the cache go back to the shared pool.
+    drop(packets);
+    assert_eq!(pool.nb_unused(), 14);
+    assert_eq!(pool.pool_slice()[0].read().unwrap().len, 0);
+
+    drop(cache);
+    assert_eq!(pool.nb_unused(), 16);
+}
+
+#[test]
+fn partial_batches_are_served_until_the_pool_is_exhausted() {
+    let pool = Arc::new(ArcPool::with_capacity(5, Packet::default));
+    let mut cache = ThreadLocalCache::new(Arc::clone(&pool), 4);
+    let _taken = pool.create().unwrap();
+
+    let packets: Vec<_> = (0..4).map(|_| cache.create().unwrap()).collect();
+    assert_eq!(cache.nb_refills(), 1);
+    assert!(cache.create().is_none());
+    assert_eq!(cache.nb_refills(), 2);
+
+    drop(packets);
+    let _packet = cache.create().unwrap();
+    assert_eq!(cache.nb_refills(), 3);
+    assert_eq!(pool.nb_unused(), 0);
+    cache.flush();
+    assert_eq!(pool.nb_unused(), 3);
+}
+
+#[test]
+fn each_thread_owns_its_cache() {
+    let pool = Arc::new(ArcPool::with_capacity(8, Packet::default));
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let mut cache = ThreadLocalCache::new(Arc::clone(&pool), 2);
+            thread::spawn(move || {
+                for len in 0..50 {
+                    let packet = cache.create().expect("each thread can buffer 2 packets");
+                    packet.write().unwrap().len = len;
+                }
+                cache.nb_refills()
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        assert!(worker.join().unwrap() <= 25);
+    }
+    assert_eq!(pool.nb_unused(), 8);
+}
@@ -0,0 +1,56 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Compile-time checks of the threading model: the `Arc` types can be shared between threads,
+//! the `Rc` types cannot. Those tests fail to compile if an auto trait is gained or lost by accident.
+
+#![allow(dead_code)]
+
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{ArcHandle, ArcPool, RcHandle, RcPool, Recyclable};
+
+#[derive(Default)]
+struct Monster {
+    level: u32,
+}
+
+impl Recyclable for Monster {
+    fn reinitialize(&mut self) {
+        self.level = 1;
+    }
+}
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+// `<T as AmbiguousIfSend<_>>::some_item` only compiles if a single impl applies, i.e. if `T` is not `Send`.
+trait AmbiguousIfSend<A> {
+    fn some_item() {}
+}
+impl<T: ?Sized> AmbiguousIfSend<()> for T {}
+impl<T: ?Sized + Send> AmbiguousIfSend<u8> for T {}
+
+// Same trick for `Sync`.
+trait AmbiguousIfSync<A> {
+    fn some_item() {}
+}
+impl<T: ?Sized> AmbiguousIfSync<()> for T {}
+impl<T: ?Sized + Sync> AmbiguousIfSync<u8> for T {}
+
+#[test]
+fn arc_types_are_send_and_sync() {
+    assert_send_sync::<ArcPool<Monster>>();
+    assert_send_sync::<ArcHandle<Monster>>();
+}
+
+#[test]
+fn rc_types_are_neither_send_nor_sync() {
+    let _ = <RcPool<Monster> as AmbiguousIfSend<_>>::some_item;
+    let _ = <RcPool<Monster> as AmbiguousIfSync<_>>::some_item;
+    let _ = <RcHandle<Monster> as AmbiguousIfSend<_>>::some_item;
+    let _ = <RcHandle<Monster> as AmbiguousIfSync<_>>::some_item;
+}
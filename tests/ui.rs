@@ -0,0 +1,16 @@
+// Copyright 2017 -2018 Maskerad Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+extern crate trybuild;
+
+#[test]
+fn const_capacity() {
+    // The pass case makes trybuild build the cases instead of only checking them, which evaluates the constants.
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/const_capacity.rs");
+    cases.compile_fail("tests/ui/zero_const_capacity.rs");
+}
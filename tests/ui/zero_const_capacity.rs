@@ -0,0 +1,18 @@
+extern crate maskerad_object_pool;
+
+use maskerad_object_pool::{RcPool, Recyclable};
+
+#[derive(Default)]
+struct Monster {
+    level: u32,
+}
+
+impl Recyclable for Monster {
+    fn reinitialize(&mut self) {
+        self.level = 1;
+    }
+}
+
+fn main() {
+    let _pool = RcPool::with_const_capacity::<0>(Monster::default);
+}